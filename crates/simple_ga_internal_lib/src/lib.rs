@@ -63,6 +63,20 @@ pub mod test_rng {
         MockThreadRng { rng }
     }
 
+    /// Replaces this thread's mock RNG state with one seeded from `seed`,
+    /// instead of the fixed `StepRng::new(0, 1)` [`rng`] otherwise always
+    /// starts from, so callers can reproduce a specific run under the mock
+    /// RNG rather than only ever the one fixed sequence.
+    pub fn seed_thread_rng(seed: u64) {
+        THREAD_RNG_KEY.with(|t| {
+            // SAFETY: same justification as `MockThreadRng`'s `RngCore` impl —
+            // no other reference into this cell is alive across this call.
+            unsafe {
+                *t.get() = StdRng::seed_from_u64(seed);
+            }
+        });
+    }
+
     impl Default for MockThreadRng {
         fn default() -> MockThreadRng {
             thread_rng()
@@ -114,5 +128,15 @@ pub mod test_rng {
             r.gen::<i32>();
             assert_eq!(r.gen_range(0..1), 0);
         }
+
+        #[test]
+        fn test_seed_thread_rng_reproduces_the_same_draw() {
+            use rand::Rng;
+            seed_thread_rng(7);
+            let first: u32 = thread_rng().gen();
+            seed_thread_rng(7);
+            let second: u32 = thread_rng().gen();
+            assert_eq!(first, second);
+        }
     }
 }
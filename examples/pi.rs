@@ -243,6 +243,8 @@ fn main() {
     let ga_options = GeneticAlgorithmOptions {
         fitness_initial_to_target_range: 0f64..target_fitness,
         fitness_range: 0f64..target_fitness,
+        target_fitness_epsilon: 0.0,
+        seed: None,
         actions: DefaultActions {
             prune: PruneAction::new(PruneExtraBackSkipFirst::new(
                 population_size.apply_ratio_round(0.33),
@@ -260,6 +262,7 @@ fn main() {
                     (MutatorFns::AddOne, 0.25).into(),
                     (MutatorFns::SubOne, 0.25).into(),
                 ]),
+                chunk_size: None,
             }),
             reproduction: GenericReproducer::new(ApplyReproductionOptions {
                 selector: SelectRandomManyWithBias::new(population_size / 10, Bias::Front),
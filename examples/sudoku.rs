@@ -548,6 +548,8 @@ fn main() {
     let ga_options = GeneticAlgorithmOptions {
         fitness_initial_to_target_range: INITIAL_FITNESS..target_fitness,
         fitness_range: target_fitness..MAX_FITNESS,
+        target_fitness_epsilon: 0.0,
+        seed: None,
         actions: DefaultActions {
             prune: PruneAction::new(PruneExtraBackSkipFirst::new(
                 population_size.apply_ratio_round(0.5),
@@ -560,6 +562,7 @@ fn main() {
                     (MutatorFn::RotateRow, 0.25).into(),
                     (MutatorFn::RandomOverwrite, 0.75).into(),
                 ]),
+                chunk_size: None,
             }),
             reproduction: GenericReproducer::new(ApplyReproductionOptions {
                 selector: SelectRandomManyWithBias::new(population_size / 4, Bias::Front),
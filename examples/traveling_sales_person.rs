@@ -314,12 +314,15 @@ fn main() {
     let ga_options = GeneticAlgorithmOptions {
         fitness_initial_to_target_range: INITIAL_FITNESS..TARGET_FITNESS,
         fitness_range: MIN_FITNESS..MAX_FITNESS,
+        target_fitness_epsilon: 0.0,
+        seed: None,
         actions: DefaultActions {
             prune: PruneAction::new(DefaultPruneHalfBackSkipFirst),
             mutation: GenericMutator::new(ApplyMutationOptions {
                 clone_on_mutation: true,
                 overall_mutation_chance: 0.75,
                 mutation_actions: WeightedActionsSampleOne(vec![(Mutation::Swap, 0.5).into()]),
+                chunk_size: None,
             }),
             reproduction: GenericReproducer::new(ApplyReproductionOptions {
                 selector: SelectRandomManyWithBias::new(population_size / 4, Bias::Front),
@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// Crate-wide error type for the handful of `simple_ga` APIs that report
+/// runtime-data problems via `Result` instead of panicking, e.g.
+/// [`crate::ga::ga_iterator::GaIterator::try_next_generation`] and
+/// [`crate::util::try_coin_flip`]. Most of this crate still panics or
+/// `debug_assert`s on programmer error (an out-of-range ratio, a malformed
+/// distribution parameter) the way Rust APIs commonly do; this only covers
+/// paths a long-running embedded GA can hit from data it doesn't control at
+/// compile time, like a fitness function returning NaN or every action's
+/// weight decaying to zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Error {
+    /// A population contained a subject whose fitness compared unordered
+    /// (NaN) against another's, so sorting couldn't establish a total
+    /// order.
+    NonFiniteFitness,
+    /// A weighted sample had no candidates, or every candidate's weight was
+    /// zero, so no index could be drawn.
+    EmptyWeights,
+    /// A probability/odds value fell outside `0.0..=1.0`.
+    InvalidOdds(f64),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NonFiniteFitness => {
+                write!(f, "fitness comparison produced no ordering (NaN?)")
+            }
+            Error::EmptyWeights => {
+                write!(f, "no candidates to sample from: weights were empty or all zero")
+            }
+            Error::InvalidOdds(odds) => {
+                write!(f, "odds must be between 0.0 and 1.0 inclusively, got: {odds}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_messages_mention_the_offending_value() {
+        assert!(Error::InvalidOdds(1.5).to_string().contains("1.5"));
+        assert!(Error::NonFiniteFitness.to_string().contains("NaN"));
+        assert!(Error::EmptyWeights.to_string().contains("zero"));
+    }
+}
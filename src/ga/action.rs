@@ -1,14 +1,17 @@
 use std::marker::PhantomData;
 
-use crate::ga::dedupe::{DedupeAction, DedupeOther};
-use crate::ga::fitness::FitnessWrapped;
-use crate::ga::inflate::InflateTarget;
+use rand::Rng;
+
+use crate::ga::dedupe::{DedupeAction, DedupeOther, EmptyDedupe};
+use crate::ga::fitness::{Fitness, FitnessWrapped};
+use crate::ga::inflate::{EmptyInflate, InflateTarget};
 use crate::ga::mutation::{ApplyMutation, GenericMutator};
 use crate::ga::population::Population;
-use crate::ga::prune::{PruneAction, PruneOther};
+use crate::ga::prune::{EmptyPrune, PruneAction, PruneOther};
 use crate::ga::reproduction::{ApplyReproduction, GenericReproducer};
 use crate::ga::select::SelectOther;
 use crate::ga::{GaAction, GaContext, SampleSelf};
+use crate::util::rng;
 
 #[derive(Debug, Default, Copy, Clone)]
 pub struct EmptyAction<Subject>(PhantomData<Subject>);
@@ -16,11 +19,21 @@ pub struct EmptyAction<Subject>(PhantomData<Subject>);
 impl<Subject> GaAction for EmptyAction<Subject> {
     type Subject = ();
 
-    fn perform_action(&self, _context: &GaContext, _population: &mut Population<Self::Subject>) {
-        // no op
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("empty", context, population, |_population| {
+            // no op
+        });
     }
 }
 
+/// Runs prune, mutation, reproduction, dedupe, and inflate in that fixed
+/// order. `perform_action` wraps the whole sequence in a `"default_actions"`
+/// [`crate::ga::instrument_action`] span, and each stage's own
+/// `perform_action` (`PruneAction`, `GenericMutator`, `GenericReproducer`,
+/// `DedupeAction`, and whatever `Inflator` is configured) opens its own
+/// nested span the same way — so `RUST_LOG` plus a span-timing subscriber
+/// layer shows duration and population-size-before/after for every stage
+/// individually, not just the pipeline as a whole.
 #[derive(Clone)]
 pub struct DefaultActions<
     Subject,
@@ -73,15 +86,19 @@ where
     Dedupe: DedupeOther<Population<Subject>>,
     Inflator: InflateTarget<Params = GaContext, Target = Population<Subject>>
         + GaAction<Subject = Subject>,
+    GenericMutator<Mutator, Subject, MutatorActions>: GaAction<Subject = Subject>,
+    GenericReproducer<Reproducer, Selector, Subject, ReproducerActions>: GaAction<Subject = Subject>,
 {
     type Subject = Subject;
 
     fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
-        self.prune.perform_action(context, population);
-        self.mutation.perform_action(context, population);
-        self.reproduction.perform_action(context, population);
-        self.dedupe.perform_action(context, population);
-        self.inflate.perform_action(context, population);
+        crate::ga::instrument_action("default_actions", context, population, |population| {
+            self.prune.perform_action(context, population);
+            self.mutation.perform_action(context, population);
+            self.reproduction.perform_action(context, population);
+            self.dedupe.perform_action(context, population);
+            self.inflate.perform_action(context, population);
+        });
     }
 }
 
@@ -129,3 +146,417 @@ where
         }
     }
 }
+
+/// Builds a [`DefaultActions`] with `mutation`/`reproduction` required and
+/// `prune`/`dedupe`/`inflate` defaulting to no-ops ([`EmptyPrune`],
+/// [`EmptyDedupe`], [`EmptyInflate`]). `DefaultActions`'s [`Default`] impl
+/// needs every field's type to implement `Default`, including `Selector`
+/// and `Inflator`, which real ones almost never do (a selector usually holds
+/// a sample size, an inflator usually closes over a `create_subject_fn`),
+/// so that impl is effectively only usable with placeholder types. This
+/// builder only requires the two stages that actually define the GA's
+/// behavior up front, and lets `prune`/`dedupe`/`inflate` be skipped
+/// entirely when a no-op is fine.
+pub struct DefaultActionsBuilder<
+    Subject,
+    Mutator,
+    MutatorActions,
+    Selector,
+    Reproducer,
+    ReproducerActions,
+    Pruner = EmptyPrune,
+    Dedupe = EmptyDedupe,
+    Inflator = EmptyInflate<Subject>,
+> {
+    prune: Option<PruneAction<Subject, Pruner>>,
+    mutation: GenericMutator<Mutator, Subject, MutatorActions>,
+    reproduction: GenericReproducer<Reproducer, Selector, Subject, ReproducerActions>,
+    dedupe: Option<DedupeAction<Subject, Dedupe>>,
+    inflate: Option<Inflator>,
+}
+
+impl<Subject, Mutator, MutatorActions, Selector, Reproducer, ReproducerActions>
+    DefaultActionsBuilder<Subject, Mutator, MutatorActions, Selector, Reproducer, ReproducerActions>
+{
+    pub fn new(
+        mutation: GenericMutator<Mutator, Subject, MutatorActions>,
+        reproduction: GenericReproducer<Reproducer, Selector, Subject, ReproducerActions>,
+    ) -> Self {
+        Self {
+            prune: None,
+            mutation,
+            reproduction,
+            dedupe: None,
+            inflate: None,
+        }
+    }
+}
+
+impl<Subject, Mutator, MutatorActions, Selector, Reproducer, ReproducerActions, Pruner, Dedupe, Inflator>
+    DefaultActionsBuilder<
+        Subject,
+        Mutator,
+        MutatorActions,
+        Selector,
+        Reproducer,
+        ReproducerActions,
+        Pruner,
+        Dedupe,
+        Inflator,
+    >
+{
+    pub fn prune<NewPruner>(
+        self,
+        action: NewPruner,
+    ) -> DefaultActionsBuilder<
+        Subject,
+        Mutator,
+        MutatorActions,
+        Selector,
+        Reproducer,
+        ReproducerActions,
+        NewPruner,
+        Dedupe,
+        Inflator,
+    > {
+        DefaultActionsBuilder {
+            prune: Some(PruneAction::new(action)),
+            mutation: self.mutation,
+            reproduction: self.reproduction,
+            dedupe: self.dedupe,
+            inflate: self.inflate,
+        }
+    }
+
+    pub fn dedupe<NewDedupe>(
+        self,
+        action: NewDedupe,
+    ) -> DefaultActionsBuilder<
+        Subject,
+        Mutator,
+        MutatorActions,
+        Selector,
+        Reproducer,
+        ReproducerActions,
+        Pruner,
+        NewDedupe,
+        Inflator,
+    > {
+        DefaultActionsBuilder {
+            prune: self.prune,
+            mutation: self.mutation,
+            reproduction: self.reproduction,
+            dedupe: Some(DedupeAction::new(action)),
+            inflate: self.inflate,
+        }
+    }
+
+    pub fn inflate<NewInflator>(
+        self,
+        inflator: NewInflator,
+    ) -> DefaultActionsBuilder<
+        Subject,
+        Mutator,
+        MutatorActions,
+        Selector,
+        Reproducer,
+        ReproducerActions,
+        Pruner,
+        Dedupe,
+        NewInflator,
+    > {
+        DefaultActionsBuilder {
+            prune: self.prune,
+            mutation: self.mutation,
+            reproduction: self.reproduction,
+            dedupe: self.dedupe,
+            inflate: Some(inflator),
+        }
+    }
+
+    pub fn build(
+        self,
+    ) -> DefaultActions<Subject, Pruner, MutatorActions, Mutator, Selector, ReproducerActions, Reproducer, Dedupe, Inflator>
+    where
+        Pruner: Default,
+        Dedupe: Default,
+        Inflator: Default,
+    {
+        DefaultActions {
+            prune: self.prune.unwrap_or_else(|| PruneAction::new(Pruner::default())),
+            mutation: self.mutation,
+            reproduction: self.reproduction,
+            dedupe: self.dedupe.unwrap_or_else(|| DedupeAction::new(Dedupe::default())),
+            inflate: self.inflate.unwrap_or_default(),
+        }
+    }
+}
+
+/// Generates `lambda` offspring by mutating a uniformly-chosen parent from
+/// the current `mu` (the population's current size), evaluates them, and
+/// keeps the best `mu` out of parents-plus-offspring combined — classic
+/// `(mu + lambda)` evolution strategy replacement, where a good parent can
+/// survive indefinitely until an offspring beats it.
+pub struct MuPlusLambda<Subject> {
+    lambda: usize,
+    mutate: fn(&Subject) -> Subject,
+    fitness_fn: fn(&Subject) -> Fitness,
+}
+
+impl<Subject> MuPlusLambda<Subject> {
+    pub fn new(
+        lambda: usize,
+        mutate: fn(&Subject) -> Subject,
+        fitness_fn: fn(&Subject) -> Fitness,
+    ) -> Self {
+        Self {
+            lambda,
+            mutate,
+            fitness_fn,
+        }
+    }
+
+    fn generate_offspring(&self, population: &Population<Subject>) -> Vec<FitnessWrapped<Subject>> {
+        let mu = population.subjects.len();
+        let mut rand = rng::thread_rng();
+        (0..self.lambda)
+            .map(|_| {
+                let parent = &population.subjects[rand.gen_range(0..mu)];
+                let child = (self.mutate)(parent.subject_ref());
+                let fitness = (self.fitness_fn)(&child);
+                FitnessWrapped::new(child, fitness)
+            })
+            .collect()
+    }
+}
+
+impl<Subject> GaAction for MuPlusLambda<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("mu_plus_lambda", context, population, |population| {
+            let mu = population.subjects.len();
+            let offspring = self.generate_offspring(population);
+            population.subjects.extend(offspring);
+            population.subjects.sort_by(|a, b| {
+                a.fitness()
+                    .partial_cmp(&b.fitness())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            population.subjects.truncate(mu);
+        });
+    }
+}
+
+/// Like [`MuPlusLambda`], but parents never survive: the next generation is
+/// the best `mu` of the `lambda` offspring alone — classic `(mu, lambda)`
+/// evolution strategy replacement, which forgets a parent no matter how fit
+/// it was. Requires `lambda >= mu` to keep the population size from
+/// shrinking; with `lambda < mu` only `lambda` offspring exist to fill the
+/// `mu` slots and the population shrinks to `lambda`.
+pub struct MuCommaLambda<Subject> {
+    lambda: usize,
+    mutate: fn(&Subject) -> Subject,
+    fitness_fn: fn(&Subject) -> Fitness,
+}
+
+impl<Subject> MuCommaLambda<Subject> {
+    pub fn new(
+        lambda: usize,
+        mutate: fn(&Subject) -> Subject,
+        fitness_fn: fn(&Subject) -> Fitness,
+    ) -> Self {
+        Self {
+            lambda,
+            mutate,
+            fitness_fn,
+        }
+    }
+
+    fn generate_offspring(&self, population: &Population<Subject>) -> Vec<FitnessWrapped<Subject>> {
+        let mu = population.subjects.len();
+        let mut rand = rng::thread_rng();
+        (0..self.lambda)
+            .map(|_| {
+                let parent = &population.subjects[rand.gen_range(0..mu)];
+                let child = (self.mutate)(parent.subject_ref());
+                let fitness = (self.fitness_fn)(&child);
+                FitnessWrapped::new(child, fitness)
+            })
+            .collect()
+    }
+}
+
+impl<Subject> GaAction for MuCommaLambda<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("mu_comma_lambda", context, population, |population| {
+            let mu = population.subjects.len();
+            let mut offspring = self.generate_offspring(population);
+            offspring.sort_by(|a, b| {
+                a.fitness()
+                    .partial_cmp(&b.fitness())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            offspring.truncate(mu);
+            population.subjects = offspring;
+        });
+    }
+}
+
+#[cfg(test)]
+mod es_tests {
+    use super::*;
+
+    fn population(values: Vec<i32>) -> Population<i32> {
+        let subjects = values
+            .into_iter()
+            .map(|v| FitnessWrapped::new(v, v as Fitness))
+            .collect();
+        Population {
+            pool_size: 0,
+            subjects,
+            memory_budget_bytes: None,
+        }
+    }
+
+    fn increment(subject: &i32) -> i32 {
+        subject + 1
+    }
+    fn identity_fitness(subject: &i32) -> Fitness {
+        *subject as Fitness
+    }
+
+    #[test]
+    fn test_mu_plus_lambda_keeps_mu_sized_population() {
+        let mut population = population(vec![0, 1, 2]);
+        let action = MuPlusLambda::new(5, increment, identity_fitness);
+        action.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects.len(), 3);
+    }
+
+    #[test]
+    fn test_mu_plus_lambda_keeps_the_best_parent_when_no_offspring_beats_it() {
+        let mut population = population(vec![0, 5, 5]);
+        let action = MuPlusLambda::new(3, increment, identity_fitness);
+        action.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects[0].fitness(), 0.0);
+    }
+
+    #[test]
+    fn test_mu_comma_lambda_discards_parents_even_if_fitter() {
+        let mut population = population(vec![0, 0, 0]);
+        let action = MuCommaLambda::new(3, increment, identity_fitness);
+        action.perform_action(&GaContext::default(), &mut population);
+        // every offspring is a mutated (incremented) parent, so the
+        // comma-selected survivors can never include the original fitness 0.
+        assert!(population.subjects.iter().all(|s| s.fitness() > 0.0));
+    }
+
+    #[test]
+    fn test_mu_comma_lambda_shrinks_when_lambda_is_less_than_mu() {
+        let mut population = population(vec![0, 1, 2]);
+        let action = MuCommaLambda::new(1, increment, identity_fitness);
+        action.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects.len(), 1);
+    }
+}
+
+#[cfg(test)]
+mod default_actions_builder_tests {
+    use crate::ga::dedupe::EmptyDedupe;
+    use crate::ga::prune::PruneSingleFrontSkipFirst;
+    use crate::ga::reproduction::{ApplyReproductionOptions, ReproductionResult};
+    use crate::ga::select::SelectAll;
+    use crate::ga::WeightedActionsSampleOne;
+
+    use super::*;
+
+    #[derive(Debug, Copy, Clone)]
+    struct Noop;
+
+    impl ApplyMutation for Noop {
+        type Subject = i32;
+        fn apply(&self, _context: &GaContext, subject: &Self::Subject) -> Self::Subject {
+            *subject
+        }
+        fn fitness(subject: &Self::Subject) -> Fitness {
+            *subject as Fitness
+        }
+    }
+
+    impl ApplyReproduction for Noop {
+        type Subject = i32;
+        fn apply(
+            &self,
+            _context: &GaContext,
+            subject_a: &Self::Subject,
+            _subject_b: &Self::Subject,
+        ) -> Option<ReproductionResult<Self::Subject>> {
+            Some(ReproductionResult::Single(*subject_a))
+        }
+        fn fitness(subject: &Self::Subject) -> Fitness {
+            *subject as Fitness
+        }
+    }
+
+    fn builder() -> DefaultActionsBuilder<i32, Noop, WeightedActionsSampleOne<Noop>, SelectAll, Noop, WeightedActionsSampleOne<Noop>> {
+        DefaultActionsBuilder::new(
+            GenericMutator::new(crate::ga::mutation::ApplyMutationOptions {
+                overall_mutation_chance: 0.0,
+                mutation_actions: WeightedActionsSampleOne(vec![]),
+                clone_on_mutation: false,
+                chunk_size: None,
+            }),
+            GenericReproducer::new(ApplyReproductionOptions {
+                selector: SelectAll,
+                overall_reproduction_chance: 0.0,
+                reproduction_actions: WeightedActionsSampleOne(vec![]),
+            }),
+        )
+    }
+
+    #[test]
+    fn test_build_with_no_overrides_uses_empty_stage_no_ops() {
+        let actions = builder().build();
+        let mut population = Population {
+            subjects: vec![FitnessWrapped::new(1, 1.0), FitnessWrapped::new(2, 2.0)],
+            pool_size: 2,
+            memory_budget_bytes: None,
+        };
+        actions.perform_action(&GaContext::default(), &mut population);
+        // prune/dedupe/inflate all no-op, and mutation/reproduction chances are 0.0.
+        assert_eq!(population.subjects.len(), 2);
+    }
+
+    #[test]
+    fn test_build_with_prune_override_is_applied() {
+        let actions = builder().prune(PruneSingleFrontSkipFirst).build();
+        let mut population = Population {
+            subjects: vec![
+                FitnessWrapped::new(1, 1.0),
+                FitnessWrapped::new(2, 2.0),
+                FitnessWrapped::new(3, 3.0),
+            ],
+            pool_size: 3,
+            memory_budget_bytes: None,
+        };
+        actions.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects.len(), 2);
+    }
+
+    #[test]
+    fn test_build_with_dedupe_override_uses_provided_dedupe() {
+        // EmptyDedupe never removes anything, matching the default, so this
+        // just confirms `.dedupe(..)` overrides without breaking `.build()`.
+        let actions = builder().dedupe(EmptyDedupe).build();
+        let mut population = Population {
+            subjects: vec![FitnessWrapped::new(1, 1.0)],
+            pool_size: 1,
+            memory_budget_bytes: None,
+        };
+        actions.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects.len(), 1);
+    }
+}
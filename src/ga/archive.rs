@@ -0,0 +1,153 @@
+//! An external Pareto archive: a bounded set of non-dominated solutions
+//! maintained across generations, independent of the live population (which
+//! keeps evolving and can lose good solutions to genetic drift, pruning, or
+//! simply not fitting in `pool_size`).
+//!
+//! Truncation when the archive exceeds capacity removes the most crowded
+//! member (smallest [`crate::ga::multi_objective::crowding_distance`]) one
+//! at a time. Full SPEA2 truncation breaks remaining ties with a k-th
+//! nearest-neighbor distance in objective space rather than NSGA-II's
+//! front-relative crowding distance; this reuses the latter since it's
+//! already implemented, is a fine approximation for picking *a* crowded
+//! point to drop, and avoids maintaining two divergent distance metrics in
+//! the crate for a difference that only matters in exact tie-breaking.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::ga::multi_objective::{crowding_distance, dominates, MultiFitness};
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+
+pub struct ParetoArchive<Subject> {
+    capacity: usize,
+    members: Vec<(Subject, MultiFitness)>,
+}
+
+impl<Subject: Clone> ParetoArchive<Subject> {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, members: vec![] }
+    }
+
+    pub fn members(&self) -> &[(Subject, MultiFitness)] {
+        &self.members
+    }
+
+    /// Merges `candidates` in, drops anything now dominated, and truncates
+    /// back down to `capacity` if the non-dominated set grew past it.
+    pub fn update(&mut self, candidates: impl IntoIterator<Item = (Subject, MultiFitness)>) {
+        self.members.extend(candidates);
+        self.prune_dominated();
+        self.truncate_to_capacity();
+    }
+
+    fn prune_dominated(&mut self) {
+        let objectives: Vec<MultiFitness> = self.members.iter().map(|(_, o)| o.clone()).collect();
+        let non_dominated: Vec<bool> = (0..objectives.len())
+            .map(|i| {
+                !objectives
+                    .iter()
+                    .enumerate()
+                    .any(|(j, other)| j != i && dominates(other, &objectives[i]))
+            })
+            .collect();
+        let mut index = 0;
+        self.members.retain(|_| {
+            let keep = non_dominated[index];
+            index += 1;
+            keep
+        });
+    }
+
+    fn truncate_to_capacity(&mut self) {
+        while self.members.len() > self.capacity {
+            let objectives: Vec<MultiFitness> = self.members.iter().map(|(_, o)| o.clone()).collect();
+            let indices: Vec<usize> = (0..objectives.len()).collect();
+            let distances = crowding_distance(&indices, &objectives);
+            let most_crowded = distances
+                .iter()
+                .enumerate()
+                .min_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(index, _)| index)
+                .expect("members is non-empty while len() > capacity");
+            self.members.remove(most_crowded);
+        }
+    }
+}
+
+/// Updates a shared [`ParetoArchive`] from the current population each
+/// generation. The `Rc<RefCell<_>>` is shared with the caller so the final
+/// front can be read back via [`ParetoArchive::members`] once the runner
+/// finishes.
+pub struct ArchiveUpdateAction<Subject> {
+    archive: Rc<RefCell<ParetoArchive<Subject>>>,
+    objectives: fn(&Subject) -> MultiFitness,
+}
+
+impl<Subject> ArchiveUpdateAction<Subject> {
+    pub fn new(archive: Rc<RefCell<ParetoArchive<Subject>>>, objectives: fn(&Subject) -> MultiFitness) -> Self {
+        Self { archive, objectives }
+    }
+}
+
+impl<Subject: Clone> GaAction for ArchiveUpdateAction<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("archive_update", context, population, |population| {
+            let candidates = population
+                .subjects
+                .iter()
+                .map(|subject| (subject.subject_ref().clone(), (self.objectives)(subject.subject_ref())));
+            self.archive.borrow_mut().update(candidates);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::fitness::FitnessWrapped;
+
+    fn population(values: Vec<i32>) -> Population<i32> {
+        let subjects = values.into_iter().map(|v| FitnessWrapped::new(v, v as f64)).collect();
+        Population { pool_size: 0, subjects, memory_budget_bytes: None }
+    }
+
+    fn objectives(subject: &i32) -> MultiFitness {
+        vec![*subject as f64, (10 - subject) as f64]
+    }
+
+    #[test]
+    fn test_update_keeps_only_non_dominated_members() {
+        let mut archive = ParetoArchive::new(10);
+        archive.update(vec![(1, objectives(&1)), (5, objectives(&5)), (9, objectives(&9))]);
+        // every point on this trade-off curve is non-dominated
+        assert_eq!(archive.members().len(), 3);
+    }
+
+    #[test]
+    fn test_dominated_candidate_is_dropped() {
+        let mut archive = ParetoArchive::new(10);
+        archive.update(vec![(5, vec![1.0, 1.0])]);
+        archive.update(vec![(6, vec![2.0, 2.0])]); // dominated by the existing member
+        assert_eq!(archive.members().len(), 1);
+        assert_eq!(archive.members()[0].0, 5);
+    }
+
+    #[test]
+    fn test_truncation_respects_capacity() {
+        let mut archive = ParetoArchive::new(2);
+        archive.update((0..10).map(|n| (n, objectives(&n))));
+        assert_eq!(archive.members().len(), 2);
+    }
+
+    #[test]
+    fn test_archive_update_action_populates_the_shared_archive() {
+        let archive = Rc::new(RefCell::new(ParetoArchive::new(10)));
+        let mut population = population(vec![1, 5, 9]);
+        let action = ArchiveUpdateAction::new(archive.clone(), objectives);
+        action.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(archive.borrow().members().len(), 3);
+    }
+}
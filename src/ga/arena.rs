@@ -0,0 +1,99 @@
+//! Optional slab-backed storage for subjects that are created and destroyed
+//! in bulk every generation, so long-running evolutions reuse storage slots
+//! instead of handing every subject to the global allocator individually.
+//!
+//! Wiring this fully into [`Population`](crate::ga::population::Population),
+//! whose entries are shared via `Arc` for cheap cloning across selection and
+//! reproduction, would require reworking that ownership model; this provides
+//! the recycling slab as a standalone building block that callers can use to
+//! stage subjects (e.g. inside a `create_subject_fn`) before wrapping them.
+
+use slab::Slab;
+
+/// A key identifying a subject's slot in a [`SubjectArena`]. Stable until the
+/// slot is removed and the key is recycled.
+pub type ArenaKey = usize;
+
+#[derive(Debug, Clone, Default)]
+pub struct SubjectArena<Subject> {
+    slab: Slab<Subject>,
+}
+
+impl<Subject> SubjectArena<Subject> {
+    pub fn new() -> Self {
+        Self { slab: Slab::new() }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slab: Slab::with_capacity(capacity),
+        }
+    }
+
+    /// Inserts a subject, reusing a freed slot if one is available.
+    pub fn insert(&mut self, subject: Subject) -> ArenaKey {
+        self.slab.insert(subject)
+    }
+
+    /// Removes and returns the subject at `key`, freeing the slot for reuse.
+    pub fn remove(&mut self, key: ArenaKey) -> Subject {
+        self.slab.remove(key)
+    }
+
+    pub fn get(&self, key: ArenaKey) -> Option<&Subject> {
+        self.slab.get(key)
+    }
+
+    pub fn get_mut(&mut self, key: ArenaKey) -> Option<&mut Subject> {
+        self.slab.get_mut(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.slab.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slab.is_empty()
+    }
+
+    /// Removes every subject, retaining the underlying storage for reuse by
+    /// the next generation's inserts.
+    pub fn clear(&mut self) {
+        self.slab.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::arena::SubjectArena;
+
+    #[test]
+    fn test_insert_get_remove() {
+        let mut arena = SubjectArena::new();
+        let key = arena.insert(42);
+        assert_eq!(arena.get(key), Some(&42));
+        assert_eq!(arena.remove(key), 42);
+        assert_eq!(arena.get(key), None);
+    }
+
+    #[test]
+    fn test_clear_retains_capacity_for_reuse() {
+        let mut arena = SubjectArena::with_capacity(4);
+        arena.insert(1);
+        arena.insert(2);
+        assert_eq!(arena.len(), 2);
+        arena.clear();
+        assert!(arena.is_empty());
+        let key = arena.insert(3);
+        assert_eq!(arena.get(key), Some(&3));
+    }
+
+    #[test]
+    fn test_slot_reuse_after_remove() {
+        let mut arena = SubjectArena::new();
+        let a = arena.insert(1);
+        arena.remove(a);
+        let b = arena.insert(2);
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,132 @@
+use std::hash::Hash;
+
+use crate::ga::fitness::{AsyncFit, Fit, Fitness, FitnessWrapped};
+use crate::ga::ga_runner::{ga_runner, GaRunResult, GaRunnerOptions};
+use crate::ga::population::Population;
+use crate::ga::subject::GaSubject;
+use crate::ga::{GaAction, GeneticAlgorithmOptions};
+
+/// Builds a [`Population`] by awaiting every subject's
+/// [`AsyncFit::measure_async`] concurrently on the current tokio runtime,
+/// instead of measuring each one inline the way
+/// [`crate::ga::create_population_pool`] does. `Subject: 'static` is
+/// required because each evaluation runs as its own `tokio::spawn`ed task.
+pub async fn evaluate_population_async<Subject>(subjects: Vec<Subject>) -> Population<Subject>
+where
+    Subject: AsyncFit + Send + Sync + 'static,
+{
+    let pool_size = subjects.len();
+    let tasks: Vec<_> = subjects
+        .into_iter()
+        .map(|subject| {
+            tokio::spawn(async move {
+                let fitness = subject.measure_async().await;
+                FitnessWrapped::new(subject, fitness)
+            })
+        })
+        .collect();
+    let mut subjects = Vec::with_capacity(pool_size);
+    for task in tasks {
+        subjects.push(task.await.expect("fitness evaluation task panicked"));
+    }
+    Population {
+        subjects,
+        pool_size,
+        memory_budget_bytes: None,
+    }
+}
+
+/// Async counterpart to [`crate::ga::ga_runner::GaRunner`]/[`ga_runner`] for
+/// subjects whose fitness comes from a network service or database
+/// round-trip instead of a synchronous, in-process computation.
+///
+/// Only the initial population's fitness is evaluated asynchronously (via
+/// [`evaluate_population_async`]); once that's built, the run hands off to
+/// the exact same synchronous generation loop every other runner in this
+/// crate uses. Mutation/reproduction/inflate actions still measure
+/// offspring fitness through the synchronous [`Fit`] trait — making every
+/// operator async as well would be a much larger, breaking change to
+/// [`crate::ga::mutation::ApplyMutation`]/[`crate::ga::reproduction::ApplyReproduction`],
+/// so subjects using this runner are expected to implement both traits:
+/// `AsyncFit` for the initial batch, `Fit` for whatever an action computes
+/// afterward (e.g. wrapping the same I/O in a blocking call, or a cheaper
+/// synchronous approximation).
+pub struct AsyncGaRunner<Subject>
+where
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq,
+{
+    runner_options: GaRunnerOptions<Subject>,
+}
+
+impl<Subject> AsyncGaRunner<Subject>
+where
+    Subject: GaSubject + AsyncFit + Fit<Fitness> + Hash + PartialEq + Eq + Send + Sync + 'static,
+{
+    pub fn new(runner_options: GaRunnerOptions<Subject>) -> Self {
+        Self { runner_options }
+    }
+
+    /// Awaits [`evaluate_population_async`] to build the initial population
+    /// from `subjects`, then runs the rest of the GA synchronously via
+    /// [`ga_runner`].
+    pub async fn run<Actions>(
+        self,
+        ga_options: GeneticAlgorithmOptions<Actions>,
+        subjects: Vec<Subject>,
+    ) -> GaRunResult<Subject>
+    where
+        Actions: GaAction<Subject = Subject> + Send,
+    {
+        let population = evaluate_population_async(subjects).await;
+        ga_runner(ga_options, self.runner_options, population)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::async_runner::AsyncGaRunner;
+    use crate::ga::fitness::{AsyncFit, Fit, Fitness};
+    use crate::ga::population::Population;
+    use crate::ga::subject::GaSubject;
+    use crate::ga::{GaAction, GaContext, GeneticAlgorithmOptions};
+
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    struct Num(u32);
+
+    impl GaSubject for Num {}
+
+    impl Fit<Fitness> for Num {
+        fn measure(&self) -> Fitness {
+            self.0 as Fitness
+        }
+    }
+
+    impl AsyncFit for Num {
+        async fn measure_async(&self) -> Fitness {
+            self.0 as Fitness
+        }
+    }
+
+    #[derive(Debug, Default, Clone)]
+    struct NoopAction;
+
+    impl GaAction for NoopAction {
+        type Subject = Num;
+        fn perform_action(&self, _context: &GaContext, _population: &mut Population<Num>) {}
+    }
+
+    #[tokio::test]
+    async fn test_run_evaluates_initial_population_via_async_fit() {
+        let runner = AsyncGaRunner::new(Default::default());
+        let options = GeneticAlgorithmOptions {
+            fitness_initial_to_target_range: 100.0..1.0,
+            fitness_range: 0.0..100.0,
+            target_fitness_epsilon: 0.0,
+            actions: NoopAction,
+            seed: None,
+        };
+        let subjects = vec![Num(3), Num(1), Num(2)];
+        let result = runner.run(options, subjects).await;
+        assert_eq!(result.best_fitness, Some(1.0));
+    }
+}
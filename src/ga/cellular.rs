@@ -0,0 +1,208 @@
+//! Cellular GA: subjects live on a 2D grid rather than an unordered pool, and
+//! reproduction only considers each cell's local neighborhood instead of the
+//! whole population. The topology is encoded directly in [`GridTopology`]
+//! (which `Population` index maps to which grid cell, and which cells count
+//! as neighbors), since that's information the reproducer needs and no
+//! existing crate type carries.
+
+use rand::seq::SliceRandom;
+
+use crate::ga::fitness::{Fitness, FitnessWrapped};
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+use crate::util::rng;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NeighborhoodShape {
+    /// Up/down/left/right (4 neighbors).
+    VonNeumann,
+    /// The 8 surrounding cells.
+    Moore,
+}
+
+/// Maps `Population::subjects` indices onto a toroidal (wrap-around)
+/// `width x height` grid.
+#[derive(Debug, Copy, Clone)]
+pub struct GridTopology {
+    pub width: usize,
+    pub height: usize,
+    pub shape: NeighborhoodShape,
+}
+
+impl GridTopology {
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> usize {
+        (y % self.height) * self.width + (x % self.width)
+    }
+
+    /// Population indices of every neighbor of cell `index`, per
+    /// [`Self::shape`]. Wraps around grid edges.
+    pub fn neighbors(&self, index: usize) -> Vec<usize> {
+        let x = index % self.width;
+        let y = index / self.width;
+        let offsets: &[(isize, isize)] = match self.shape {
+            NeighborhoodShape::VonNeumann => &[(0, -1), (0, 1), (-1, 0), (1, 0)],
+            NeighborhoodShape::Moore => &[
+                (-1, -1), (0, -1), (1, -1),
+                (-1, 0), (1, 0),
+                (-1, 1), (0, 1), (1, 1),
+            ],
+        };
+        offsets
+            .iter()
+            .map(|&(dx, dy)| {
+                let nx = (x as isize + dx).rem_euclid(self.width as isize) as usize;
+                let ny = (y as isize + dy).rem_euclid(self.height as isize) as usize;
+                self.index_of(nx, ny)
+            })
+            .collect()
+    }
+}
+
+/// Whether a generation's cells are all updated from a single consistent
+/// snapshot, or one at a time with each update immediately visible to the
+/// next cell processed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// Every cell's neighbors are read from the population as it was at the
+    /// start of the generation; all updates are committed together at the
+    /// end.
+    Synchronous,
+    /// Cells are updated one at a time, in a random order each generation,
+    /// and see prior updates from the same pass.
+    Asynchronous,
+}
+
+/// Replaces each cell with the offspring of itself and its fittest neighbor,
+/// mutated, evaluated, and kept only if it's at least as good as the cell it
+/// replaces. `population.subjects.len()` must equal `topology.len()`.
+pub struct CellularGaAction<Subject> {
+    topology: GridTopology,
+    update_mode: UpdateMode,
+    reproduce: fn(&Subject, &Subject) -> Subject,
+    mutate: fn(&Subject) -> Subject,
+    fitness_fn: fn(&Subject) -> Fitness,
+}
+
+impl<Subject> CellularGaAction<Subject> {
+    pub fn new(
+        topology: GridTopology,
+        update_mode: UpdateMode,
+        reproduce: fn(&Subject, &Subject) -> Subject,
+        mutate: fn(&Subject) -> Subject,
+        fitness_fn: fn(&Subject) -> Fitness,
+    ) -> Self {
+        Self { topology, update_mode, reproduce, mutate, fitness_fn }
+    }
+
+    fn offspring_for(&self, population: &Population<Subject>, index: usize) -> FitnessWrapped<Subject> {
+        let cell = &population.subjects[index];
+        let best_neighbor = self
+            .topology
+            .neighbors(index)
+            .into_iter()
+            .map(|neighbor_index| &population.subjects[neighbor_index])
+            .min_by(|a, b| a.fitness().partial_cmp(&b.fitness()).unwrap_or(std::cmp::Ordering::Equal));
+        let Some(best_neighbor) = best_neighbor else {
+            return cell.clone();
+        };
+        let child = (self.reproduce)(cell.subject_ref(), best_neighbor.subject_ref());
+        let child = (self.mutate)(&child);
+        let child_fitness = (self.fitness_fn)(&child);
+        if child_fitness <= cell.fitness() {
+            FitnessWrapped::new(child, child_fitness)
+        } else {
+            cell.clone()
+        }
+    }
+}
+
+impl<Subject: Clone> GaAction for CellularGaAction<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("cellular", context, population, |population| {
+            if population.subjects.len() != self.topology.len() {
+                return;
+            }
+            match self.update_mode {
+                UpdateMode::Synchronous => {
+                    let next_generation: Vec<FitnessWrapped<Subject>> = (0..population.subjects.len())
+                        .map(|index| self.offspring_for(population, index))
+                        .collect();
+                    population.subjects = next_generation;
+                }
+                UpdateMode::Asynchronous => {
+                    let mut order: Vec<usize> = (0..population.subjects.len()).collect();
+                    order.shuffle(&mut rng::thread_rng());
+                    for index in order {
+                        population.subjects[index] = self.offspring_for(population, index);
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn population(values: Vec<i32>) -> Population<i32> {
+        let subjects = values.into_iter().map(|v| FitnessWrapped::new(v, v as Fitness)).collect();
+        Population { pool_size: 0, subjects, memory_budget_bytes: None }
+    }
+
+    fn average(a: &i32, b: &i32) -> i32 {
+        (a + b) / 2
+    }
+    fn identity(subject: &i32) -> i32 {
+        *subject
+    }
+    fn fitness(subject: &i32) -> Fitness {
+        *subject as Fitness
+    }
+
+    #[test]
+    fn test_von_neumann_neighbors_on_a_3x3_torus() {
+        let topology = GridTopology { width: 3, height: 3, shape: NeighborhoodShape::VonNeumann };
+        let mut neighbors = topology.neighbors(0);
+        neighbors.sort();
+        assert_eq!(neighbors, vec![1, 2, 3, 6]); // wraps to right edge and bottom edge
+    }
+
+    #[test]
+    fn test_moore_neighbors_has_eight_entries_on_a_3x3_torus() {
+        let topology = GridTopology { width: 3, height: 3, shape: NeighborhoodShape::Moore };
+        assert_eq!(topology.neighbors(4).len(), 8);
+    }
+
+    #[test]
+    fn test_synchronous_update_moves_toward_the_fittest_neighbor() {
+        // 4x1 torus: [10, 0, 0, 0]; cell 1's neighbors are itself (the vertical
+        // offsets wrap back onto row 0), cell 0 (10) and cell 2 (0).
+        let topology = GridTopology { width: 4, height: 1, shape: NeighborhoodShape::VonNeumann };
+        let mut population = population(vec![10, 0, 0, 0]);
+        let action = CellularGaAction::new(topology, UpdateMode::Synchronous, average, identity, fitness);
+        action.perform_action(&GaContext::default(), &mut population);
+        // the fittest (lowest-fitness) neighbor is cell 1 itself, so the child is
+        // average(0, 0) = 0, which greedy replacement keeps since it is at least as good.
+        assert_eq!(*population.subjects[1].subject_ref(), 0);
+    }
+
+    #[test]
+    fn test_mismatched_population_size_is_a_no_op() {
+        let topology = GridTopology { width: 2, height: 2, shape: NeighborhoodShape::VonNeumann };
+        let mut population = population(vec![1, 2, 3]);
+        let action = CellularGaAction::new(topology, UpdateMode::Synchronous, average, identity, fitness);
+        action.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects.len(), 3);
+    }
+}
@@ -0,0 +1,156 @@
+use std::fs::File;
+use std::io;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ga::fitness::Fitness;
+use crate::ga::ga_iterator::GaIterState;
+use crate::ga::lineage::Genealogy;
+use crate::ga::population::Population;
+use crate::ga::GaContext;
+
+/// Bumped whenever the on-disk layout of [`Checkpoint`] changes in a way that
+/// breaks older files, so [`read_checkpoint`] callers can detect and reject
+/// checkpoints written by an incompatible version.
+pub const CHECKPOINT_FORMAT_VERSION: u32 = 1;
+
+/// A versioned, serializable snapshot of a [`GaIterState`], suitable for
+/// resuming a long-running `GaRunner` loop from disk via `GaRunner::resume`.
+/// `GaRunnerOptions::checkpoint` writes snapshots of this shape periodically
+/// during a run; one can also be taken by hand from inside the
+/// `before_each_generation`/`after_each_generation` hooks, where a
+/// `&mut GaIterState` is already available.
+///
+/// `context` embeds the run's [`GaContext`] but its `rng` field is skipped on
+/// (de)serialization, since `StdRng` has no portable serialized form; a
+/// resumed run gets a fresh, OS-seeded RNG rather than a save of the one it
+/// checkpointed with. Free-standing random draws that go through
+/// `crate::util::rng::thread_rng` are not captured either: a checkpoint holds
+/// no state or seed for that generator, whether it is OS-seeded, reseeded via
+/// `crate::util::rng::seed_thread_rng`, or the `deterministic-rng` feature's
+/// `simple_ga_internal_lib::test_rng::MockThreadRng`. A resumed run is
+/// therefore not bit-for-bit reproducible from its pre-checkpoint RNG draws —
+/// only the population and iteration state are restored.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Checkpoint<Subject> {
+    pub format_version: u32,
+    /// Caller-supplied hash of the `GeneticAlgorithmOptions` the run used, so
+    /// [`read_checkpoint`] callers can refuse to resume with mismatched
+    /// options. `None` if the caller didn't provide one.
+    pub options_digest: Option<u64>,
+    pub context: GaContext,
+    pub current_fitness: Option<Fitness>,
+    pub reverse_mode_enabled: Option<bool>,
+    pub population: Population<Subject>,
+}
+
+impl<Subject: Clone> Checkpoint<Subject> {
+    pub fn from_state(state: &GaIterState<Subject>, options_digest: Option<u64>) -> Self {
+        Self {
+            format_version: CHECKPOINT_FORMAT_VERSION,
+            options_digest,
+            context: state.context().clone(),
+            current_fitness: state.current_fitness,
+            reverse_mode_enabled: state.reverse_mode_enabled,
+            population: state.population.clone(),
+        }
+    }
+}
+
+impl<Subject> Checkpoint<Subject> {
+    pub fn into_state(self) -> GaIterState<Subject> {
+        GaIterState {
+            context: self.context,
+            current_fitness: self.current_fitness,
+            reverse_mode_enabled: self.reverse_mode_enabled,
+            termination_reason: None,
+            population: self.population,
+            // Not captured by `Checkpoint` (see its docs re: RNG state); a
+            // resumed run starts a fresh ancestry DAG rather than one that's
+            // silently missing everything before the checkpoint.
+            genealogy: Genealogy::new(),
+        }
+    }
+}
+
+pub fn write_checkpoint<Subject: Serialize>(
+    checkpoint: &Checkpoint<Subject>,
+    path: impl AsRef<Path>,
+) -> io::Result<()> {
+    let file = File::create(path)?;
+    serde_json::to_writer(BufWriter::new(file), checkpoint)?;
+    Ok(())
+}
+
+pub fn read_checkpoint<Subject: for<'de> Deserialize<'de>>(
+    path: impl AsRef<Path>,
+) -> io::Result<Checkpoint<Subject>> {
+    let file = File::open(path)?;
+    let checkpoint = serde_json::from_reader(BufReader::new(file))?;
+    Ok(checkpoint)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::checkpoint::{write_checkpoint, Checkpoint, CHECKPOINT_FORMAT_VERSION};
+    use crate::ga::fitness::FitnessWrapped;
+    use crate::ga::ga_iterator::GaIterState;
+    use crate::ga::population::Population;
+    use crate::ga::GaContext;
+
+    fn sample_state() -> GaIterState<u32> {
+        let population = Population {
+            pool_size: 2,
+            subjects: vec![FitnessWrapped::new(1, 1.0), FitnessWrapped::new(2, 2.0)],
+            memory_budget_bytes: None,
+        };
+        GaIterState::new(GaContext::new(3), population)
+    }
+
+    #[test]
+    fn test_checkpoint_round_trips_through_json() {
+        let state = sample_state();
+        let checkpoint = Checkpoint::from_state(&state, Some(42));
+        let json = serde_json::to_vec(&checkpoint).unwrap();
+        let round_tripped: Checkpoint<u32> = serde_json::from_slice(&json).unwrap();
+
+        assert_eq!(round_tripped.format_version, CHECKPOINT_FORMAT_VERSION);
+        assert_eq!(round_tripped.options_digest, Some(42));
+        assert_eq!(round_tripped.context.generation, 3);
+        assert_eq!(
+            round_tripped.population.subjects,
+            state.population.subjects
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_into_state_preserves_fields() {
+        let mut state = sample_state();
+        state.current_fitness = Some(7.0);
+        let checkpoint = Checkpoint::from_state(&state, None);
+        let restored = checkpoint.into_state();
+
+        assert_eq!(restored.context().generation, 3);
+        assert_eq!(restored.current_fitness, Some(7.0));
+        assert_eq!(restored.population.subjects, state.population.subjects);
+    }
+
+    #[test]
+    fn test_write_checkpoint_then_read_checkpoint_round_trips_via_disk() {
+        let state = sample_state();
+        let checkpoint = Checkpoint::from_state(&state, Some(7));
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_checkpoint_test_{:?}.json",
+            std::thread::current().id()
+        ));
+
+        write_checkpoint(&checkpoint, &path).unwrap();
+        let round_tripped: Checkpoint<u32> = super::read_checkpoint(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(round_tripped.context.generation, 3);
+        assert_eq!(round_tripped.population.subjects, state.population.subjects);
+    }
+}
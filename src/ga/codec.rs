@@ -0,0 +1,84 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Turns a subject into bytes and back, so a single best-of-run genome can be
+/// written to disk (e.g. by [`crate::ga::ga_runner::GaRunnerOptions::export_best_every`])
+/// and later read back in as a seed subject for a new run.
+///
+/// A `bincode`-backed codec was planned alongside [`JsonCodec`] per the
+/// originating request, but the `bincode` crate available to this workspace
+/// is a squatted placeholder (`compile_error!` on use, no real API) rather
+/// than the real `bincode-org/bincode` crate, so that half is scoped out
+/// until a usable version is available. [`JsonCodec`] covers both halves of
+/// the request (export and re-import) on its own.
+pub trait SubjectCodec<Subject> {
+    fn encode(&self, subject: &Subject) -> io::Result<Vec<u8>>;
+    fn decode(&self, bytes: &[u8]) -> io::Result<Subject>;
+}
+
+/// [`SubjectCodec`] backed by `serde_json`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonCodec;
+
+impl<Subject> SubjectCodec<Subject> for JsonCodec
+where
+    Subject: serde::Serialize + serde::de::DeserializeOwned,
+{
+    fn encode(&self, subject: &Subject) -> io::Result<Vec<u8>> {
+        serde_json::to_vec(subject).map_err(io::Error::other)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> io::Result<Subject> {
+        serde_json::from_slice(bytes).map_err(io::Error::other)
+    }
+}
+
+/// Encodes `subject` with `codec` and writes it to `path`, overwriting any
+/// existing file.
+pub fn write_subject_file<Subject>(
+    path: impl AsRef<Path>,
+    subject: &Subject,
+    codec: &impl SubjectCodec<Subject>,
+) -> io::Result<()> {
+    let bytes = codec.encode(subject)?;
+    fs::write(path, bytes)
+}
+
+/// Reads a subject previously written by [`write_subject_file`], to seed a
+/// later run's initial population with it.
+pub fn read_subject_file<Subject>(
+    path: impl AsRef<Path>,
+    codec: &impl SubjectCodec<Subject>,
+) -> io::Result<Subject> {
+    let bytes = fs::read(path)?;
+    codec.decode(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_codec_round_trips_through_bytes() {
+        let codec = JsonCodec;
+        let bytes = codec.encode(&42u32).unwrap();
+        let decoded: u32 = codec.decode(&bytes).unwrap();
+        assert_eq!(decoded, 42);
+    }
+
+    #[test]
+    fn test_write_subject_file_then_read_subject_file_round_trips_via_disk() {
+        let codec = JsonCodec;
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_codec_test_{:?}.json",
+            std::thread::current().id()
+        ));
+
+        write_subject_file(&path, &7u32, &codec).unwrap();
+        let decoded: u32 = read_subject_file(&path, &codec).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(decoded, 7);
+    }
+}
@@ -0,0 +1,185 @@
+//! Multi-population evolution where a subject's fitness depends on another
+//! population rather than a fixed objective: competitive ([`evaluate_competitive`],
+//! predator/prey, players/test-cases) and cooperative ([`evaluate_cooperative`],
+//! decomposable problems split across subcomponents).
+//!
+//! Both are deliberately scoped to the evaluation step, not a full second
+//! `GaRunner`/`GaIterator` variant: `GaIterator` is generic over exactly one
+//! `Population<Subject>`, and driving two of them with synchronized
+//! generation stepping while letting each keep its own prune/mutate/
+//! reproduce pipeline would mean a parallel `GaIterator` construction, not a
+//! drop-in addition. Instead, these functions re-score both populations in
+//! place; call the relevant one once per generation, before each
+//! population's own `GaIterator::next_generation` (or a `GaAction` wrapping
+//! it), to keep both halves' fitness current against/with each other.
+use crate::ga::fitness::{Fitness, FitnessWrapped};
+use crate::ga::population::Population;
+use crate::util::{random_index_bias, Bias};
+
+/// How opponents are drawn from the other population for each subject being
+/// scored.
+#[derive(Debug, Copy, Clone)]
+pub enum OpponentSampling {
+    /// Play against every member of the other population.
+    All,
+    /// Play against `amount` members, drawn uniformly at random with
+    /// replacement.
+    Random { amount: usize },
+}
+
+fn opponent_indexes(sampling: OpponentSampling, len: usize) -> Vec<usize> {
+    match sampling {
+        OpponentSampling::All => (0..len).collect(),
+        OpponentSampling::Random { amount } => (0..amount.min(len.max(1)))
+            .map(|_| random_index_bias(len, Bias::Uniform))
+            .collect(),
+    }
+}
+
+/// Re-scores every subject in `population_a` and `population_b` by playing
+/// each against a sample of the other population, per `sampling`. `payoff`
+/// returns `(a_fitness_contribution, b_fitness_contribution)` for one match;
+/// contributions for a subject are averaged across its matches. Does nothing
+/// if either population is empty.
+pub fn evaluate_competitive<SubjectA: Clone, SubjectB: Clone>(
+    population_a: &mut Population<SubjectA>,
+    population_b: &mut Population<SubjectB>,
+    sampling: OpponentSampling,
+    payoff: impl Fn(&SubjectA, &SubjectB) -> (Fitness, Fitness),
+) {
+    if population_a.subjects.is_empty() || population_b.subjects.is_empty() {
+        return;
+    }
+    let mut a_totals = vec![0.0; population_a.subjects.len()];
+    let mut a_counts = vec![0usize; population_a.subjects.len()];
+    let mut b_totals = vec![0.0; population_b.subjects.len()];
+    let mut b_counts = vec![0usize; population_b.subjects.len()];
+
+    for (a_ix, a_subject) in population_a.subjects.iter().enumerate() {
+        for b_ix in opponent_indexes(sampling, population_b.subjects.len()) {
+            let b_subject = &population_b.subjects[b_ix];
+            let (a_score, b_score) = payoff(a_subject.subject_ref(), b_subject.subject_ref());
+            a_totals[a_ix] += a_score;
+            a_counts[a_ix] += 1;
+            b_totals[b_ix] += b_score;
+            b_counts[b_ix] += 1;
+        }
+    }
+
+    for (subject, (total, count)) in population_a.subjects.iter_mut().zip(a_totals.into_iter().zip(a_counts)) {
+        if count > 0 {
+            *subject = FitnessWrapped::new(subject.subject().as_ref().clone(), total / count as f64)
+        }
+    }
+    for (subject, (total, count)) in population_b.subjects.iter_mut().zip(b_totals.into_iter().zip(b_counts)) {
+        if count > 0 {
+            *subject = FitnessWrapped::new(subject.subject().as_ref().clone(), total / count as f64)
+        }
+    }
+}
+
+/// Cooperative co-evolution: each subpopulation evolves one subcomponent of a
+/// decomposable problem, and a subject's fitness is the fitness of the full
+/// solution assembled from it plus the *current best* collaborator(s) from
+/// the other subpopulation(s).
+///
+/// Scoped to two subpopulations, like [`evaluate_competitive`]; an N-way
+/// decomposition is the same protocol repeated pairwise against a merged
+/// "current best of everyone else" collaborator rather than a fundamentally
+/// different one, so callers with more than two subcomponents can fold them
+/// into `SubjectB` (e.g. `Vec<SubjectB>`) without needing a new primitive
+/// here.
+///
+/// Picks each population's current best by existing fitness (lowest wins),
+/// so the very first call — before any population has been scored against
+/// this assembler — uses whatever fitness the population started with.
+pub fn evaluate_cooperative<SubjectA: Clone, SubjectB: Clone>(
+    population_a: &mut Population<SubjectA>,
+    population_b: &mut Population<SubjectB>,
+    assemble: impl Fn(&SubjectA, &SubjectB) -> Fitness,
+) {
+    let Some(best_a) = best_subject(population_a) else {
+        return;
+    };
+    let Some(best_b) = best_subject(population_b) else {
+        return;
+    };
+    for subject in population_a.subjects.iter_mut() {
+        let fitness = assemble(subject.subject_ref(), &best_b);
+        *subject = FitnessWrapped::new(subject.subject().as_ref().clone(), fitness);
+    }
+    for subject in population_b.subjects.iter_mut() {
+        let fitness = assemble(&best_a, subject.subject_ref());
+        *subject = FitnessWrapped::new(subject.subject().as_ref().clone(), fitness);
+    }
+}
+
+fn best_subject<Subject: Clone>(population: &Population<Subject>) -> Option<Subject> {
+    population
+        .subjects
+        .iter()
+        .min_by(|a, b| a.fitness().partial_cmp(&b.fitness()).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|subject| subject.subject_ref().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn population(values: Vec<i32>) -> Population<i32> {
+        let subjects = values.into_iter().map(|v| FitnessWrapped::new(v, 0.0)).collect();
+        Population { pool_size: 0, subjects, memory_budget_bytes: None }
+    }
+
+    #[test]
+    fn test_evaluate_competitive_averages_payoff_across_all_opponents() {
+        let mut predators = population(vec![1, 2]);
+        let mut prey = population(vec![10, 20]);
+        evaluate_competitive(&mut predators, &mut prey, OpponentSampling::All, |&a, &b| {
+            ((a * b) as Fitness, (b - a) as Fitness)
+        });
+        // predator 1 vs prey {10, 20}: (10 + 20) / 2 = 15
+        assert_eq!(predators.subjects[0].fitness(), 15.0);
+        // predator 2 vs prey {10, 20}: (20 + 40) / 2 = 30
+        assert_eq!(predators.subjects[1].fitness(), 30.0);
+        // prey 10 vs predators {1, 2}: (9 + 8) / 2 = 8.5
+        assert_eq!(prey.subjects[0].fitness(), 8.5);
+    }
+
+    #[test]
+    fn test_evaluate_competitive_on_empty_population_is_a_no_op() {
+        let mut a: Population<i32> = population(vec![]);
+        let mut b = population(vec![1]);
+        evaluate_competitive(&mut a, &mut b, OpponentSampling::All, |&a, &b| {
+            (a as Fitness, b as Fitness)
+        });
+        assert_eq!(b.subjects[0].fitness(), 0.0);
+    }
+
+    fn scored_population(values: Vec<(i32, Fitness)>) -> Population<i32> {
+        let subjects = values.into_iter().map(|(v, f)| FitnessWrapped::new(v, f)).collect();
+        Population { pool_size: 0, subjects, memory_budget_bytes: None }
+    }
+
+    #[test]
+    fn test_evaluate_cooperative_assembles_with_each_others_current_best() {
+        // best of a is 1 (fitness 0.0), best of b is 10 (fitness 0.0)
+        let mut a = scored_population(vec![(1, 0.0), (2, 5.0)]);
+        let mut b = scored_population(vec![(10, 0.0), (20, 5.0)]);
+        evaluate_cooperative(&mut a, &mut b, |&a, &b| (a + b) as Fitness);
+        // every member of a is assembled with best_b == 10
+        assert_eq!(a.subjects[0].fitness(), 11.0);
+        assert_eq!(a.subjects[1].fitness(), 12.0);
+        // every member of b is assembled with best_a == 1
+        assert_eq!(b.subjects[0].fitness(), 11.0);
+        assert_eq!(b.subjects[1].fitness(), 21.0);
+    }
+
+    #[test]
+    fn test_evaluate_cooperative_on_empty_population_is_a_no_op() {
+        let mut a: Population<i32> = scored_population(vec![]);
+        let mut b = scored_population(vec![(1, 0.0)]);
+        evaluate_cooperative(&mut a, &mut b, |&a, &b| (a + b) as Fitness);
+        assert_eq!(b.subjects[0].fitness(), 0.0);
+    }
+}
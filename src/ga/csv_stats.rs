@@ -0,0 +1,266 @@
+use std::cell::RefCell;
+use std::fs::{File, OpenOptions};
+use std::hash::Hash;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::ga::fitness::Fitness;
+use crate::ga::population::Population;
+use crate::ga::stats::compute_stats;
+use crate::ga::{GaAction, GaContext};
+
+/// Appends one CSV row per generation (generation, population size, and
+/// fitness/diversity summary statistics from [`crate::ga::stats`]) to
+/// `path`, writing a header row the first time the file is created.
+///
+/// Duplicates-removed and evaluation counts aren't emitted: nothing in this
+/// crate currently counts either of those anywhere an action could observe
+/// them (`DedupeAction` reports no count, and evaluation happens before an
+/// action ever sees the population), so adding those columns would mean
+/// plumbing new counters through those call sites first.
+pub struct CsvStatsRecorder<Subject> {
+    path: PathBuf,
+    writer: RefCell<Option<BufWriter<File>>>,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> CsvStatsRecorder<Subject> {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            writer: RefCell::new(None),
+            _subject: PhantomData,
+        }
+    }
+
+    fn append_row(&self, row: &str) -> io::Result<()> {
+        let mut writer_slot = self.writer.borrow_mut();
+        if writer_slot.is_none() {
+            let file_is_new = !self.path.exists();
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            let mut writer = BufWriter::new(file);
+            if file_is_new {
+                writeln!(
+                    writer,
+                    "generation,population_size,min_fitness,max_fitness,mean_fitness,stddev_fitness,diversity"
+                )?;
+            }
+            *writer_slot = Some(writer);
+        }
+        let writer = writer_slot.as_mut().expect("writer was just initialized");
+        writeln!(writer, "{row}")?;
+        writer.flush()
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash> GaAction for CsvStatsRecorder<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("csv_stats", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Send + Sync> GaAction for CsvStatsRecorder<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("csv_stats", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+macro_rules! impl_record_generation {
+    () => {
+        fn record_generation(&self, context: &GaContext, population: &Population<Subject>) {
+            let Some(stats) = compute_stats(population) else {
+                return;
+            };
+            let row = format!(
+                "{},{},{},{},{},{},{}",
+                context.generation,
+                population.subjects.len(),
+                stats.min_fitness,
+                stats.max_fitness,
+                stats.mean_fitness,
+                stats.stddev_fitness,
+                stats.diversity,
+            );
+            if let Err(err) = self.append_row(&row) {
+                tracing::log::warn!("failed to append CSV stats row: {err}");
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash> CsvStatsRecorder<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Send + Sync> CsvStatsRecorder<Subject> {
+    impl_record_generation!();
+}
+
+/// Appends one CSV row (generation, best fitness, mean fitness, fitness
+/// stddev, population size, elapsed milliseconds since the run started) to
+/// `path`, writing a header row the first time the file is created.
+///
+/// Unlike [`CsvStatsRecorder`] — a [`GaAction`] driven from the action
+/// pipeline, and so limited to what a `&Population` alone exposes, with no
+/// notion of elapsed time or a reverse-mode-aware "best so far" — this is
+/// driven directly by [`crate::ga::ga_runner::GaRunner`] via
+/// [`crate::ga::ga_runner::GaRunnerOptions::csv_report_path`], which has
+/// both. Not generic over `Subject`: it only ever sees fitness values and
+/// counts, never a subject itself.
+pub struct CsvReporter {
+    path: PathBuf,
+    writer: RefCell<Option<BufWriter<File>>>,
+}
+
+impl CsvReporter {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            writer: RefCell::new(None),
+        }
+    }
+
+    pub fn report(
+        &self,
+        generation: usize,
+        best_fitness: Fitness,
+        mean_fitness: Fitness,
+        stddev_fitness: Fitness,
+        population_size: usize,
+        elapsed: Duration,
+    ) {
+        let row = format!(
+            "{},{},{},{},{},{}",
+            generation,
+            best_fitness,
+            mean_fitness,
+            stddev_fitness,
+            population_size,
+            elapsed.as_millis(),
+        );
+        if let Err(err) = self.append_row(&row) {
+            tracing::log::warn!("failed to append CSV report row: {err}");
+        }
+    }
+
+    fn append_row(&self, row: &str) -> io::Result<()> {
+        let mut writer_slot = self.writer.borrow_mut();
+        if writer_slot.is_none() {
+            let file_is_new = !self.path.exists();
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            let mut writer = BufWriter::new(file);
+            if file_is_new {
+                writeln!(
+                    writer,
+                    "generation,best_fitness,mean_fitness,stddev_fitness,population_size,elapsed_ms"
+                )?;
+            }
+            *writer_slot = Some(writer);
+        }
+        let writer = writer_slot.as_mut().expect("writer was just initialized");
+        writeln!(writer, "{row}")?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::csv_stats::CsvStatsRecorder;
+    use crate::ga::fitness::FitnessWrapped;
+    use crate::ga::population::Population;
+    use crate::ga::{GaAction, GaContext};
+
+    fn population_of(fitnesses: &[u32]) -> Population<u32> {
+        Population {
+            pool_size: fitnesses.len(),
+            subjects: fitnesses
+                .iter()
+                .map(|&f| FitnessWrapped::new(f, f as f64))
+                .collect(),
+            memory_budget_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_appends_header_then_one_row_per_generation() {
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_csv_stats_test_{:?}.csv",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let recorder = CsvStatsRecorder::new(&path);
+        recorder.perform_action(&GaContext::new(0), &mut population_of(&[1, 2, 3]));
+        recorder.perform_action(&GaContext::new(1), &mut population_of(&[4, 5, 6]));
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("generation,population_size"));
+        assert!(lines[1].starts_with("0,3,"));
+        assert!(lines[2].starts_with("1,3,"));
+    }
+
+    #[test]
+    fn test_skips_row_for_empty_population() {
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_csv_stats_empty_test_{:?}.csv",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let recorder = CsvStatsRecorder::new(&path);
+        recorder.perform_action(&GaContext::new(0), &mut population_of(&[]));
+
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_csv_reporter_appends_header_then_one_row_per_report() {
+        use crate::ga::csv_stats::CsvReporter;
+        use std::time::Duration;
+
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_csv_reporter_test_{:?}.csv",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let reporter = CsvReporter::new(&path);
+        reporter.report(0, 1.0, 2.0, 0.5, 3, Duration::from_millis(10));
+        reporter.report(1, 0.5, 1.5, 0.25, 3, Duration::from_millis(20));
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "generation,best_fitness,mean_fitness,stddev_fitness,population_size,elapsed_ms"
+        );
+        assert_eq!(lines[1], "0,1,2,0.5,3,10");
+        assert_eq!(lines[2], "1,0.5,1.5,0.25,3,20");
+    }
+}
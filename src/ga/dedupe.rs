@@ -52,8 +52,10 @@ where
 {
     type Subject = Subject;
 
-    fn perform_action(&self, _context: &GaContext, population: &mut Population<Self::Subject>) {
-        self.action.dedupe(population)
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("dedupe", context, population, |population| {
+            self.action.dedupe(population)
+        });
     }
 }
 
@@ -97,7 +99,7 @@ where
                 }
                 // TODO: should equality check be left to the wrapper struct?
                 if b_subject.fitness() == a_subject.fitness()
-                    && b_subject.subject() == a_subject.subject()
+                    && b_subject.subject_ref() == a_subject.subject_ref()
                 {
                     indexes_to_delete.insert(b_ix);
                 }
@@ -0,0 +1,233 @@
+//! A differential-evolution driver for [`RealGenome`](crate::ga::genome::real_vector::RealGenome)
+//! populations, implemented as a [`GaAction`] rather than a separate run loop.
+//! Registering a [`DifferentialEvolutionAction`] on `GaRunner`/`GaIterator`
+//! instead of a `GenericMutator`/`GenericReproducer` pair gets DE for free on
+//! top of the crate's existing `Population`, `Fit`, termination and reporting
+//! infrastructure, so users can A/B DE against a GA on the same problem
+//! definition.
+
+use rand::Rng;
+
+use crate::ga::fitness::{Fitness, FitnessWrapped};
+use crate::ga::genome::real_vector::RealGenome;
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+use crate::util::rng;
+
+/// Which vector DE perturbs to build the mutant before crossover.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DifferentialEvolutionStrategy {
+    /// `mutant = r1 + F * (r2 - r3)`, all three distinct random population
+    /// members (excluding the target).
+    Rand1Bin,
+    /// `mutant = best + F * (r1 - r2)`, using the current generation's best
+    /// subject as the base. Requires the population to already be sorted
+    /// best-first (ascending by fitness, or descending in reverse mode), which
+    /// [`crate::ga::ga_iterator::GaIterator`] guarantees before running actions.
+    Best1Bin,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct DifferentialEvolutionOptions {
+    pub strategy: DifferentialEvolutionStrategy,
+    /// Scales the difference vector; commonly `0.4..=1.0`.
+    pub differential_weight: f64,
+    /// Per-gene probability of taking the mutant's value over the target's
+    /// during binomial crossover; commonly `0.1..=1.0`.
+    pub crossover_rate: f64,
+}
+
+/// Drives one DE generation over `Population<RealGenome>`: for every target
+/// vector, builds a mutant via [`DifferentialEvolutionOptions::strategy`],
+/// crosses it with the target (binomial crossover), and greedily replaces the
+/// target with the trial vector if the trial scores at least as well.
+pub struct DifferentialEvolutionAction {
+    options: DifferentialEvolutionOptions,
+    fitness_fn: fn(&RealGenome) -> Fitness,
+}
+
+impl DifferentialEvolutionAction {
+    pub fn new(options: DifferentialEvolutionOptions, fitness_fn: fn(&RealGenome) -> Fitness) -> Self {
+        Self { options, fitness_fn }
+    }
+
+    fn mutant(&self, population: &Population<RealGenome>, target_ix: usize) -> Option<RealGenome> {
+        let len = population.subjects.len();
+        match self.options.strategy {
+            DifferentialEvolutionStrategy::Rand1Bin => {
+                let [r1, r2, r3] = distinct_others(len, target_ix, 3)?[..] else {
+                    return None;
+                };
+                Some(combine(
+                    population.subjects[r1].subject_ref(),
+                    population.subjects[r2].subject_ref(),
+                    population.subjects[r3].subject_ref(),
+                    self.options.differential_weight,
+                ))
+            }
+            DifferentialEvolutionStrategy::Best1Bin => {
+                let [r1, r2] = distinct_others(len, target_ix, 2)?[..] else {
+                    return None;
+                };
+                Some(combine(
+                    population.subjects[0].subject_ref(),
+                    population.subjects[r1].subject_ref(),
+                    population.subjects[r2].subject_ref(),
+                    self.options.differential_weight,
+                ))
+            }
+        }
+    }
+}
+
+/// `base + weight * (a - b)`, clamped back into `base`'s bounds.
+fn combine(base: &RealGenome, a: &RealGenome, b: &RealGenome, weight: f64) -> RealGenome {
+    let values = base
+        .values
+        .iter()
+        .zip(a.values.iter())
+        .zip(b.values.iter())
+        .map(|((&base, &a), &b)| base + weight * (a - b))
+        .collect();
+    RealGenome::new(values, base.bounds().to_vec())
+}
+
+/// `amount` distinct indices into `0..len`, none of which are `exclude`.
+/// `None` if there aren't enough other members to draw from.
+fn distinct_others(len: usize, exclude: usize, amount: usize) -> Option<Vec<usize>> {
+    if len <= amount {
+        return None;
+    }
+    let sampled = rand::seq::index::sample(&mut rng::thread_rng(), len - 1, amount).into_vec();
+    Some(
+        sampled
+            .into_iter()
+            .map(|index| if index >= exclude { index + 1 } else { index })
+            .collect(),
+    )
+}
+
+/// Binomial crossover of `target` and `mutant`: each gene independently has
+/// probability `crossover_rate` of coming from `mutant`, with one guaranteed
+/// forced gene so the trial always differs from `target`.
+fn binomial_crossover(target: &RealGenome, mutant: &RealGenome, crossover_rate: f64) -> RealGenome {
+    let mut rand = rng::thread_rng();
+    let forced_index = rand.gen_range(0..target.len().max(1));
+    let values = target
+        .values
+        .iter()
+        .zip(mutant.values.iter())
+        .enumerate()
+        .map(|(index, (&target_value, &mutant_value))| {
+            if index == forced_index || rand.gen_bool(crossover_rate) {
+                mutant_value
+            } else {
+                target_value
+            }
+        })
+        .collect();
+    RealGenome::new(values, target.bounds().to_vec())
+}
+
+impl GaAction for DifferentialEvolutionAction {
+    type Subject = RealGenome;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("differential_evolution", context, population, |population| {
+            let mut next_generation = Vec::with_capacity(population.subjects.len());
+            for target_ix in 0..population.subjects.len() {
+                let target = &population.subjects[target_ix];
+                let Some(mutant) = self.mutant(population, target_ix) else {
+                    next_generation.push(target.clone());
+                    continue;
+                };
+                let trial = binomial_crossover(target.subject_ref(), &mutant, self.options.crossover_rate);
+                let trial_fitness = (self.fitness_fn)(&trial);
+                if trial_fitness <= target.fitness() {
+                    next_generation.push(FitnessWrapped::new(trial, trial_fitness));
+                } else {
+                    next_generation.push(target.clone());
+                }
+            }
+            population.subjects = next_generation;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds() -> Vec<std::ops::RangeInclusive<f64>> {
+        vec![-10.0..=10.0; 2]
+    }
+
+    fn sphere(genome: &RealGenome) -> Fitness {
+        genome.values.iter().map(|v| v * v).sum()
+    }
+
+    fn population(n: usize) -> Population<RealGenome> {
+        let subjects = (0..n)
+            .map(|_| {
+                let genome = RealGenome::random(bounds());
+                let fitness = sphere(&genome);
+                FitnessWrapped::new(genome, fitness)
+            })
+            .collect();
+        Population { pool_size: n, subjects, memory_budget_bytes: None }
+    }
+
+    #[test]
+    fn test_rand1bin_never_makes_the_population_worse_on_average() {
+        let mut population = population(20);
+        let before: Fitness = population.subjects.iter().map(|s| s.fitness()).sum();
+        let action = DifferentialEvolutionAction::new(
+            DifferentialEvolutionOptions {
+                strategy: DifferentialEvolutionStrategy::Rand1Bin,
+                differential_weight: 0.8,
+                crossover_rate: 0.9,
+            },
+            sphere,
+        );
+        action.perform_action(&GaContext::default(), &mut population);
+        let after: Fitness = population.subjects.iter().map(|s| s.fitness()).sum();
+        assert!(after <= before);
+    }
+
+    #[test]
+    fn test_best1bin_never_makes_the_population_worse_on_average() {
+        let mut population = population(20);
+        population
+            .subjects
+            .sort_by(|a, b| a.fitness().partial_cmp(&b.fitness()).unwrap());
+        let before: Fitness = population.subjects.iter().map(|s| s.fitness()).sum();
+        let action = DifferentialEvolutionAction::new(
+            DifferentialEvolutionOptions {
+                strategy: DifferentialEvolutionStrategy::Best1Bin,
+                differential_weight: 0.8,
+                crossover_rate: 0.9,
+            },
+            sphere,
+        );
+        action.perform_action(&GaContext::default(), &mut population);
+        let after: Fitness = population.subjects.iter().map(|s| s.fitness()).sum();
+        assert!(after <= before);
+    }
+
+    #[test]
+    fn test_too_small_population_leaves_subjects_unchanged() {
+        let mut population = population(2);
+        let before: Vec<Fitness> = population.subjects.iter().map(|s| s.fitness()).collect();
+        let action = DifferentialEvolutionAction::new(
+            DifferentialEvolutionOptions {
+                strategy: DifferentialEvolutionStrategy::Rand1Bin,
+                differential_weight: 0.8,
+                crossover_rate: 0.9,
+            },
+            sphere,
+        );
+        action.perform_action(&GaContext::default(), &mut population);
+        let after: Vec<Fitness> = population.subjects.iter().map(|s| s.fitness()).collect();
+        assert_eq!(before, after);
+    }
+}
@@ -0,0 +1,190 @@
+//! Genotype diversity: mean pairwise distance (via the existing
+//! [`GenotypeDistance`] trait from [`crate::ga::niching`], already used
+//! there for fitness sharing and in [`crate::ga::species`] for speciation)
+//! plus fitness entropy, so stagnation and premature convergence can be
+//! detected programmatically instead of by eyeballing a fitness curve.
+//!
+//! Distinct from [`crate::ga::stats::PopulationStats::diversity`], which
+//! reports the fraction of subjects unique by hash — a cheap
+//! duplicate-detection signal, not a genotype-space distance metric.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::ga::fitness::{Fitness, FitnessWrapped};
+use crate::ga::niching::GenotypeDistance;
+use crate::ga::population::Population;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiversityStats {
+    pub mean_pairwise_distance: f64,
+    pub fitness_entropy: f64,
+}
+
+/// Mean of [`GenotypeDistance::genotype_distance`] over every unordered pair
+/// of subjects. `0.0` for a population of zero or one, since there are no
+/// pairs to average.
+#[cfg(not(feature = "parallel"))]
+pub fn mean_pairwise_distance<Subject: GenotypeDistance>(
+    subjects: &[FitnessWrapped<Subject>],
+) -> f64 {
+    let n = subjects.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let mut total = 0.0;
+    let mut pairs = 0usize;
+    for i in 0..n {
+        for j in (i + 1)..n {
+            total += subjects[i].subject_ref().genotype_distance(subjects[j].subject_ref());
+            pairs += 1;
+        }
+    }
+    total / pairs as f64
+}
+
+#[cfg(feature = "parallel")]
+pub fn mean_pairwise_distance<Subject: GenotypeDistance + Send + Sync>(
+    subjects: &[FitnessWrapped<Subject>],
+) -> f64 {
+    let n = subjects.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let total: f64 = (0..n)
+        .into_par_iter()
+        .map(|i| {
+            ((i + 1)..n)
+                .map(|j| subjects[i].subject_ref().genotype_distance(subjects[j].subject_ref()))
+                .sum::<f64>()
+        })
+        .sum();
+    let pairs = n * (n - 1) / 2;
+    total / pairs as f64
+}
+
+/// Shannon entropy (base 2, in bits) of the fitness distribution, binned into
+/// `bins` equal-width buckets across `[min, max]`. `0.0` if every subject
+/// shares the same fitness (a single occupied bin), there are fewer than two
+/// subjects, or `bins` is `0`.
+pub fn fitness_entropy(fitnesses: &[Fitness], bins: usize) -> f64 {
+    let n = fitnesses.len();
+    if n < 2 || bins == 0 {
+        return 0.0;
+    }
+    let min = fitnesses.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = fitnesses.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+    if range == 0.0 {
+        return 0.0;
+    }
+    let mut counts = vec![0usize; bins];
+    for &fitness in fitnesses {
+        let bin = (((fitness - min) / range) * bins as f64) as usize;
+        counts[bin.min(bins - 1)] += 1;
+    }
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / n as f64;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Combines [`mean_pairwise_distance`] and [`fitness_entropy`] into one call,
+/// mirroring [`crate::ga::stats::compute_stats`]'s `Option`-on-empty
+/// convention. `bins` is forwarded to [`fitness_entropy`] as-is; a caller
+/// unsure what to pick can use `population.subjects.len()`, one bin per
+/// subject in the worst case.
+#[cfg(not(feature = "parallel"))]
+pub fn compute_diversity<Subject: GenotypeDistance>(
+    population: &Population<Subject>,
+    bins: usize,
+) -> Option<DiversityStats> {
+    if population.subjects.is_empty() {
+        return None;
+    }
+    let fitnesses: Vec<Fitness> = population.subjects.iter().map(|s| s.fitness()).collect();
+    Some(DiversityStats {
+        mean_pairwise_distance: mean_pairwise_distance(&population.subjects),
+        fitness_entropy: fitness_entropy(&fitnesses, bins),
+    })
+}
+
+#[cfg(feature = "parallel")]
+pub fn compute_diversity<Subject: GenotypeDistance + Send + Sync>(
+    population: &Population<Subject>,
+    bins: usize,
+) -> Option<DiversityStats> {
+    if population.subjects.is_empty() {
+        return None;
+    }
+    let fitnesses: Vec<Fitness> = population.subjects.par_iter().map(|s| s.fitness()).collect();
+    Some(DiversityStats {
+        mean_pairwise_distance: mean_pairwise_distance(&population.subjects),
+        fitness_entropy: fitness_entropy(&fitnesses, bins),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::fitness::FitnessWrapped;
+
+    #[derive(Clone)]
+    struct Point(f64);
+
+    impl GenotypeDistance for Point {
+        fn genotype_distance(&self, other: &Self) -> f64 {
+            (self.0 - other.0).abs()
+        }
+    }
+
+    fn population(values: Vec<(f64, f64)>) -> Population<Point> {
+        let subjects = values
+            .into_iter()
+            .map(|(genotype, fitness)| FitnessWrapped::new(Point(genotype), fitness))
+            .collect();
+        Population { pool_size: 0, subjects, memory_budget_bytes: None }
+    }
+
+    #[test]
+    fn test_mean_pairwise_distance_of_empty_or_singleton_is_zero() {
+        assert_eq!(mean_pairwise_distance::<Point>(&[]), 0.0);
+        assert_eq!(mean_pairwise_distance(&population(vec![(1.0, 0.0)]).subjects), 0.0);
+    }
+
+    #[test]
+    fn test_mean_pairwise_distance_averages_every_unordered_pair() {
+        let population = population(vec![(0.0, 0.0), (2.0, 0.0), (4.0, 0.0)]);
+        // pairs: |0-2|=2, |0-4|=4, |2-4|=2 -> mean 8/3
+        assert_eq!(mean_pairwise_distance(&population.subjects), 8.0 / 3.0);
+    }
+
+    #[test]
+    fn test_fitness_entropy_of_identical_fitnesses_is_zero() {
+        assert_eq!(fitness_entropy(&[1.0, 1.0, 1.0], 4), 0.0);
+    }
+
+    #[test]
+    fn test_fitness_entropy_of_evenly_split_bins_is_maximal() {
+        let entropy = fitness_entropy(&[0.0, 0.0, 1.0, 1.0], 2);
+        assert_eq!(entropy, 1.0); // two equally-populated bins -> 1 bit
+    }
+
+    #[test]
+    fn test_compute_diversity_empty_population_is_none() {
+        assert!(compute_diversity(&population(vec![]), 4).is_none());
+    }
+
+    #[test]
+    fn test_compute_diversity_combines_both_metrics() {
+        let population = population(vec![(0.0, 0.0), (10.0, 5.0)]);
+        let stats = compute_diversity(&population, 2).unwrap();
+        assert_eq!(stats.mean_pairwise_distance, 10.0);
+        assert_eq!(stats.fitness_entropy, 1.0);
+    }
+}
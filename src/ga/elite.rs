@@ -0,0 +1,171 @@
+//! Elitism: guarantees the best `k` subjects of a generation survive
+//! whatever an inner action does to the rest of the population.
+//!
+//! The crate's "skip first" prune variants (e.g. [`crate::ga::prune::PruneSingleFrontSkipFirst`])
+//! only protect a single subject from that one action, and say nothing about
+//! mutation or reproduction potentially discarding it downstream. Wrapping
+//! the whole prune/mutate/reproduce pipeline in [`ElitismAction`] instead
+//! snapshots the top `k` subjects up front (`GaIterator` already sorts the
+//! population best-first before any action runs — ascending, or descending
+//! in reverse mode — so the top `k` are simply the first `k`) and re-appends that unchanged snapshot after the wrapped
+//! action runs, regardless of what it did.
+
+use std::num::NonZeroUsize;
+
+use crate::ga::fitness::{Fit, Fitness, FitnessWrapped};
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+
+/// Preserves the `k` best subjects (by the population's current order —
+/// best first, as `GaIterator` sorts it in either fitness direction) across
+/// `inner`, re-appending them unchanged after `inner` runs.
+pub struct ElitismAction<Inner> {
+    k: usize,
+    inner: Inner,
+}
+
+impl<Inner> ElitismAction<Inner> {
+    pub fn new(k: usize, inner: Inner) -> Self {
+        Self { k, inner }
+    }
+}
+
+impl<Inner: GaAction> GaAction for ElitismAction<Inner>
+where
+    Inner::Subject: Clone,
+{
+    type Subject = Inner::Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("elitism", context, population, |population| {
+            let k = self.k.min(population.subjects.len());
+            let elites: Vec<FitnessWrapped<Self::Subject>> = population.subjects[..k].to_vec();
+            self.inner.perform_action(context, population);
+            population.subjects.extend(elites);
+        });
+    }
+}
+
+/// Periodically re-measures the top `k` subjects' fitness in place instead of
+/// trusting one evaluation forever — most useful paired with
+/// [`crate::ga::fitness::NoisyFitness`], whose resampled mean can drift as
+/// its own samples change, so an elite that only got lucky once doesn't stay
+/// "the best" for the rest of the run.
+pub struct ReevaluateElitesAction<Inner> {
+    k: usize,
+    every_n_generations: NonZeroUsize,
+    inner: Inner,
+}
+
+impl<Inner> ReevaluateElitesAction<Inner> {
+    pub fn new(k: usize, every_n_generations: NonZeroUsize, inner: Inner) -> Self {
+        Self {
+            k,
+            every_n_generations,
+            inner,
+        }
+    }
+}
+
+impl<Inner: GaAction> GaAction for ReevaluateElitesAction<Inner>
+where
+    Inner::Subject: Fit<Fitness>,
+{
+    type Subject = Inner::Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("reevaluate_elites", context, population, |population| {
+            if context.generation.is_multiple_of(self.every_n_generations.get()) {
+                let k = self.k.min(population.subjects.len());
+                for wrapped in population.subjects[..k].iter_mut() {
+                    let fitness = wrapped.subject_ref().measure();
+                    wrapped.set_fitness(fitness);
+                }
+            }
+            self.inner.perform_action(context, population);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ClearAction;
+    impl GaAction for ClearAction {
+        type Subject = i32;
+        fn perform_action(&self, _context: &GaContext, population: &mut Population<Self::Subject>) {
+            population.subjects.clear();
+        }
+    }
+
+    fn population(values: Vec<i32>) -> Population<i32> {
+        let subjects = values.into_iter().map(|v| FitnessWrapped::new(v, v as f64)).collect();
+        Population { pool_size: 0, subjects, memory_budget_bytes: None }
+    }
+
+    #[test]
+    fn test_elites_survive_an_inner_action_that_clears_the_population() {
+        let mut population = population(vec![0, 1, 2, 3, 4]);
+        let action = ElitismAction::new(2, ClearAction);
+        action.perform_action(&GaContext::default(), &mut population);
+        let survivors: Vec<i32> = population.subjects.iter().map(|s| *s.subject_ref()).collect();
+        assert_eq!(survivors, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_k_larger_than_population_preserves_everything() {
+        let mut population = population(vec![0, 1]);
+        let action = ElitismAction::new(10, ClearAction);
+        action.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects.len(), 2);
+    }
+
+    /// Fitness that increments by 1.0 every time it's measured, so tests can
+    /// tell a re-measured subject apart from an untouched one.
+    struct Incrementing(std::cell::Cell<Fitness>);
+
+    impl Fit<Fitness> for Incrementing {
+        fn measure(&self) -> Fitness {
+            let next = self.0.get() + 1.0;
+            self.0.set(next);
+            next
+        }
+    }
+
+    struct NoopAction;
+    impl GaAction for NoopAction {
+        type Subject = Incrementing;
+        fn perform_action(&self, _context: &GaContext, _population: &mut Population<Self::Subject>) {}
+    }
+
+    #[test]
+    fn test_reevaluate_elites_overwrites_top_k_fitness_in_place() {
+        let mut population = Population {
+            subjects: vec![
+                FitnessWrapped::new(Incrementing(std::cell::Cell::new(0.0)), 0.0),
+                FitnessWrapped::new(Incrementing(std::cell::Cell::new(0.0)), 0.0),
+            ],
+            pool_size: 0,
+            memory_budget_bytes: None,
+        };
+        let action = ReevaluateElitesAction::new(1, NonZeroUsize::new(1).unwrap(), NoopAction);
+        action.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects[0].fitness(), 1.0);
+        assert_eq!(population.subjects[1].fitness(), 0.0);
+    }
+
+    #[test]
+    fn test_reevaluate_elites_skips_generations_not_on_the_interval() {
+        let mut population = Population {
+            subjects: vec![FitnessWrapped::new(Incrementing(std::cell::Cell::new(0.0)), 0.0)],
+            pool_size: 0,
+            memory_budget_bytes: None,
+        };
+        let action = ReevaluateElitesAction::new(1, NonZeroUsize::new(2).unwrap(), NoopAction);
+        action.perform_action(&GaContext::new(1), &mut population);
+        assert_eq!(population.subjects[0].fitness(), 0.0);
+        action.perform_action(&GaContext::new(2), &mut population);
+        assert_eq!(population.subjects[0].fitness(), 1.0);
+    }
+}
@@ -0,0 +1,72 @@
+//! [`GaEvent`]/[`GaEventListener`]: a run-driven event hook, so logging,
+//! metrics, and checkpointing-style concerns can subscribe to what
+//! [`crate::ga::ga_runner::GaRunner`] observes about a run instead of being
+//! wired directly into its loop (the way [`crate::ga::csv_stats::CsvReporter`],
+//! [`crate::ga::jsonl_log::JsonlReporter`], and [`crate::ga::plot::PlotReporter`]
+//! currently are).
+//!
+//! Only covers what `GaRunner` itself observes between generations: it sits
+//! above the action pipeline and never sees what an individual
+//! prune/reproduce/mutate/dedupe action did to get from one population to
+//! the next, so there's no `SubjectPruned` or `OffspringCreated` variant
+//! here — emitting those would need hooks inside
+//! [`crate::ga::action::DefaultActions`]'s individual stages, not `GaRunner`.
+
+use std::sync::Arc;
+
+use crate::ga::fitness::Fitness;
+use crate::ga::termination::TerminationReason;
+
+/// An observation [`GaRunner`](crate::ga::ga_runner::GaRunner) reports to
+/// every [`GaEventListener`] registered on
+/// [`crate::ga::ga_runner::GaRunnerOptions::listeners`].
+#[derive(Debug, Clone, Copy)]
+pub enum GaEvent {
+    /// A new generation is about to run.
+    GenerationStarted { generation: usize },
+    /// A generation finished and the population reflects it.
+    GenerationCompleted { generation: usize, population_size: usize },
+    /// The reverse-mode-aware best-so-far fitness (see
+    /// [`crate::ga::ga_iterator::GaIterState::current_fitness`]) improved
+    /// on this generation.
+    BestImproved { generation: usize, fitness: Fitness },
+    /// The run is stopping because the target fitness was reached.
+    TargetReached { generation: usize, fitness: Fitness },
+    /// The run has stopped, for any reason.
+    RunTerminated { generation: usize, reason: TerminationReason },
+}
+
+/// Subscribes to [`GaEvent`]s reported by
+/// [`crate::ga::ga_runner::GaRunner`]. Register any number of listeners on
+/// [`crate::ga::ga_runner::GaRunnerOptions::listeners`]; each one sees every
+/// event in the order they occur.
+pub trait GaEventListener {
+    fn on_event(&self, event: &GaEvent);
+}
+
+impl<F: Fn(&GaEvent)> GaEventListener for F {
+    fn on_event(&self, event: &GaEvent) {
+        self(event)
+    }
+}
+
+/// Convenience alias for [`crate::ga::ga_runner::GaRunnerOptions::listeners`]'
+/// element type, so call sites don't have to spell out the trait object
+/// bounds themselves.
+pub type SharedGaEventListener = Arc<dyn GaEventListener + Send + Sync>;
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn test_closures_implement_ga_event_listener() {
+        let seen = RefCell::new(Vec::new());
+        let listener = |event: &GaEvent| seen.borrow_mut().push(*event);
+        listener.on_event(&GaEvent::GenerationStarted { generation: 1 });
+        listener.on_event(&GaEvent::TargetReached { generation: 2, fitness: 0.0 });
+        assert_eq!(seen.borrow().len(), 2);
+    }
+}
@@ -1,19 +1,40 @@
+use std::cell::Cell;
 use std::fmt;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
 use std::sync::Arc;
 
 use derivative::Derivative;
 
+use crate::ga::lineage::LineageId;
+
 pub type Fitness = f64;
 pub type CalculateFitnessFn<'a, Subject> = Box<dyn Fn(&Subject) -> Fitness + 'a>;
 
+/// Hands out a fresh [`LineageId`] for every [`FitnessWrapped`] constructed,
+/// process-wide, so ids stay unique without threading a counter through every
+/// call site that builds one.
+fn next_lineage_id() -> LineageId {
+    static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+    NEXT_ID.fetch_add(1, AtomicOrdering::Relaxed)
+}
+
 #[derive(Derivative)]
 #[derivative(PartialOrd)]
 pub struct FitnessWrapped<Subject> {
     fitness: Fitness,
     #[derivative(PartialEq = "ignore")]
     subject: Arc<Subject>,
+    #[derivative(PartialEq = "ignore", PartialOrd = "ignore")]
+    id: LineageId,
+    #[derivative(PartialEq = "ignore", PartialOrd = "ignore")]
+    parents: Vec<LineageId>,
+    #[derivative(PartialEq = "ignore", PartialOrd = "ignore")]
+    created_by: Option<&'static str>,
+    #[derivative(PartialEq = "ignore", PartialOrd = "ignore")]
+    generation_born: usize,
 }
 
 impl<Subject> FitnessWrapped<Subject> {
@@ -21,6 +42,31 @@ impl<Subject> FitnessWrapped<Subject> {
         FitnessWrapped {
             fitness,
             subject: Arc::new(subject),
+            id: next_lineage_id(),
+            parents: vec![],
+            created_by: None,
+            generation_born: 0,
+        }
+    }
+    /// Like [`Self::new`], but also records `parents` and the operator that
+    /// produced `subject` (e.g. `std::any::type_name::<Mutator>()`), so a
+    /// [`crate::ga::lineage::Genealogy`] can pick up the provenance
+    /// automatically instead of a caller having to assign and thread
+    /// [`LineageId`]s through their own actions the way
+    /// [`crate::ga::lineage::LineageGraph`]'s docs describe.
+    pub fn new_with_parentage(
+        subject: Subject,
+        fitness: Fitness,
+        parents: Vec<LineageId>,
+        created_by: &'static str,
+    ) -> Self {
+        FitnessWrapped {
+            fitness,
+            subject: Arc::new(subject),
+            id: next_lineage_id(),
+            parents,
+            created_by: Some(created_by),
+            generation_born: 0,
         }
     }
     pub fn fitness(&self) -> Fitness {
@@ -29,6 +75,48 @@ impl<Subject> FitnessWrapped<Subject> {
     pub fn subject(&self) -> Arc<Subject> {
         self.subject.clone()
     }
+    /// Borrows the subject without bumping the `Arc` refcount, for hot paths
+    /// that only need to read it.
+    pub fn subject_ref(&self) -> &Subject {
+        &self.subject
+    }
+    /// Overwrites the cached fitness without touching the subject — for
+    /// callers that intentionally re-measure a subject in place (e.g.
+    /// [`crate::ga::elite::ReevaluateElitesAction`]) rather than constructing
+    /// a new [`FitnessWrapped`] just to reflect it.
+    pub fn set_fitness(&mut self, fitness: Fitness) {
+        self.fitness = fitness;
+    }
+    /// Unique, process-wide identity assigned when this `FitnessWrapped` was
+    /// constructed, unrelated to `Subject`'s own equality — two subjects with
+    /// identical genes still get distinct ids.
+    pub fn id(&self) -> LineageId {
+        self.id
+    }
+    /// The parent id(s) this subject was produced from, or empty for one
+    /// built via [`Self::new`]/an initial population member.
+    pub fn parents(&self) -> &[LineageId] {
+        &self.parents
+    }
+    /// The operator that produced this subject, or `None` for one built via
+    /// [`Self::new`] rather than [`Self::new_with_parentage`].
+    pub fn created_by(&self) -> Option<&'static str> {
+        self.created_by
+    }
+    /// The generation this subject was created in, defaulting to `0` until
+    /// set explicitly — [`crate::ga::mutation::apply_mutations`]/
+    /// [`crate::ga::reproduction::apply_reproductions`] set it from
+    /// [`crate::ga::GaContext::generation`] as each offspring is produced, so
+    /// [`crate::ga::prune::PruneOlderThan`] can prune by age regardless of
+    /// fitness.
+    pub fn generation_born(&self) -> usize {
+        self.generation_born
+    }
+    /// Overwrites the recorded birth generation — see [`Self::set_fitness`]
+    /// for why this is a setter rather than a new constructor.
+    pub fn set_generation_born(&mut self, generation_born: usize) {
+        self.generation_born = generation_born;
+    }
 }
 
 impl<Subject> From<Subject> for FitnessWrapped<Subject>
@@ -45,6 +133,126 @@ pub trait Fit<Fitness> {
     fn measure(&self) -> Fitness;
 }
 
+/// Opt-in batch counterpart to [`Fit`] for vectorized/SIMD or BLAS-backed
+/// objective functions that evaluate a whole slice of subjects in one call
+/// rather than paying per-subject call overhead.
+pub trait FitBatch: Fit<Fitness> + Sized {
+    fn measure_batch(subjects: &[Self]) -> Vec<Fitness>;
+}
+
+/// Wraps a batch of subjects using [`FitBatch::measure_batch`] instead of
+/// measuring each subject individually, for use anywhere a population is
+/// built or refilled from freshly created subjects.
+pub fn wrap_batch<Subject: FitBatch>(subjects: Vec<Subject>) -> Vec<FitnessWrapped<Subject>> {
+    let fitnesses = Subject::measure_batch(&subjects);
+    subjects
+        .into_iter()
+        .zip(fitnesses)
+        .map(|(subject, fitness)| FitnessWrapped::new(subject, fitness))
+        .collect()
+}
+
+/// Opt-in counterpart to [`Fit`] for program-synthesis style problems judged
+/// against a fixed battery of test cases rather than a single aggregate
+/// score, so selectors like `ga::select::SelectLexicase` can filter
+/// candidates case-by-case instead of only ever seeing the aggregate.
+pub trait PerCaseFitness {
+    /// One error value per test case, lower is better, same order every
+    /// call.
+    fn case_errors(&self) -> Vec<Fitness>;
+}
+
+/// A resampled subject's fitness statistics, as computed by
+/// [`NoisyFitness::measure`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoisyMeasurement {
+    pub mean: Fitness,
+    pub variance: Fitness,
+}
+
+/// Wraps a [`Fit`] subject whose fitness function is stochastic (e.g. a
+/// Monte Carlo simulation), so a single lucky/unlucky sample can't lock a
+/// subject into the population's elite or drop it out unfairly.
+/// [`Fit::measure`] resamples the inner subject `samples` times and reports
+/// the mean, stashing both the mean and the (population) variance of those
+/// samples in [`Self::last_measurement`] so callers can gauge how noisy a
+/// given subject's score still is.
+#[derive(Debug, Clone)]
+pub struct NoisyFitness<Subject> {
+    pub subject: Subject,
+    pub samples: NonZeroUsize,
+    last_measurement: Cell<Option<NoisyMeasurement>>,
+}
+
+impl<Subject> NoisyFitness<Subject> {
+    pub fn new(subject: Subject, samples: NonZeroUsize) -> Self {
+        Self {
+            subject,
+            samples,
+            last_measurement: Cell::new(None),
+        }
+    }
+    /// The mean/variance computed by the most recent [`Fit::measure`] call,
+    /// or `None` if this subject has never been measured yet.
+    pub fn last_measurement(&self) -> Option<NoisyMeasurement> {
+        self.last_measurement.get()
+    }
+}
+
+impl<Subject: Fit<Fitness>> Fit<Fitness> for NoisyFitness<Subject> {
+    fn measure(&self) -> Fitness {
+        let n = self.samples.get() as Fitness;
+        let samples: Vec<Fitness> = (0..self.samples.get()).map(|_| self.subject.measure()).collect();
+        let mean = samples.iter().sum::<Fitness>() / n;
+        let variance = samples.iter().map(|sample| (sample - mean).powi(2)).sum::<Fitness>() / n;
+        self.last_measurement.set(Some(NoisyMeasurement { mean, variance }));
+        mean
+    }
+}
+
+/// Async counterpart to [`Fit`] for fitness functions backed by a network
+/// service, database, or other I/O the synchronous, blocking [`Fit::measure`]
+/// can't express well. Kept a fully separate trait rather than an async
+/// method on `Fit` itself, so every existing synchronous `Fit` impl is
+/// untouched by opting into this one; see [`crate::ga::async_runner`] for
+/// where it's actually awaited.
+#[cfg(feature = "async")]
+pub trait AsyncFit {
+    fn measure_async(&self) -> impl std::future::Future<Output = Fitness> + Send;
+}
+
+/// Pluggable evaluation backend: turns freshly produced, unevaluated
+/// subjects into `FitnessWrapped` subjects, one call per batch. Operators
+/// that currently call `Fit::measure`/[`FitBatch::measure_batch`] directly
+/// can instead be handed an `Evaluator`, so a GPU/CUDA-backed implementation
+/// can receive the whole batch and run it off-thread (blocking this call
+/// until the accelerator replies) without the crate taking on an async
+/// runtime dependency.
+pub trait Evaluator<Subject> {
+    fn evaluate(&self, subjects: Vec<Subject>) -> Vec<FitnessWrapped<Subject>>;
+}
+
+/// Default evaluator: measures each subject individually via [`Fit::measure`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SequentialEvaluator;
+
+impl<Subject: Fit<Fitness>> Evaluator<Subject> for SequentialEvaluator {
+    fn evaluate(&self, subjects: Vec<Subject>) -> Vec<FitnessWrapped<Subject>> {
+        subjects.into_iter().map(FitnessWrapped::from).collect()
+    }
+}
+
+/// Evaluator that defers to [`FitBatch::measure_batch`] for the whole batch,
+/// e.g. for a vectorized objective or one that offloads to an accelerator.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BatchEvaluator;
+
+impl<Subject: FitBatch> Evaluator<Subject> for BatchEvaluator {
+    fn evaluate(&self, subjects: Vec<Subject>) -> Vec<FitnessWrapped<Subject>> {
+        wrap_batch(subjects)
+    }
+}
+
 impl<T> Fit<Fitness> for FitnessWrapped<T> {
     fn measure(&self) -> Fitness {
         self.fitness
@@ -62,6 +270,10 @@ impl<Subject: Debug> Debug for FitnessWrapped<Subject> {
         f.debug_struct("FitnessWrapped")
             .field("fitness", &self.fitness)
             .field("subject", &self.subject)
+            .field("id", &self.id)
+            .field("parents", &self.parents)
+            .field("created_by", &self.created_by)
+            .field("generation_born", &self.generation_born)
             .finish()
     }
 }
@@ -72,11 +284,19 @@ impl<Subject> Clone for FitnessWrapped<Subject> {
         FitnessWrapped {
             fitness: self.fitness,
             subject: self.subject.clone(),
+            id: self.id,
+            parents: self.parents.clone(),
+            created_by: self.created_by,
+            generation_born: self.generation_born,
         }
     }
     fn clone_from(&mut self, source: &Self) {
         self.fitness = source.fitness;
         self.subject = source.subject.clone();
+        self.id = source.id;
+        self.parents = source.parents.clone();
+        self.created_by = source.created_by;
+        self.generation_born = source.generation_born;
     }
 }
 
@@ -93,3 +313,173 @@ impl<Subject: Hash> Hash for FitnessWrapped<Subject> {
         self.subject.hash(state);
     }
 }
+
+// Hand-written rather than derived because `subject` is an `Arc<Subject>`,
+// which we serialize/deserialize by value instead of depending on serde's
+// optional `rc` feature. `id`/`parents`/`created_by`/`generation_born` are
+// deliberately left out of the wire format: a deserialized `FitnessWrapped`
+// gets a fresh id with no recorded parentage/generation, the same way a
+// subject read back from a checkpoint doesn't retroactively populate a
+// `Genealogy`.
+#[cfg(feature = "serde")]
+impl<Subject: serde::Serialize> serde::Serialize for FitnessWrapped<Subject> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("FitnessWrapped", 2)?;
+        state.serialize_field("fitness", &self.fitness)?;
+        state.serialize_field("subject", &*self.subject)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, Subject: serde::Deserialize<'de>> serde::Deserialize<'de> for FitnessWrapped<Subject> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Raw<Subject> {
+            fitness: Fitness,
+            subject: Subject,
+        }
+        let raw = Raw::deserialize(deserializer)?;
+        Ok(FitnessWrapped::new(raw.subject, raw.fitness))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::fitness::{
+        wrap_batch, BatchEvaluator, Evaluator, Fit, FitBatch, Fitness, FitnessWrapped, NoisyFitness,
+        SequentialEvaluator,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Num(u32);
+
+    impl Fit<Fitness> for Num {
+        fn measure(&self) -> Fitness {
+            self.0 as Fitness
+        }
+    }
+
+    impl FitBatch for Num {
+        fn measure_batch(subjects: &[Self]) -> Vec<Fitness> {
+            subjects.iter().map(|subject| subject.0 as Fitness * 2.0).collect()
+        }
+    }
+
+    #[test]
+    fn test_subject_ref_borrows_without_cloning() {
+        let wrapped = FitnessWrapped::new(Num(5), 5.0);
+        assert_eq!(*wrapped.subject_ref(), Num(5));
+    }
+
+    #[test]
+    fn test_new_assigns_distinct_ids_with_no_parentage() {
+        let a = FitnessWrapped::new(Num(1), 1.0);
+        let b = FitnessWrapped::new(Num(1), 1.0);
+        assert_ne!(a.id(), b.id());
+        assert!(a.parents().is_empty());
+        assert_eq!(a.created_by(), None);
+    }
+
+    #[test]
+    fn test_new_with_parentage_records_parents_and_operator() {
+        let parent = FitnessWrapped::new(Num(1), 1.0);
+        let child = FitnessWrapped::new_with_parentage(Num(2), 2.0, vec![parent.id()], "SomeMutator");
+        assert_eq!(child.parents(), &[parent.id()]);
+        assert_eq!(child.created_by(), Some("SomeMutator"));
+    }
+
+    #[test]
+    fn test_generation_born_defaults_to_zero_and_can_be_overwritten() {
+        let mut wrapped = FitnessWrapped::new(Num(1), 1.0);
+        assert_eq!(wrapped.generation_born(), 0);
+        wrapped.set_generation_born(3);
+        assert_eq!(wrapped.generation_born(), 3);
+    }
+
+    #[test]
+    fn test_clone_preserves_id_and_parentage() {
+        let original = FitnessWrapped::new_with_parentage(Num(2), 2.0, vec![1, 2], "SomeMutator");
+        let cloned = original.clone();
+        assert_eq!(cloned.id(), original.id());
+        assert_eq!(cloned.parents(), original.parents());
+        assert_eq!(cloned.created_by(), original.created_by());
+    }
+
+    #[test]
+    fn test_wrap_batch_uses_measure_batch() {
+        let subjects = vec![Num(1), Num(2), Num(3)];
+        let wrapped = wrap_batch(subjects);
+        assert_eq!(
+            wrapped.iter().map(|w| w.fitness()).collect::<Vec<_>>(),
+            vec![2.0, 4.0, 6.0]
+        );
+    }
+
+    #[test]
+    fn test_sequential_evaluator_measures_individually() {
+        let wrapped = SequentialEvaluator.evaluate(vec![Num(1), Num(2)]);
+        assert_eq!(
+            wrapped.iter().map(|w| w.fitness()).collect::<Vec<_>>(),
+            vec![1.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn test_batch_evaluator_uses_measure_batch() {
+        let wrapped = BatchEvaluator.evaluate(vec![Num(1), Num(2)]);
+        assert_eq!(
+            wrapped.iter().map(|w| w.fitness()).collect::<Vec<_>>(),
+            vec![2.0, 4.0]
+        );
+    }
+
+    /// Cycles through a fixed sequence of samples each `measure()` call,
+    /// so [`NoisyFitness`] tests get deterministic mean/variance without
+    /// depending on RNG.
+    struct FixedSamples {
+        samples: Vec<Fitness>,
+        next: std::cell::Cell<usize>,
+    }
+
+    impl Fit<Fitness> for FixedSamples {
+        fn measure(&self) -> Fitness {
+            let index = self.next.get();
+            self.next.set(index + 1);
+            self.samples[index % self.samples.len()]
+        }
+    }
+
+    #[test]
+    fn test_noisy_fitness_measure_returns_mean_of_samples() {
+        let subject = FixedSamples {
+            samples: vec![1.0, 2.0, 3.0],
+            next: std::cell::Cell::new(0),
+        };
+        let noisy = NoisyFitness::new(subject, std::num::NonZeroUsize::new(3).unwrap());
+        assert_eq!(noisy.measure(), 2.0);
+    }
+
+    #[test]
+    fn test_noisy_fitness_stashes_last_measurement() {
+        let subject = FixedSamples {
+            samples: vec![1.0, 2.0, 3.0],
+            next: std::cell::Cell::new(0),
+        };
+        let noisy = NoisyFitness::new(subject, std::num::NonZeroUsize::new(3).unwrap());
+        assert!(noisy.last_measurement().is_none());
+        let mean = noisy.measure();
+        let measurement = noisy.last_measurement().expect("measure() should stash a measurement");
+        assert_eq!(measurement.mean, mean);
+        assert!((measurement.variance - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_set_fitness_overwrites_cached_fitness_in_place() {
+        let mut wrapped = FitnessWrapped::new(Num(1), 1.0);
+        wrapped.set_fitness(9.0);
+        assert_eq!(wrapped.fitness(), 9.0);
+        assert_eq!(*wrapped.subject_ref(), Num(1));
+    }
+}
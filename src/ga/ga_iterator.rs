@@ -6,28 +6,84 @@ use tracing::info;
 use tracing::log::debug;
 
 use crate::ga::fitness::{Fit, Fitness};
+use crate::ga::lineage::Genealogy;
 use crate::ga::population::Population;
+use crate::ga::stats::compute_stats;
 use crate::ga::subject::GaSubject;
+use crate::ga::termination::TerminationReason;
 use crate::ga::{GaAction, GaContext, GeneticAlgorithmOptions};
 
+/// Snapshot of one call to [`GaIterator::next_generation`]: the generation
+/// index and a fitness/size summary of the population it just evaluated, so
+/// a caller doesn't have to reach into [`GaIterState::population`] and call
+/// [`crate::ga::stats::compute_stats`] itself the way
+/// [`crate::ga::csv_stats::CsvStatsRecorder`] and its sibling exporters do
+/// as `GaAction`s.
+///
+/// Offspring/mutation/dedupe counts for the generation aren't included:
+/// nothing in this crate counts those anywhere an action could observe them
+/// (see [`crate::ga::csv_stats::CsvStatsRecorder`]'s docs for the same
+/// limitation), so adding those fields would mean plumbing new counters
+/// through every `GaAction` call site first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenerationSummary {
+    pub generation: usize,
+    pub population_size: usize,
+    pub best_fitness: Fitness,
+    pub worst_fitness: Fitness,
+    pub mean_fitness: Fitness,
+}
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub struct GaIterOptions<Subject> {
     #[derivative(Debug = "ignore")]
     pub debug_print: Option<fn(&Subject)>,
+    /// Whether [`GaIterState::genealogy`] is updated each generation. `false`
+    /// by default: an unbounded ancestry DAG growing for the life of the run
+    /// isn't something every caller wants paying for, especially alongside
+    /// [`crate::ga::population::Population::enforce_memory_budget`], which
+    /// caps population size but has no bearing on this. Callers that want
+    /// [`crate::ga::lineage::write_dot`] output need to opt in.
+    pub track_genealogy: bool,
 }
 
 impl<Subject> Default for GaIterOptions<Subject> {
     fn default() -> Self {
-        Self { debug_print: None }
+        Self {
+            debug_print: None,
+            track_genealogy: false,
+        }
     }
 }
 
+/// Ad hoc serde support for inspecting/round-tripping a `GaIterState`
+/// directly (e.g. logging it, or a test fixture). For actually checkpointing
+/// a long-running `GaRunner` loop to disk and resuming it, prefer
+/// [`crate::ga::checkpoint::Checkpoint`], which additionally versions the
+/// on-disk format and resets `termination_reason` on resume rather than
+/// round-tripping it verbatim the way this derive does.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GaIterState<Subject> {
     pub(crate) context: GaContext,
     pub(crate) current_fitness: Option<Fitness>,
     pub(crate) reverse_mode_enabled: Option<bool>,
+    pub(crate) termination_reason: Option<TerminationReason>,
     pub population: Population<Subject>,
+    /// Ancestry DAG of every subject seen so far, auto-updated once per
+    /// generation — while [`GaIterOptions::track_genealogy`] is set — from
+    /// whatever parent/operator provenance
+    /// [`crate::ga::mutation::apply_mutations`]/
+    /// [`crate::ga::reproduction::apply_reproductions`] recorded on each
+    /// [`crate::ga::fitness::FitnessWrapped`] — see [`Genealogy`]. Left
+    /// empty (and costs nothing beyond the field itself) unless that option
+    /// is turned on: it has no eviction of its own, so a run that opts in
+    /// grows this for as long as it runs. Not (de)serialized: like
+    /// `termination_reason`, it's reconstructed as the run continues rather
+    /// than round-tripped.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub genealogy: Genealogy,
 }
 
 impl<Subject> GaIterState<Subject> {
@@ -37,11 +93,25 @@ impl<Subject> GaIterState<Subject> {
             context,
             current_fitness: None,
             reverse_mode_enabled: None,
+            termination_reason: None,
+            genealogy: Genealogy::new(),
         }
     }
     pub fn context(&self) -> &GaContext {
         &self.context
     }
+    /// Why the run stopped advancing generations, once it has. `None` while
+    /// the run is still in progress. See [`TerminationReason`].
+    pub fn termination_reason(&self) -> Option<TerminationReason> {
+        self.termination_reason
+    }
+    /// The best fitness seen so far, updated as each generation's population
+    /// is sorted. `None` before the first generation has run. Used by
+    /// [`crate::ga::termination::Terminator`] implementations that need to
+    /// inspect fitness without crate-internal access to [`GaIterState`].
+    pub fn current_fitness(&self) -> Option<Fitness> {
+        self.current_fitness
+    }
     pub(crate) fn get_or_determine_reverse_mode_from_options<Actions>(
         &self,
         options: &GeneticAlgorithmOptions<Actions>,
@@ -75,7 +145,9 @@ impl<Subject: Debug> Debug for GaIterState<Subject> {
             .field("context", &self.context)
             .field("current_fitness", &self.current_fitness)
             .field("reverse_mode_enabled", &self.reverse_mode_enabled)
+            .field("termination_reason", &self.termination_reason)
             .field("population", &self.population)
+            .field("genealogy", &self.genealogy)
             .finish()
     }
 }
@@ -119,8 +191,18 @@ where
         &mut self.state
     }
 
+    /// Consumes the iterator, handing back its final [`GaIterState`]. For
+    /// callers (e.g. [`crate::ga::island::run_islands`]) that drive several
+    /// `GaIterator`s to completion and then only care about their end states.
+    pub fn into_state(self) -> GaIterState<Subject> {
+        self.state
+    }
+
     pub fn is_fitness_at_target(&self) -> bool {
-        Some(self.options.target_fitness()) == self.state.current_fitness
+        let Some(current_fitness) = self.state.current_fitness else {
+            return false;
+        };
+        (current_fitness - self.options.target_fitness()).abs() <= self.options.target_fitness_epsilon
     }
 
     pub fn is_fitness_within_range(&self) -> bool {
@@ -136,7 +218,39 @@ where
         }
     }
 
-    pub fn next_generation(&mut self) -> Option<Fitness> {
+    pub fn next_generation(&mut self) -> Option<GenerationSummary> {
+        let start = std::time::Instant::now();
+        let span = tracing::info_span!(
+            "next_generation",
+            generation = tracing::field::Empty,
+            population_size = tracing::field::Empty,
+            duration_ms = tracing::field::Empty,
+            termination_reason = tracing::field::Empty,
+        );
+        let _enter = span.enter();
+        let result = self.next_generation_inner();
+        span.record("generation", self.state.context.generation);
+        span.record("population_size", self.state.population.subjects.len());
+        span.record("duration_ms", start.elapsed().as_secs_f64() * 1000.0);
+        if let Some(reason) = self.state.termination_reason {
+            span.record("termination_reason", reason.to_string());
+        }
+        result
+    }
+
+    /// Fallible counterpart to [`Self::next_generation`] for callers that
+    /// want a subject whose fitness is NaN reported as an error rather than
+    /// sorted to the back, which is what `Population::sort`/`sort_rev` do with
+    /// it. Reports [`crate::error::Error::NonFiniteFitness`] instead of
+    /// sorting in that case.
+    pub fn try_next_generation(&mut self) -> crate::error::Result<Option<GenerationSummary>> {
+        if self.state.population.subjects.iter().any(|s| s.fitness().is_nan()) {
+            return Err(crate::error::Error::NonFiniteFitness);
+        }
+        Ok(self.next_generation())
+    }
+
+    fn next_generation_inner(&mut self) -> Option<GenerationSummary> {
         self.state.context.generation += 1;
         let generation_ix = self.state.context.generation;
         let target_fitness = self.options.target_fitness();
@@ -146,6 +260,7 @@ where
         } else {
             self.state.population.sort();
         }
+        self.state.population.enforce_memory_budget();
         if let Some(wrapped_subject) = self.state.population.subjects.first() {
             let subject = wrapped_subject;
             let fitness_will_update = if self.is_reverse_mode {
@@ -156,7 +271,7 @@ where
             if fitness_will_update {
                 self.state.current_fitness = Some(subject.fitness());
                 info!("generation: {generation_ix}, fitness: {current_fitness:?}/{target_fitness}");
-                self.debug_print(&subject.subject())
+                self.debug_print(subject.subject_ref())
             }
             if !self.options.fitness_range.contains(&subject.fitness()) {
                 debug!(
@@ -165,19 +280,39 @@ where
                     self.options.fitness_range.end,
                     subject.fitness()
                 );
-                self.debug_print(&subject.subject());
+                self.debug_print(subject.subject_ref());
+                self.state.termination_reason = Some(TerminationReason::OutOfRange);
                 return None;
             }
             if self.options.target_fitness() == subject.fitness() {
                 debug!("target fitness reached: {target_fitness}, generation: {generation_ix}");
-                self.debug_print(&subject.subject());
+                self.debug_print(subject.subject_ref());
+                self.state.termination_reason = Some(TerminationReason::TargetReached);
                 return None;
             }
         }
 
+        let summary = compute_stats(&self.state.population).map(|stats| {
+            let (best_fitness, worst_fitness) = if self.is_reverse_mode {
+                (stats.max_fitness, stats.min_fitness)
+            } else {
+                (stats.min_fitness, stats.max_fitness)
+            };
+            GenerationSummary {
+                generation: generation_ix,
+                population_size: self.state.population.subjects.len(),
+                best_fitness,
+                worst_fitness,
+                mean_fitness: stats.mean_fitness,
+            }
+        });
+
         self.options
             .actions
             .perform_action(&self.state.context, &mut self.state.population);
-        self.state.current_fitness
+        if self.ga_iter_options.track_genealogy {
+            self.state.genealogy.observe(&self.state.population.subjects);
+        }
+        summary
     }
 }
@@ -1,11 +1,27 @@
 use std::hash::Hash;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use derivative::Derivative;
+use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::ThreadPool;
 
+#[cfg(feature = "checkpoint")]
+use crate::ga::checkpoint::CHECKPOINT_FORMAT_VERSION;
+use crate::ga::csv_stats::CsvReporter;
+use crate::ga::event::{GaEvent, SharedGaEventListener};
 use crate::ga::fitness::{Fit, Fitness};
+#[cfg(feature = "jsonl-log")]
+use crate::ga::jsonl_log::JsonlReporter;
+#[cfg(feature = "plot")]
+use crate::ga::plot::PlotReporter;
 use crate::ga::ga_iterator::{GaIterOptions, GaIterState, GaIterator};
 use crate::ga::population::Population;
+use crate::ga::progress::{ProgressReporter, ProgressUpdate};
 use crate::ga::subject::GaSubject;
+use crate::ga::termination::TerminationReason;
 use crate::ga::{GaAction, GaContext, GeneticAlgorithmOptions};
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -13,17 +29,186 @@ pub enum GaRunnerCustomForEachGenerationResult {
     Terminate,
 }
 
+/// What [`GaRunner::run`]/[`GaRunner::resume`] hand back once a run stops,
+/// so a caller doesn't have to smuggle the outcome out through
+/// `before_each_generation`/`after_each_generation` callbacks the way
+/// [`crate::ga::operator_stats`]'s tracked operators smuggle out stats via a
+/// shared handle. `best_subject` is an `Arc`, like [`crate::ga::fitness::FitnessWrapped::subject`],
+/// so returning it doesn't require `Subject: Clone`.
+#[derive(Derivative)]
+#[derivative(Debug(bound = "Subject: std::fmt::Debug"), Clone(bound = ""))]
+pub struct GaRunResult<Subject> {
+    /// The best subject in the final population (lowest fitness, or highest
+    /// in reverse mode), or `None` if that population is empty.
+    pub best_subject: Option<Arc<Subject>>,
+    /// [`Self::best_subject`]'s fitness.
+    pub best_fitness: Option<Fitness>,
+    /// How many generations actually ran.
+    pub generations: usize,
+    pub termination_reason: TerminationReason,
+    /// Wall-clock time spent in [`GaRunner::run_generations`], from just
+    /// before the first generation to the moment the loop stopped.
+    pub elapsed: Duration,
+}
+
 // TODO: should this be GaIterator? at the expense of requiring the generics to be known at GaRunner construction
 type EachGenerationFnOpt<Subject> =
     Option<fn(&mut GaIterState<Subject>) -> Option<GaRunnerCustomForEachGenerationResult>>;
 
+/// Encodes a subject for [`GaRunnerOptions::export_best_every`]. A type alias
+/// rather than an inline `Option<fn(...)>` so the field declaration doesn't
+/// trip clippy's `type_complexity` lint.
+type ExportBestEncodeFn<Subject> = Option<fn(&Subject) -> std::io::Result<Vec<u8>>>;
+
+/// A borrowed, serializable snapshot of a [`GaIterState`], shaped like
+/// [`crate::ga::checkpoint::Checkpoint`] but holding references instead of
+/// owning a cloned [`Population`]. `Checkpoint::from_state` requires
+/// `Subject: Clone` (to clone the population); borrowing it instead lets
+/// [`GaRunner`] write periodic checkpoints without demanding `Clone` from
+/// every subject type, since `serde::Serialize` only ever needs `&self`.
+/// The JSON it produces is shaped identically to `Checkpoint`'s, so it
+/// still round-trips through [`crate::ga::checkpoint::read_checkpoint`].
+#[cfg(feature = "checkpoint")]
+#[derive(serde::Serialize)]
+pub struct CheckpointSnapshot<'a, Subject> {
+    pub format_version: u32,
+    pub options_digest: Option<u64>,
+    pub context: &'a GaContext,
+    pub current_fitness: Option<Fitness>,
+    pub reverse_mode_enabled: Option<bool>,
+    pub population: &'a Population<Subject>,
+}
+
+/// Encodes a [`CheckpointSnapshot`] for [`CheckpointOptions::encode`], e.g.
+/// `|snapshot| serde_json::to_vec(snapshot).map_err(std::io::Error::other)`.
+/// A plain fn pointer, like [`ExportBestEncodeFn`], so `GaRunner` doesn't
+/// need a `Serialize` bound or a codec generic parameter.
+#[cfg(feature = "checkpoint")]
+type CheckpointEncodeFn<Subject> = fn(&CheckpointSnapshot<'_, Subject>) -> std::io::Result<Vec<u8>>;
+
+/// Configures [`GaRunnerOptions::checkpoint`]: how often to snapshot the run
+/// to disk and how many rotated snapshots to keep around.
+#[cfg(feature = "checkpoint")]
+#[derive(Derivative, Clone)]
+#[derivative(Debug)]
+pub struct CheckpointOptions<Subject> {
+    /// Write a checkpoint every this many generations. `0` disables writing
+    /// (equivalent to leaving [`GaRunnerOptions::checkpoint`] as `None`).
+    pub every_n_generations: usize,
+    /// Base path for checkpoint files; the current generation number is
+    /// appended to the file name, e.g. `run.checkpoint` becomes
+    /// `run.checkpoint.42`.
+    pub path: PathBuf,
+    /// How many of the most recently written checkpoint files to retain;
+    /// older ones are deleted (best-effort) as new ones are written. `None`
+    /// keeps every checkpoint ever written.
+    pub keep_last: Option<usize>,
+    /// Forwarded as [`crate::ga::checkpoint::Checkpoint::options_digest`] on
+    /// every snapshot, so a caller reading one back with
+    /// [`crate::ga::checkpoint::read_checkpoint`] can compare it against its
+    /// current [`GeneticAlgorithmOptions`] before calling [`GaRunner::resume`],
+    /// which does not check it. `None` writes no digest.
+    pub options_digest: Option<u64>,
+    #[derivative(Debug = "ignore")]
+    pub encode: CheckpointEncodeFn<Subject>,
+}
+
 #[derive(Derivative, Clone, Default)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GaRunnerOptions<Subject> {
     #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub debug_print: Option<fn(&Subject)>,
+    /// Forwarded to [`crate::ga::ga_iterator::GaIterOptions::track_genealogy`].
+    /// `false` by default.
+    pub track_genealogy: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub before_each_generation: EachGenerationFnOpt<Subject>,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub after_each_generation: EachGenerationFnOpt<Subject>,
+    /// Writes the current generation's best subject to [`Self::export_best_path`]
+    /// every `export_best_every` generations. `None`, or leaving either of
+    /// the other two `export_best_*` fields unset, disables exporting.
+    pub export_best_every: Option<usize>,
+    /// Destination file for [`Self::export_best_every`]; overwritten on each
+    /// export. Read it back with, e.g., [`crate::ga::codec::read_subject_file`]
+    /// to seed a later run.
+    pub export_best_path: Option<PathBuf>,
+    /// Encodes a subject for [`Self::export_best_every`], e.g.
+    /// `|subject| JsonCodec.encode(subject)` from [`crate::ga::codec`]. Kept
+    /// as a plain fn pointer, like [`Self::debug_print`], so `GaRunner`
+    /// doesn't need a `Serialize` bound or a codec generic parameter.
+    #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub export_best_encode: ExportBestEncodeFn<Subject>,
+    /// Appends one row per generation (generation, best fitness, mean
+    /// fitness, fitness stddev, population size, elapsed ms since the run
+    /// started) to this path via [`crate::ga::csv_stats::CsvReporter`].
+    /// `None` disables it. For a [`GaAction`]-driven dump that also reports
+    /// min/max/diversity instead of best-so-far and elapsed time, see
+    /// [`crate::ga::csv_stats::CsvStatsRecorder`].
+    pub csv_report_path: Option<PathBuf>,
+    /// Appends one JSON-lines record per generation, plus a final `summary`
+    /// record once the run stops, to this path via
+    /// [`crate::ga::jsonl_log::JsonlReporter`]. `None` disables it. For a
+    /// [`GaAction`]-driven log with no final summary event, see
+    /// [`crate::ga::jsonl_log::JsonlRunLogger`].
+    #[cfg(feature = "jsonl-log")]
+    pub jsonl_report_path: Option<PathBuf>,
+    /// Renders a best/mean fitness chart via
+    /// [`crate::ga::plot::PlotReporter`] to this path (extension `.svg` or
+    /// `.png`) once the run stops, and additionally every
+    /// [`Self::plot_report_every`] generations while it's still running.
+    /// `None` disables it.
+    #[cfg(feature = "plot")]
+    pub plot_report_path: Option<PathBuf>,
+    /// How often (in generations) to re-render [`Self::plot_report_path`]
+    /// while the run is in progress, on top of the render at run end.
+    /// `None` or `0` renders only at run end.
+    #[cfg(feature = "plot")]
+    pub plot_report_every: Option<usize>,
+    /// Dedicated rayon thread pool to run generations on instead of the
+    /// global pool, so embedding applications can bound GA CPU usage and
+    /// isolate it from their own rayon work. Only has an effect with the
+    /// `parallel` feature enabled.
+    #[cfg(feature = "parallel")]
+    #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub thread_pool: Option<Arc<ThreadPool>>,
+    /// Stops the run once this much wall-clock time has elapsed since
+    /// [`GaRunner::run`] started, reporting [`TerminationReason::Budget`],
+    /// so a CI job or hyperparameter sweep gets a result back within a
+    /// fixed time box instead of running to convergence (or forever).
+    /// Checked at the same generation boundary as the other termination
+    /// conditions, so `before_each_generation`/`after_each_generation` for
+    /// the last completed generation still run before the run stops.
+    pub max_duration: Option<Duration>,
+    /// Periodically snapshots the run to disk via [`crate::ga::checkpoint::Checkpoint`],
+    /// rotating old files, so a crashed long run can be resumed with
+    /// [`GaRunner::resume`] instead of starting over. `None` disables it.
+    #[cfg(feature = "checkpoint")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub checkpoint: Option<CheckpointOptions<Subject>>,
+    /// Invoked once per generation, and once more after the run terminates,
+    /// via [`crate::ga::progress::ProgressReporter`] — e.g. to drive an
+    /// `indicatif` progress bar with [`crate::ga::progress::IndicatifProgressReporter`]
+    /// (behind the `indicatif` feature) — instead of hand-rolling progress
+    /// tracking in [`Self::before_each_generation`]/[`Self::after_each_generation`],
+    /// which only see a `&mut GaIterState`, not elapsed time or a
+    /// fitness-range-based completion fraction. `None` disables it.
+    #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub progress_reporter: Option<Arc<dyn ProgressReporter + Send + Sync>>,
+    /// Notified of every [`crate::ga::event::GaEvent`]
+    /// [`GaRunner`] observes, in registration order, so logging, metrics,
+    /// and checkpointing-style concerns can subscribe without being wired
+    /// directly into [`Self::run_generations`](GaRunner::run_generations)'s
+    /// loop the way [`Self::csv_report_path`]/[`Self::jsonl_report_path`]/
+    /// [`Self::plot_report_path`] are. Empty by default.
+    #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub listeners: Vec<SharedGaEventListener>,
 }
 
 pub struct GaRunner<Subject>
@@ -31,6 +216,12 @@ where
     Subject: Fit<Fitness> + Hash + PartialEq + Eq,
 {
     runner_options: GaRunnerOptions<Subject>,
+    csv_reporter: Option<CsvReporter>,
+    #[cfg(feature = "jsonl-log")]
+    jsonl_reporter: Option<JsonlReporter>,
+    #[cfg(feature = "plot")]
+    plot_reporter: Option<PlotReporter>,
+    progress_reporter: Option<Arc<dyn ProgressReporter + Send + Sync>>,
 }
 
 impl<Subject> GaRunner<Subject>
@@ -38,48 +229,534 @@ where
     Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq,
 {
     pub fn new(runner_options: GaRunnerOptions<Subject>) -> Self {
-        Self { runner_options }
+        let csv_reporter = runner_options.csv_report_path.clone().map(CsvReporter::new);
+        #[cfg(feature = "jsonl-log")]
+        let jsonl_reporter = runner_options.jsonl_report_path.clone().map(JsonlReporter::new);
+        #[cfg(feature = "plot")]
+        let plot_reporter = runner_options.plot_report_path.clone().map(PlotReporter::new);
+        let progress_reporter = runner_options.progress_reporter.clone();
+        Self {
+            runner_options,
+            csv_reporter,
+            #[cfg(feature = "jsonl-log")]
+            jsonl_reporter,
+            #[cfg(feature = "plot")]
+            plot_reporter,
+            progress_reporter,
+        }
     }
-    pub fn run<Actions>(
+
+    /// Appends a record to [`GaRunnerOptions::jsonl_report_path`]'s
+    /// [`JsonlReporter`], if one was configured. Computes mean/stddev the
+    /// same way as [`Self::report_csv_if_due`], for the same reason.
+    #[cfg(feature = "jsonl-log")]
+    fn report_jsonl_if_due(&self, state: &GaIterState<Subject>, elapsed: Duration) {
+        let Some(reporter) = self.jsonl_reporter.as_ref() else {
+            return;
+        };
+        if state.population.subjects.is_empty() {
+            return;
+        }
+        let fitnesses: Vec<Fitness> = state.population.subjects.iter().map(|s| s.fitness()).collect();
+        let n = fitnesses.len() as f64;
+        let mean_fitness = fitnesses.iter().sum::<Fitness>() / n;
+        let variance = fitnesses.iter().map(|fitness| (fitness - mean_fitness).powi(2)).sum::<Fitness>() / n;
+        reporter.report_generation(
+            state.context().generation,
+            state.current_fitness().unwrap_or(mean_fitness),
+            mean_fitness,
+            variance.sqrt(),
+            fitnesses.len(),
+            elapsed,
+        );
+    }
+
+    /// Appends a row to [`GaRunnerOptions::csv_report_path`]'s
+    /// [`CsvReporter`], if one was configured. Computes mean/stddev directly
+    /// rather than via [`crate::ga::stats::compute_stats`] since that also
+    /// requires `Subject: Hash` (+ `Send + Sync` under `parallel`) for its
+    /// duplicate-counting diversity metric, which this doesn't need.
+    fn report_csv_if_due(&self, state: &GaIterState<Subject>, elapsed: Duration) {
+        let Some(reporter) = self.csv_reporter.as_ref() else {
+            return;
+        };
+        if state.population.subjects.is_empty() {
+            return;
+        }
+        let fitnesses: Vec<Fitness> = state.population.subjects.iter().map(|s| s.fitness()).collect();
+        let n = fitnesses.len() as f64;
+        let mean_fitness = fitnesses.iter().sum::<Fitness>() / n;
+        let variance = fitnesses.iter().map(|fitness| (fitness - mean_fitness).powi(2)).sum::<Fitness>() / n;
+        reporter.report(
+            state.context().generation,
+            state.current_fitness().unwrap_or(mean_fitness),
+            mean_fitness,
+            variance.sqrt(),
+            fitnesses.len(),
+            elapsed,
+        );
+    }
+
+    /// Records a point to [`GaRunnerOptions::plot_report_path`]'s
+    /// [`PlotReporter`] and re-renders it, if one was configured. Called
+    /// every [`GaRunnerOptions::plot_report_every`] generations from
+    /// [`Self::run_generations`]'s loop, and once more, unconditionally,
+    /// right before the run ends.
+    #[cfg(feature = "plot")]
+    fn record_plot_point(&self, state: &GaIterState<Subject>) {
+        let Some(reporter) = self.plot_reporter.as_ref() else {
+            return;
+        };
+        if state.population.subjects.is_empty() {
+            return;
+        }
+        let fitnesses: Vec<Fitness> = state.population.subjects.iter().map(|s| s.fitness()).collect();
+        let mean_fitness = fitnesses.iter().sum::<Fitness>() / fitnesses.len() as f64;
+        reporter.record(
+            state.context().generation,
+            state.current_fitness().unwrap_or(mean_fitness),
+            mean_fitness,
+        );
+    }
+
+    /// Notifies every [`GaRunnerOptions::listeners`] of `event`, in
+    /// registration order.
+    fn emit(&self, event: GaEvent) {
+        for listener in &self.runner_options.listeners {
+            listener.on_event(&event);
+        }
+    }
+
+    /// Builds a [`ProgressUpdate`] from `state`/`elapsed` and the run's
+    /// configured fitness range, and hands it to
+    /// [`GaRunnerOptions::progress_reporter`], if one was configured.
+    /// `initial_fitness`/`target_fitness` come from
+    /// [`GeneticAlgorithmOptions::fitness_initial_to_target_range`], captured
+    /// by [`Self::run_generations`] before that value is moved into the
+    /// [`GaIterator`] it constructs.
+    fn report_progress(
+        &self,
+        state: &GaIterState<Subject>,
+        elapsed: Duration,
+        initial_fitness: Fitness,
+        target_fitness: Fitness,
+    ) {
+        let Some(reporter) = self.progress_reporter.as_ref() else {
+            return;
+        };
+        let update = Self::progress_update(state, elapsed, initial_fitness, target_fitness);
+        reporter.on_generation(&update);
+    }
+
+    fn progress_update(
+        state: &GaIterState<Subject>,
+        elapsed: Duration,
+        initial_fitness: Fitness,
+        target_fitness: Fitness,
+    ) -> ProgressUpdate {
+        let best_fitness = state.current_fitness();
+        let span = target_fitness - initial_fitness;
+        let progress_fraction = best_fitness.and_then(|best| {
+            if span == 0.0 {
+                None
+            } else {
+                Some(((best - initial_fitness) / span).clamp(0.0, 1.0))
+            }
+        });
+        ProgressUpdate {
+            generation: state.context().generation,
+            population_size: state.population.subjects.len(),
+            elapsed,
+            best_fitness,
+            progress_fraction,
+        }
+    }
+
+    #[cfg(feature = "plot")]
+    fn report_plot_if_due(&self, state: &GaIterState<Subject>) {
+        let due = self
+            .runner_options
+            .plot_report_every
+            .map(|every| every != 0 && state.context().generation.is_multiple_of(every))
+            .unwrap_or(false);
+        if due {
+            self.record_plot_point(state);
+        }
+    }
+
+    fn run_generations<Actions>(
         &mut self,
         ga_options: GeneticAlgorithmOptions<Actions>,
-        population: Population<Subject>,
-    ) where
+        state: GaIterState<Subject>,
+    ) -> GaRunResult<Subject>
+    where
         Actions: GaAction<Subject = Subject>,
     {
-        #[cfg(test)]
-        {
-            simple_ga_internal_lib::tracing::init_tracing();
-        }
+        let initial_fitness = ga_options.initial_fitness();
+        let target_fitness = ga_options.target_fitness();
         let mut ga_iter = GaIterator::new_with_options(
             ga_options,
-            GaIterState::new(GaContext::default(), population),
+            state,
             GaIterOptions {
                 debug_print: self.runner_options.debug_print,
+                track_genealogy: self.runner_options.track_genealogy,
             },
         );
-        while ga_iter.is_fitness_within_range() && !ga_iter.is_fitness_at_target() {
+        let start = Instant::now();
+        #[cfg(feature = "checkpoint")]
+        let mut written_checkpoints: Vec<PathBuf> = Vec::new();
+        loop {
+            if !ga_iter.is_fitness_within_range() {
+                ga_iter.state_mut().termination_reason = Some(TerminationReason::OutOfRange);
+                break;
+            }
+            if ga_iter.is_fitness_at_target() {
+                ga_iter.state_mut().termination_reason = Some(TerminationReason::TargetReached);
+                if let Some(fitness) = ga_iter.state().current_fitness() {
+                    self.emit(GaEvent::TargetReached {
+                        generation: ga_iter.state().context().generation,
+                        fitness,
+                    });
+                }
+                break;
+            }
+            if let Some(max_duration) = self.runner_options.max_duration {
+                if start.elapsed() >= max_duration {
+                    ga_iter.state_mut().termination_reason = Some(TerminationReason::Budget);
+                    break;
+                }
+            }
             if let Some(before_each) = self.runner_options.before_each_generation {
                 if let Some(result) = before_each(ga_iter.state_mut()) {
                     match result {
-                        GaRunnerCustomForEachGenerationResult::Terminate => break,
+                        GaRunnerCustomForEachGenerationResult::Terminate => {
+                            ga_iter.state_mut().termination_reason =
+                                Some(TerminationReason::UserRequested);
+                            break;
+                        }
                     }
                 }
             }
+            self.emit(GaEvent::GenerationStarted {
+                generation: ga_iter.state().context().generation + 1,
+            });
+            let previous_best_fitness = ga_iter.state().current_fitness();
             if ga_iter.next_generation().is_none() {
+                // next_generation already recorded why on ga_iter.state().
                 break;
             }
+            let generation = ga_iter.state().context().generation;
+            self.emit(GaEvent::GenerationCompleted {
+                generation,
+                population_size: ga_iter.state().population.subjects.len(),
+            });
+            if let Some(fitness) = ga_iter.state().current_fitness() {
+                if Some(fitness) != previous_best_fitness {
+                    self.emit(GaEvent::BestImproved { generation, fitness });
+                }
+            }
+            self.export_best_if_due(ga_iter.state());
+            self.report_progress(ga_iter.state(), start.elapsed(), initial_fitness, target_fitness);
+            self.report_csv_if_due(ga_iter.state(), start.elapsed());
+            #[cfg(feature = "jsonl-log")]
+            self.report_jsonl_if_due(ga_iter.state(), start.elapsed());
+            #[cfg(feature = "plot")]
+            self.report_plot_if_due(ga_iter.state());
+            #[cfg(feature = "checkpoint")]
+            self.checkpoint_if_due(ga_iter.state(), &mut written_checkpoints);
             if let Some(after_each) = self.runner_options.after_each_generation {
                 if let Some(result) = after_each(ga_iter.state_mut()) {
                     match result {
-                        GaRunnerCustomForEachGenerationResult::Terminate => break,
+                        GaRunnerCustomForEachGenerationResult::Terminate => {
+                            ga_iter.state_mut().termination_reason =
+                                Some(TerminationReason::UserRequested);
+                            break;
+                        }
                     }
                 }
             }
         }
+        let termination_reason = ga_iter
+            .state()
+            .termination_reason()
+            .expect("a break point above always sets a reason");
+        tracing::info!(
+            reason = %termination_reason,
+            generation = ga_iter.state().context().generation,
+            "ga run terminated"
+        );
+        self.emit(GaEvent::RunTerminated {
+            generation: ga_iter.state().context().generation,
+            reason: termination_reason,
+        });
+        #[cfg(feature = "jsonl-log")]
+        if let Some(reporter) = self.jsonl_reporter.as_ref() {
+            reporter.report_summary(
+                ga_iter.state().context().generation,
+                ga_iter.state().current_fitness(),
+                termination_reason,
+                start.elapsed(),
+            );
+        }
+        #[cfg(feature = "plot")]
+        self.record_plot_point(ga_iter.state());
+        if let Some(reporter) = self.progress_reporter.as_ref() {
+            let update = Self::progress_update(ga_iter.state(), start.elapsed(), initial_fitness, target_fitness);
+            reporter.on_finish(&update, termination_reason);
+        }
+        let state = ga_iter.into_state();
+        let subjects = state.population.subjects.iter();
+        let best = if state.reverse_mode_enabled == Some(true) {
+            subjects.max_by(|a, b| a.fitness().partial_cmp(&b.fitness()).unwrap_or(std::cmp::Ordering::Equal))
+        } else {
+            subjects.min_by(|a, b| a.fitness().partial_cmp(&b.fitness()).unwrap_or(std::cmp::Ordering::Equal))
+        };
+        GaRunResult {
+            best_subject: best.map(|best| best.subject()),
+            best_fitness: best.map(|best| best.fitness()),
+            generations: state.context().generation,
+            termination_reason,
+            elapsed: start.elapsed(),
+        }
+    }
+
+    /// Writes out the current best subject if `export_best_every` generations
+    /// have passed, per [`GaRunnerOptions::export_best_every`].
+    fn export_best_if_due(&self, state: &GaIterState<Subject>) {
+        let (Some(every), Some(path), Some(encode)) = (
+            self.runner_options.export_best_every,
+            self.runner_options.export_best_path.as_ref(),
+            self.runner_options.export_best_encode,
+        ) else {
+            return;
+        };
+        if every == 0 || !state.context().generation.is_multiple_of(every) {
+            return;
+        }
+        let subjects = state.population.subjects.iter();
+        let best = if state.reverse_mode_enabled == Some(true) {
+            subjects.max_by(|a, b| a.fitness().partial_cmp(&b.fitness()).unwrap_or(std::cmp::Ordering::Equal))
+        } else {
+            subjects.min_by(|a, b| a.fitness().partial_cmp(&b.fitness()).unwrap_or(std::cmp::Ordering::Equal))
+        };
+        let Some(best) = best else {
+            return;
+        };
+        match encode(best.subject_ref()) {
+            Ok(bytes) => {
+                if let Err(err) = std::fs::write(path, bytes) {
+                    tracing::log::warn!("failed to export best subject: {err}");
+                }
+            }
+            Err(err) => tracing::log::warn!("failed to encode best subject for export: {err}"),
+        }
+    }
+
+    /// Writes a [`CheckpointSnapshot`] of `state` to disk if
+    /// `every_n_generations` generations have passed, per
+    /// [`GaRunnerOptions::checkpoint`], then deletes checkpoints beyond
+    /// [`CheckpointOptions::keep_last`], oldest first. `written` tracks the
+    /// paths this run has written so far, so rotation only ever deletes
+    /// files this call wrote, never a caller's pre-existing files that
+    /// happen to share the base path.
+    #[cfg(feature = "checkpoint")]
+    fn checkpoint_if_due(&self, state: &GaIterState<Subject>, written: &mut Vec<PathBuf>) {
+        let Some(checkpoint_options) = self.runner_options.checkpoint.as_ref() else {
+            return;
+        };
+        if checkpoint_options.every_n_generations == 0
+            || !state
+                .context()
+                .generation
+                .is_multiple_of(checkpoint_options.every_n_generations)
+        {
+            return;
+        }
+        let snapshot = CheckpointSnapshot {
+            format_version: CHECKPOINT_FORMAT_VERSION,
+            options_digest: checkpoint_options.options_digest,
+            context: state.context(),
+            current_fitness: state.current_fitness,
+            reverse_mode_enabled: state.reverse_mode_enabled,
+            population: &state.population,
+        };
+        let bytes = match (checkpoint_options.encode)(&snapshot) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                tracing::log::warn!("failed to encode checkpoint: {err}");
+                return;
+            }
+        };
+        let file_name = format!(
+            "{}.{}",
+            checkpoint_options
+                .path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("checkpoint"),
+            state.context().generation
+        );
+        let path = checkpoint_options.path.with_file_name(file_name);
+        if let Err(err) = std::fs::write(&path, bytes) {
+            tracing::log::warn!("failed to write checkpoint: {err}");
+            return;
+        }
+        written.push(path);
+        if let Some(keep_last) = checkpoint_options.keep_last {
+            while written.len() > keep_last {
+                let oldest = written.remove(0);
+                if let Err(err) = std::fs::remove_file(&oldest) {
+                    tracing::log::warn!(
+                        "failed to delete rotated checkpoint {}: {err}",
+                        oldest.display()
+                    );
+                }
+            }
+        }
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    pub fn run<Actions>(
+        &mut self,
+        ga_options: GeneticAlgorithmOptions<Actions>,
+        population: Population<Subject>,
+    ) -> GaRunResult<Subject>
+    where
+        Actions: GaAction<Subject = Subject>,
+    {
+        let context = seeded_context(ga_options.seed);
+        self.resume(ga_options, GaIterState::new(context, population))
+    }
+
+    #[cfg(feature = "parallel")]
+    pub fn run<Actions>(
+        &mut self,
+        ga_options: GeneticAlgorithmOptions<Actions>,
+        population: Population<Subject>,
+    ) -> GaRunResult<Subject>
+    where
+        Actions: GaAction<Subject = Subject> + Send,
+        Subject: Send + Sync,
+    {
+        let context = seeded_context(ga_options.seed);
+        self.resume(ga_options, GaIterState::new(context, population))
+    }
+
+    /// Continues a run from a previously saved [`GaIterState`] (e.g. one
+    /// restored via [`crate::ga::checkpoint::Checkpoint::into_state`])
+    /// instead of starting fresh from a [`Population`], carrying over its
+    /// generation counter, reverse-mode flag, and current fitness so a
+    /// crashed long-running job doesn't have to start over.
+    #[cfg(not(feature = "parallel"))]
+    pub fn resume<Actions>(
+        &mut self,
+        ga_options: GeneticAlgorithmOptions<Actions>,
+        state: GaIterState<Subject>,
+    ) -> GaRunResult<Subject>
+    where
+        Actions: GaAction<Subject = Subject>,
+    {
+        #[cfg(test)]
+        {
+            simple_ga_internal_lib::tracing::init_tracing();
+        }
+        self.run_generations(ga_options, state)
+    }
+
+    /// Continues a run from a previously saved [`GaIterState`] (e.g. one
+    /// restored via [`crate::ga::checkpoint::Checkpoint::into_state`])
+    /// instead of starting fresh from a [`Population`], carrying over its
+    /// generation counter, reverse-mode flag, and current fitness so a
+    /// crashed long-running job doesn't have to start over.
+    #[cfg(feature = "parallel")]
+    pub fn resume<Actions>(
+        &mut self,
+        ga_options: GeneticAlgorithmOptions<Actions>,
+        state: GaIterState<Subject>,
+    ) -> GaRunResult<Subject>
+    where
+        Actions: GaAction<Subject = Subject> + Send,
+        Subject: Send + Sync,
+    {
+        #[cfg(test)]
+        {
+            simple_ga_internal_lib::tracing::init_tracing();
+        }
+        match self
+            .runner_options
+            .thread_pool
+            .clone()
+            .or_else(|| seeded_thread_pool(ga_options.seed))
+        {
+            Some(thread_pool) => thread_pool.install(|| self.run_generations(ga_options, state)),
+            None => self.run_generations(ga_options, state),
+        }
     }
 }
 
+/// Builds the `GaContext` a fresh [`GaRunner::run`] starts from. `None` seeds
+/// leave it OS-seeded, matching the pre-[`GeneticAlgorithmOptions::seed`]
+/// behavior. `Some(seed)` seeds it directly, then derives a second seed from
+/// its RNG stream to also seed `crate::util::rng::thread_rng()` on this
+/// thread — rather than reusing `seed` for both, which would make the two
+/// generators produce identical sequences.
+fn seeded_context(seed: Option<u64>) -> GaContext {
+    match seed {
+        Some(seed) => {
+            let context = GaContext::with_seed(0, seed);
+            let derived_seed: u64 = context.rng().gen();
+            crate::util::rng::seed_thread_rng(derived_seed);
+            context
+        }
+        None => GaContext::default(),
+    }
+}
+
+/// When `seed` is set and [`GaRunnerOptions::thread_pool`] wasn't already
+/// supplied, builds a dedicated rayon pool whose `start_handler` seeds each
+/// worker's `crate::util::rng::thread_rng()` with a sub-seed derived from
+/// `seed` and that worker's index, so a `parallel` run's draws through that
+/// free function become reproducible across runs with the same seed and
+/// worker count. Rayon's work-stealing still assigns population items to
+/// workers non-deterministically, so this makes each worker's own stream
+/// reproducible, not which subject ends up drawing from which stream.
+///
+/// This only reaches the call sites still backed by `thread_rng()` directly:
+/// [`crate::util::coin_flip`]/[`crate::util::random_index_bias`] and the
+/// free-standing genome operators under `crate::ga::genome` (e.g.
+/// [`crate::ga::genome::real_vector`]'s mutation functions,
+/// [`crate::ga::genome::permutation::order_crossover`]). It does nothing for
+/// [`crate::ga::select`] or the generic operators in
+/// `crate::ga::mutation::operators`/`crate::ga::reproduction::operators`,
+/// which all draw from the single shared [`GaContext::rng`] instead — that
+/// path isn't reproducible under `parallel` regardless of this seeding; see
+/// the note on [`GaContext`].
+///
+/// Returns `None` for an unseeded run, leaving the caller to fall back to
+/// the global rayon pool as before.
+#[cfg(feature = "parallel")]
+fn seeded_thread_pool(seed: Option<u64>) -> Option<Arc<ThreadPool>> {
+    let seed = seed?;
+    rayon::ThreadPoolBuilder::new()
+        .start_handler(move |index| {
+            crate::util::rng::seed_thread_rng(worker_stream_seed(seed, index));
+        })
+        .build()
+        .ok()
+        .map(Arc::new)
+}
+
+/// Derives worker `index`'s sub-seed from the run's `seed` via a
+/// splitmix64-style xor/multiply mix, so each rayon worker gets a distinct,
+/// reproducible stream instead of every worker racing to reseed the same
+/// thread-local from `seed` directly.
+#[cfg(feature = "parallel")]
+fn worker_stream_seed(seed: u64, index: usize) -> u64 {
+    const GOLDEN_GAMMA: u64 = 0x9E3779B97F4A7C15;
+    seed ^ (index as u64).wrapping_mul(GOLDEN_GAMMA)
+}
+
+#[cfg(not(feature = "parallel"))]
 pub fn ga_runner<
     Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq,
     Actions: GaAction<Subject = Subject>,
@@ -87,6 +764,18 @@ pub fn ga_runner<
     ga_options: GeneticAlgorithmOptions<Actions>,
     runner_options: GaRunnerOptions<Subject>,
     population: Population<Subject>,
-) {
-    GaRunner::new(runner_options).run(ga_options, population);
+) -> GaRunResult<Subject> {
+    GaRunner::new(runner_options).run(ga_options, population)
+}
+
+#[cfg(feature = "parallel")]
+pub fn ga_runner<
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq + Send + Sync,
+    Actions: GaAction<Subject = Subject> + Send,
+>(
+    ga_options: GeneticAlgorithmOptions<Actions>,
+    runner_options: GaRunnerOptions<Subject>,
+    population: Population<Subject>,
+) -> GaRunResult<Subject> {
+    GaRunner::new(runner_options).run(ga_options, population)
 }
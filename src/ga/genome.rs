@@ -0,0 +1,131 @@
+//! Copy-on-write gene storage for crate-provided genome types. Cloning a
+//! [`CowGenome`] (e.g. for `clone_on_mutation` or crossover) only bumps an
+//! `Arc` refcount; the backing `Vec` is copied lazily, and only once, the
+//! first time a clone is actually mutated while shared.
+
+use std::fmt;
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, Index};
+use std::sync::Arc;
+
+pub mod bitstring;
+pub mod linear_gp;
+pub mod neat;
+pub mod permutation;
+pub mod real_vector;
+pub mod self_adaptive;
+pub mod tree;
+
+#[derive(Clone)]
+pub struct CowGenome<Gene> {
+    genes: Arc<Vec<Gene>>,
+}
+
+impl<Gene> CowGenome<Gene> {
+    pub fn new(genes: Vec<Gene>) -> Self {
+        Self {
+            genes: Arc::new(genes),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.genes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.genes.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[Gene] {
+        &self.genes
+    }
+}
+
+impl<Gene: Clone> CowGenome<Gene> {
+    /// Overwrites the gene at `index`, cloning the backing storage only if
+    /// it is currently shared with another `CowGenome`.
+    pub fn set(&mut self, index: usize, gene: Gene) {
+        Arc::make_mut(&mut self.genes)[index] = gene;
+    }
+
+    /// Mutably borrows the backing storage, cloning it only if it is
+    /// currently shared with another `CowGenome`.
+    pub fn make_mut(&mut self) -> &mut Vec<Gene> {
+        Arc::make_mut(&mut self.genes)
+    }
+}
+
+impl<Gene> Deref for CowGenome<Gene> {
+    type Target = [Gene];
+    fn deref(&self) -> &Self::Target {
+        &self.genes
+    }
+}
+
+impl<Gene> Index<usize> for CowGenome<Gene> {
+    type Output = Gene;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.genes[index]
+    }
+}
+
+impl<Gene: Clone> From<Vec<Gene>> for CowGenome<Gene> {
+    fn from(genes: Vec<Gene>) -> Self {
+        Self::new(genes)
+    }
+}
+
+impl<Gene: Debug> fmt::Debug for CowGenome<Gene> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CowGenome").field(&self.genes).finish()
+    }
+}
+
+impl<Gene: PartialEq> PartialEq for CowGenome<Gene> {
+    fn eq(&self, other: &Self) -> bool {
+        self.genes == other.genes
+    }
+}
+
+impl<Gene: Eq> Eq for CowGenome<Gene> {}
+
+impl<Gene: Hash> Hash for CowGenome<Gene> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.genes.hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::ga::genome::CowGenome;
+
+    #[test]
+    fn test_clone_is_shallow() {
+        let genome = CowGenome::new(vec![1, 2, 3]);
+        let clone = genome.clone();
+        assert_eq!(genome, clone);
+        assert!(Arc::ptr_eq(&genome.genes, &clone.genes));
+    }
+
+    #[test]
+    fn test_set_copies_only_when_shared() {
+        let genome = CowGenome::new(vec![1, 2, 3]);
+        let mut clone = genome.clone();
+        clone.set(0, 9);
+        assert_eq!(genome.as_slice(), &[1, 2, 3]);
+        assert_eq!(clone.as_slice(), &[9, 2, 3]);
+        assert!(!Arc::ptr_eq(&genome.genes, &clone.genes));
+    }
+
+    #[test]
+    fn test_set_on_uniquely_owned_genome_does_not_reallocate() {
+        let mut genome = CowGenome::new(vec![1, 2, 3]);
+        let before = Arc::as_ptr(&genome.genes);
+        genome.set(1, 9);
+        assert_eq!(Arc::as_ptr(&genome.genes), before);
+        assert_eq!(genome.as_slice(), &[1, 9, 3]);
+    }
+}
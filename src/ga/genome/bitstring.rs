@@ -0,0 +1,220 @@
+//! A packed bitstring genome (`BitGenome`) with crate-provided crossover and
+//! mutation operators, so binary-encoded problems (knapsack, feature
+//! selection, ...) work end-to-end without hand-rolled genome code.
+
+use rand::Rng;
+
+use crate::ga::subject::GaSubject;
+use crate::util::rng;
+use crate::util::{coin_flip, Odds};
+
+fn word_count(len: usize) -> usize {
+    len.div_ceil(64)
+}
+
+fn last_word_mask(len: usize) -> u64 {
+    let rem = len % 64;
+    if rem == 0 {
+        u64::MAX
+    } else {
+        (1u64 << rem) - 1
+    }
+}
+
+/// A fixed-length bitstring packed into `u64` words.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BitGenome {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitGenome {
+    pub fn zeros(len: usize) -> Self {
+        Self { words: vec![0; word_count(len)], len }
+    }
+
+    pub fn from_bits(bits: &[bool]) -> Self {
+        let mut genome = Self::zeros(bits.len());
+        for (index, &bit) in bits.iter().enumerate() {
+            genome.set(index, bit);
+        }
+        genome
+    }
+
+    pub fn random(len: usize) -> Self {
+        let mut rand = rng::thread_rng();
+        let mut words: Vec<u64> = (0..word_count(len)).map(|_| rand.gen()).collect();
+        if let Some(last) = words.last_mut() {
+            *last &= last_word_mask(len);
+        }
+        Self { words, len }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "bit index out of bounds");
+        (self.words[index / 64] >> (index % 64)) & 1 == 1
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "bit index out of bounds");
+        let mask = 1u64 << (index % 64);
+        if value {
+            self.words[index / 64] |= mask;
+        } else {
+            self.words[index / 64] &= !mask;
+        }
+    }
+
+    pub fn flip(&mut self, index: usize) {
+        self.set(index, !self.get(index));
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Number of bit positions that differ between `self` and `other`.
+    /// Panics if the two genomes have different lengths.
+    pub fn hamming_distance(&self, other: &Self) -> u32 {
+        assert_eq!(self.len, other.len, "hamming_distance requires equal-length genomes");
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+}
+
+impl GaSubject for BitGenome {}
+
+/// Splits both genomes at a single random bit position and swaps the tails,
+/// the classic single-point crossover. Panics if the two genomes have
+/// different lengths.
+pub fn single_point_crossover(a: &BitGenome, b: &BitGenome) -> (BitGenome, BitGenome) {
+    assert_eq!(a.len, b.len, "single_point_crossover requires equal-length genomes");
+    let len = a.len;
+    let point = rng::thread_rng().gen_range(0..=len);
+    let mut child_a = BitGenome::zeros(len);
+    let mut child_b = BitGenome::zeros(len);
+    for index in 0..len {
+        if index < point {
+            child_a.set(index, a.get(index));
+            child_b.set(index, b.get(index));
+        } else {
+            child_a.set(index, b.get(index));
+            child_b.set(index, a.get(index));
+        }
+    }
+    (child_a, child_b)
+}
+
+/// Swaps each bit independently with 50/50 odds. Panics if the two genomes
+/// have different lengths.
+pub fn uniform_crossover(a: &BitGenome, b: &BitGenome) -> (BitGenome, BitGenome) {
+    assert_eq!(a.len, b.len, "uniform_crossover requires equal-length genomes");
+    let len = a.len;
+    let mut child_a = BitGenome::zeros(len);
+    let mut child_b = BitGenome::zeros(len);
+    for index in 0..len {
+        if coin_flip(0.5) {
+            child_a.set(index, a.get(index));
+            child_b.set(index, b.get(index));
+        } else {
+            child_a.set(index, b.get(index));
+            child_b.set(index, a.get(index));
+        }
+    }
+    (child_a, child_b)
+}
+
+/// Flips each bit independently with probability `rate`.
+pub fn bit_flip_mutation(genome: &BitGenome, rate: Odds) -> BitGenome {
+    let mut mutated = genome.clone();
+    for index in 0..mutated.len {
+        if coin_flip(rate) {
+            mutated.flip(index);
+        }
+    }
+    mutated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_get_round_trip() {
+        let mut genome = BitGenome::zeros(8);
+        genome.set(3, true);
+        assert!(genome.get(3));
+        assert!(!genome.get(2));
+    }
+
+    #[test]
+    fn test_from_bits_matches_source() {
+        let bits = [true, false, true, true];
+        let genome = BitGenome::from_bits(&bits);
+        for (index, &bit) in bits.iter().enumerate() {
+            assert_eq!(genome.get(index), bit);
+        }
+    }
+
+    #[test]
+    fn test_hamming_distance_counts_differing_bits() {
+        let a = BitGenome::from_bits(&[true, false, true, false]);
+        let b = BitGenome::from_bits(&[true, true, false, false]);
+        assert_eq!(a.hamming_distance(&b), 2);
+    }
+
+    #[test]
+    fn test_count_ones() {
+        let genome = BitGenome::from_bits(&[true, false, true, true]);
+        assert_eq!(genome.count_ones(), 3);
+    }
+
+    #[test]
+    fn test_single_point_crossover_preserves_length() {
+        let a = BitGenome::from_bits(&[true, true, true, true]);
+        let b = BitGenome::from_bits(&[false, false, false, false]);
+        let (child_a, child_b) = single_point_crossover(&a, &b);
+        assert_eq!(child_a.len(), 4);
+        assert_eq!(child_b.len(), 4);
+    }
+
+    #[test]
+    fn test_uniform_crossover_children_are_complementary_bitwise() {
+        let a = BitGenome::from_bits(&[true, true, true, true]);
+        let b = BitGenome::from_bits(&[false, false, false, false]);
+        let (child_a, child_b) = uniform_crossover(&a, &b);
+        for index in 0..4 {
+            assert_ne!(child_a.get(index), child_b.get(index));
+        }
+    }
+
+    #[test]
+    fn test_bit_flip_mutation_at_full_rate_flips_every_bit() {
+        let genome = BitGenome::from_bits(&[true, false, true, false]);
+        let mutated = bit_flip_mutation(&genome, 1.0);
+        for index in 0..4 {
+            assert_ne!(mutated.get(index), genome.get(index));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let genome = BitGenome::random(100);
+        let json = serde_json::to_string(&genome).unwrap();
+        let round_tripped: BitGenome = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, genome);
+    }
+}
@@ -0,0 +1,252 @@
+//! A linear genetic programming genome: a flat sequence of register-machine
+//! instructions, interpreted by running each instruction in order and
+//! writing its result into a destination register. Lighter-weight than
+//! [`crate::ga::genome::tree`] since there's no tree structure to maintain —
+//! crossover and mutation are just slice operations.
+
+use std::fmt::Debug;
+
+use rand::Rng;
+
+use crate::ga::subject::GaSubject;
+use crate::util::rng;
+
+/// A single operation a [`LinearGpGenome`] instruction can perform. Mirrors
+/// [`crate::ga::genome::tree::TreeGene`]'s bound set: `Send + Sync` is
+/// required unconditionally (rather than only under the `parallel` feature)
+/// so `LinearGpGenome<Op>` can implement `GaSubject` the same way regardless
+/// of which features are enabled.
+pub trait LinearGpOp: Clone + Debug + PartialEq + Send + Sync {
+    /// Applies this operation to two input values, returning the result to
+    /// store in the instruction's destination register.
+    fn apply(&self, a: f64, b: f64) -> f64;
+}
+
+/// `dest = op(registers[src_a], registers[src_b])`. Register indices are
+/// taken modulo the genome's register count when executed, so a genome is
+/// always executable regardless of how crossover/mutation shuffled indices.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instruction<Op> {
+    pub op: Op,
+    pub dest: usize,
+    pub src_a: usize,
+    pub src_b: usize,
+}
+
+/// A fixed-size register file plus a flat instruction sequence.
+/// `registers[0..num_inputs]` are seeded with the program's inputs before
+/// [`Self::eval`] runs; the remaining registers start at `0.0`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LinearGpGenome<Op> {
+    pub instructions: Vec<Instruction<Op>>,
+    pub num_registers: usize,
+}
+
+impl<Op: LinearGpOp> LinearGpGenome<Op> {
+    pub fn new(instructions: Vec<Instruction<Op>>, num_registers: usize) -> Self {
+        assert!(num_registers > 0, "a genome needs at least one register");
+        Self { instructions, num_registers }
+    }
+
+    /// Runs every instruction in order over a register file seeded with
+    /// `inputs` (registers beyond `inputs.len()` start at `0.0`), returning
+    /// the final register file. The value(s) the caller treats as "the
+    /// program's output" are whichever registers their fitness function
+    /// reads back out of it.
+    pub fn eval(&self, inputs: &[f64]) -> Vec<f64> {
+        let mut registers = vec![0.0; self.num_registers];
+        for (register, &input) in registers.iter_mut().zip(inputs.iter()) {
+            *register = input;
+        }
+        for instruction in &self.instructions {
+            let a = registers[instruction.src_a % self.num_registers];
+            let b = registers[instruction.src_b % self.num_registers];
+            let dest = instruction.dest % self.num_registers;
+            registers[dest] = instruction.op.apply(a, b);
+        }
+        registers
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+}
+
+impl<Op: LinearGpOp> GaSubject for LinearGpGenome<Op> {}
+
+/// Swaps a random contiguous slice of instructions between the two parents,
+/// at independently-chosen cut points, producing two children.
+pub fn two_point_crossover<Op: LinearGpOp>(
+    a: &LinearGpGenome<Op>,
+    b: &LinearGpGenome<Op>,
+) -> (LinearGpGenome<Op>, LinearGpGenome<Op>) {
+    let mut rand = rng::thread_rng();
+    fn cut_points(len: usize, rand: &mut impl Rng) -> (usize, usize) {
+        if len == 0 {
+            return (0, 0);
+        }
+        let mut bounds = [rand.gen_range(0..=len), rand.gen_range(0..=len)];
+        bounds.sort_unstable();
+        (bounds[0], bounds[1])
+    }
+    let (a_start, a_end) = cut_points(a.instructions.len(), &mut rand);
+    let (b_start, b_end) = cut_points(b.instructions.len(), &mut rand);
+
+    let mut child_a = a.instructions[..a_start].to_vec();
+    child_a.extend_from_slice(&b.instructions[b_start..b_end]);
+    child_a.extend_from_slice(&a.instructions[a_end..]);
+
+    let mut child_b = b.instructions[..b_start].to_vec();
+    child_b.extend_from_slice(&a.instructions[a_start..a_end]);
+    child_b.extend_from_slice(&b.instructions[b_end..]);
+
+    (
+        LinearGpGenome::new(child_a, a.num_registers),
+        LinearGpGenome::new(child_b, b.num_registers),
+    )
+}
+
+/// One-point crossover: a single shared cut point splits both parents into a
+/// head and tail, which are swapped to produce two children.
+pub fn one_point_crossover<Op: LinearGpOp>(
+    a: &LinearGpGenome<Op>,
+    b: &LinearGpGenome<Op>,
+) -> (LinearGpGenome<Op>, LinearGpGenome<Op>) {
+    let mut rand = rng::thread_rng();
+    let cut = rand.gen_range(0..=a.instructions.len().min(b.instructions.len()));
+    let mut child_a = a.instructions[..cut].to_vec();
+    child_a.extend_from_slice(&b.instructions[cut..]);
+    let mut child_b = b.instructions[..cut].to_vec();
+    child_b.extend_from_slice(&a.instructions[cut..]);
+    (
+        LinearGpGenome::new(child_a, a.num_registers),
+        LinearGpGenome::new(child_b, b.num_registers),
+    )
+}
+
+/// Micro-mutation: replaces one instruction's operand (chosen uniformly
+/// among op/dest/src_a/src_b) with a newly generated one, leaving the
+/// program's length unchanged. `new_op`/`new_register` draw replacements the
+/// same way the caller builds random instructions in the first place.
+pub fn micro_mutation<Op: LinearGpOp>(
+    genome: &LinearGpGenome<Op>,
+    new_op: impl Fn() -> Op,
+    new_register: impl Fn() -> usize,
+) -> LinearGpGenome<Op> {
+    let mut mutated = genome.clone();
+    if mutated.instructions.is_empty() {
+        return mutated;
+    }
+    let mut rand = rng::thread_rng();
+    let index = rand.gen_range(0..mutated.instructions.len());
+    match rand.gen_range(0..4) {
+        0 => mutated.instructions[index].op = new_op(),
+        1 => mutated.instructions[index].dest = new_register(),
+        2 => mutated.instructions[index].src_a = new_register(),
+        _ => mutated.instructions[index].src_b = new_register(),
+    }
+    mutated
+}
+
+/// Macro-mutation: with equal probability, inserts a newly generated
+/// instruction at a random position or deletes a random existing one,
+/// changing the program's length by one.
+pub fn macro_mutation<Op: LinearGpOp>(
+    genome: &LinearGpGenome<Op>,
+    new_instruction: impl FnOnce() -> Instruction<Op>,
+) -> LinearGpGenome<Op> {
+    let mut mutated = genome.clone();
+    let mut rand = rng::thread_rng();
+    if mutated.instructions.is_empty() || rand.gen_bool(0.5) {
+        let index = rand.gen_range(0..=mutated.instructions.len());
+        mutated.instructions.insert(index, new_instruction());
+    } else {
+        let index = rand.gen_range(0..mutated.instructions.len());
+        mutated.instructions.remove(index);
+    }
+    mutated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Op {
+        Add,
+        Mul,
+    }
+
+    impl LinearGpOp for Op {
+        fn apply(&self, a: f64, b: f64) -> f64 {
+            match self {
+                Op::Add => a + b,
+                Op::Mul => a * b,
+            }
+        }
+    }
+
+    fn instruction(op: Op, dest: usize, src_a: usize, src_b: usize) -> Instruction<Op> {
+        Instruction { op, dest, src_a, src_b }
+    }
+
+    #[test]
+    fn test_eval_seeds_inputs_and_runs_in_order() {
+        let genome = LinearGpGenome::new(
+            vec![instruction(Op::Add, 2, 0, 1), instruction(Op::Mul, 2, 2, 2)],
+            3,
+        );
+        let registers = genome.eval(&[3.0, 4.0]);
+        assert_eq!(registers[2], 49.0); // (3 + 4) ^ 2
+    }
+
+    #[test]
+    fn test_register_indexes_wrap_modulo_register_count() {
+        let genome = LinearGpGenome::new(vec![instruction(Op::Add, 5, 5, 5)], 2);
+        let registers = genome.eval(&[0.0, 1.0]);
+        assert_eq!(registers.len(), 2);
+        assert_eq!(registers[1], 2.0); // dest/src wrap to register 1 (5 % 2)
+    }
+
+    #[test]
+    fn test_two_point_crossover_preserves_register_count() {
+        let a = LinearGpGenome::new(vec![instruction(Op::Add, 0, 0, 0); 4], 3);
+        let b = LinearGpGenome::new(vec![instruction(Op::Mul, 1, 1, 1); 4], 3);
+        let (child_a, child_b) = two_point_crossover(&a, &b);
+        assert_eq!(child_a.num_registers, 3);
+        assert_eq!(child_b.num_registers, 3);
+    }
+
+    #[test]
+    fn test_one_point_crossover_splits_at_shared_cut() {
+        let a = LinearGpGenome::new(vec![instruction(Op::Add, 0, 0, 0); 4], 2);
+        let b = LinearGpGenome::new(vec![instruction(Op::Mul, 1, 1, 1); 4], 2);
+        let (child_a, _) = one_point_crossover(&a, &b);
+        assert_eq!(child_a.len(), 4);
+    }
+
+    #[test]
+    fn test_micro_mutation_preserves_length() {
+        let genome = LinearGpGenome::new(vec![instruction(Op::Add, 0, 0, 0)], 2);
+        let mutated = micro_mutation(&genome, || Op::Mul, || 1);
+        assert_eq!(mutated.len(), genome.len());
+    }
+
+    #[test]
+    fn test_macro_mutation_changes_length_by_one() {
+        let genome = LinearGpGenome::new(vec![instruction(Op::Add, 0, 0, 0); 3], 2);
+        let mutated = macro_mutation(&genome, || instruction(Op::Mul, 0, 0, 0));
+        assert_eq!((mutated.len() as i64 - genome.len() as i64).abs(), 1);
+    }
+
+    #[test]
+    fn test_macro_mutation_on_empty_genome_inserts() {
+        let genome: LinearGpGenome<Op> = LinearGpGenome::new(vec![], 2);
+        let mutated = macro_mutation(&genome, || instruction(Op::Add, 0, 0, 0));
+        assert_eq!(mutated.len(), 1);
+    }
+}
@@ -0,0 +1,328 @@
+//! A NEAT-style (Stanley & Miikkulainen) neuroevolution genome: nodes and
+//! connections tagged with historical "innovation numbers" so structurally
+//! equivalent mutations that arise independently in different genomes can
+//! still be recognized as the same gene during crossover and compatibility
+//! comparison.
+
+use std::collections::HashMap;
+
+use rand::Rng;
+
+use crate::ga::fitness::Fitness;
+use crate::ga::subject::GaSubject;
+use crate::util::{coin_flip, rng, Odds};
+
+pub type InnovationNumber = u64;
+pub type NodeId = usize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    Input,
+    Output,
+    Hidden,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeGene {
+    pub id: NodeId,
+    pub kind: NodeKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConnectionGene {
+    pub innovation: InnovationNumber,
+    pub from: NodeId,
+    pub to: NodeId,
+    pub weight: f64,
+    pub enabled: bool,
+}
+
+/// A network topology: nodes plus the connections between them. `GaSubject`
+/// is implemented directly; the network itself is evaluated by user code
+/// (this crate doesn't ship a forward-pass interpreter).
+#[derive(Clone, Debug, PartialEq)]
+pub struct NeatGenome {
+    pub nodes: Vec<NodeGene>,
+    pub connections: Vec<ConnectionGene>,
+}
+
+impl NeatGenome {
+    /// A minimal fully-connected genome: every input wired directly to every
+    /// output, no hidden nodes.
+    pub fn minimal(inputs: usize, outputs: usize, tracker: &mut InnovationTracker) -> Self {
+        let nodes: Vec<NodeGene> = (0..inputs)
+            .map(|id| NodeGene { id, kind: NodeKind::Input })
+            .chain((inputs..inputs + outputs).map(|id| NodeGene { id, kind: NodeKind::Output }))
+            .collect();
+        let mut rand = rng::thread_rng();
+        let connections = (0..inputs)
+            .flat_map(|from| (inputs..inputs + outputs).map(move |to| (from, to)))
+            .map(|(from, to)| ConnectionGene {
+                innovation: tracker.connection_innovation(from, to),
+                from,
+                to,
+                weight: rand.gen_range(-1.0..1.0),
+                enabled: true,
+            })
+            .collect();
+        Self { nodes, connections }
+    }
+
+    pub fn connection(&self, innovation: InnovationNumber) -> Option<&ConnectionGene> {
+        self.connections.iter().find(|connection| connection.innovation == innovation)
+    }
+}
+
+impl GaSubject for NeatGenome {}
+
+/// Hands out globally-unique innovation numbers and node ids, reusing the
+/// same innovation number for a `(from, to)` connection that has already
+/// appeared this run (the "historical marking" NEAT crossover relies on to
+/// recognize matching genes across genomes).
+#[derive(Debug, Default)]
+pub struct InnovationTracker {
+    next_innovation: InnovationNumber,
+    next_node_id: NodeId,
+    seen_connections: HashMap<(NodeId, NodeId), InnovationNumber>,
+}
+
+impl InnovationTracker {
+    pub fn new(next_node_id: NodeId) -> Self {
+        Self { next_innovation: 0, next_node_id, seen_connections: HashMap::new() }
+    }
+
+    pub fn connection_innovation(&mut self, from: NodeId, to: NodeId) -> InnovationNumber {
+        if let Some(&innovation) = self.seen_connections.get(&(from, to)) {
+            return innovation;
+        }
+        let innovation = self.next_innovation;
+        self.next_innovation += 1;
+        self.seen_connections.insert((from, to), innovation);
+        innovation
+    }
+
+    pub fn next_node_id(&mut self) -> NodeId {
+        let id = self.next_node_id;
+        self.next_node_id += 1;
+        id
+    }
+}
+
+/// NEAT crossover: genes with a matching innovation number are inherited
+/// randomly from either parent; disjoint/excess genes are inherited from the
+/// fitter parent only (lower fitness wins; this function always minimizes,
+/// whatever the run's reverse mode). On a fitness tie, disjoint/excess
+/// genes are inherited from `a`.
+pub fn crossover(a: &NeatGenome, fitness_a: Fitness, b: &NeatGenome, fitness_b: Fitness) -> NeatGenome {
+    let (fitter, other) = if fitness_a <= fitness_b { (a, b) } else { (b, a) };
+    let mut rand = rng::thread_rng();
+    let mut connections = Vec::new();
+    for fitter_connection in &fitter.connections {
+        let matching = other.connection(fitter_connection.innovation);
+        let inherited = match matching {
+            Some(other_connection) if rand.gen_bool(0.5) => other_connection.clone(),
+            _ => fitter_connection.clone(),
+        };
+        connections.push(inherited);
+    }
+    let mut nodes = fitter.nodes.clone();
+    for node in &other.nodes {
+        if !nodes.iter().any(|existing| existing.id == node.id) {
+            nodes.push(node.clone());
+        }
+    }
+    NeatGenome { nodes, connections }
+}
+
+/// Adds a new connection between two previously unconnected nodes, if one
+/// exists to add. Returns `None` (leaving `genome` unchanged) if every pair
+/// is already connected.
+pub fn mutate_add_connection(genome: &NeatGenome, tracker: &mut InnovationTracker) -> Option<NeatGenome> {
+    let mut rand = rng::thread_rng();
+    let candidates: Vec<(NodeId, NodeId)> = genome
+        .nodes
+        .iter()
+        .flat_map(|from| {
+            genome.nodes.iter().filter_map(move |to| {
+                let valid = from.id != to.id && to.kind != NodeKind::Input;
+                valid.then_some((from.id, to.id))
+            })
+        })
+        .filter(|(from, to)| !genome.connections.iter().any(|c| c.from == *from && c.to == *to))
+        .collect();
+    if candidates.is_empty() {
+        return None;
+    }
+    let &(from, to) = &candidates[rand.gen_range(0..candidates.len())];
+    let mut mutated = genome.clone();
+    mutated.connections.push(ConnectionGene {
+        innovation: tracker.connection_innovation(from, to),
+        from,
+        to,
+        weight: rand.gen_range(-1.0..1.0),
+        enabled: true,
+    });
+    Some(mutated)
+}
+
+/// Splits a randomly chosen enabled connection into two: the original is
+/// disabled, a new hidden node is inserted in the middle, and two new
+/// connections (`from -> new`, weight `1.0`; `new -> to`, the original
+/// weight) replace it. Returns `None` if there's no enabled connection to
+/// split.
+pub fn mutate_add_node(genome: &NeatGenome, tracker: &mut InnovationTracker) -> Option<NeatGenome> {
+    let enabled_indexes: Vec<usize> = genome
+        .connections
+        .iter()
+        .enumerate()
+        .filter(|(_, connection)| connection.enabled)
+        .map(|(index, _)| index)
+        .collect();
+    if enabled_indexes.is_empty() {
+        return None;
+    }
+    let mut rand = rng::thread_rng();
+    let split_index = enabled_indexes[rand.gen_range(0..enabled_indexes.len())];
+    let mut mutated = genome.clone();
+    let split = mutated.connections[split_index].clone();
+    mutated.connections[split_index].enabled = false;
+    let new_node_id = tracker.next_node_id();
+    mutated.nodes.push(NodeGene { id: new_node_id, kind: NodeKind::Hidden });
+    mutated.connections.push(ConnectionGene {
+        innovation: tracker.connection_innovation(split.from, new_node_id),
+        from: split.from,
+        to: new_node_id,
+        weight: 1.0,
+        enabled: true,
+    });
+    mutated.connections.push(ConnectionGene {
+        innovation: tracker.connection_innovation(new_node_id, split.to),
+        from: new_node_id,
+        to: split.to,
+        weight: split.weight,
+        enabled: true,
+    });
+    Some(mutated)
+}
+
+/// Perturbs each connection's weight independently with probability `rate`
+/// by adding Gaussian noise with standard deviation `std_dev`.
+pub fn mutate_weights(genome: &NeatGenome, rate: Odds, std_dev: f64) -> NeatGenome {
+    use rand_distr::{Distribution, Normal};
+    let mut mutated = genome.clone();
+    let normal = Normal::new(0.0, std_dev).expect("std_dev must be finite and non-negative");
+    let mut rand = rng::thread_rng();
+    for connection in mutated.connections.iter_mut() {
+        if coin_flip(rate) {
+            connection.weight += normal.sample(&mut rand);
+        }
+    }
+    mutated
+}
+
+/// The NEAT compatibility distance: `c1 * E / N + c2 * D / N + c3 * W̄`,
+/// where `E`/`D` are excess/disjoint connection gene counts, `N` is the
+/// larger genome's connection count (or `1` if both genomes are smaller than
+/// 20 connections, per the original paper), and `W̄` is the average weight
+/// difference between matching genes.
+pub fn compatibility_distance(a: &NeatGenome, b: &NeatGenome, c1: f64, c2: f64, c3: f64) -> f64 {
+    let max_innovation_a = a.connections.iter().map(|c| c.innovation).max().unwrap_or(0);
+    let max_innovation_b = b.connections.iter().map(|c| c.innovation).max().unwrap_or(0);
+    let (lower_max_genome, higher_max_genome) = if max_innovation_a <= max_innovation_b { (a, b) } else { (b, a) };
+
+    let mut matching_weight_diff_total = 0.0;
+    let mut matching_count = 0u32;
+    let mut disjoint = 0u32;
+    let mut excess = 0u32;
+    let lower_max_innovation = max_innovation_a.min(max_innovation_b);
+
+    for connection in &lower_max_genome.connections {
+        match higher_max_genome.connection(connection.innovation) {
+            Some(other) => {
+                matching_weight_diff_total += (connection.weight - other.weight).abs();
+                matching_count += 1;
+            }
+            None => disjoint += 1,
+        }
+    }
+    for connection in &higher_max_genome.connections {
+        if lower_max_genome.connection(connection.innovation).is_some() {
+            continue;
+        }
+        if connection.innovation > lower_max_innovation {
+            excess += 1;
+        } else {
+            disjoint += 1;
+        }
+    }
+
+    let n = a.connections.len().max(b.connections.len()).max(1) as f64;
+    let n = if n < 20.0 { 1.0 } else { n };
+    let average_weight_diff = if matching_count > 0 { matching_weight_diff_total / matching_count as f64 } else { 0.0 };
+    c1 * excess as f64 / n + c2 * disjoint as f64 / n + c3 * average_weight_diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimal_genome_is_fully_connected() {
+        let mut tracker = InnovationTracker::new(0);
+        let genome = NeatGenome::minimal(2, 1, &mut tracker);
+        assert_eq!(genome.nodes.len(), 3);
+        assert_eq!(genome.connections.len(), 2);
+    }
+
+    #[test]
+    fn test_reused_connection_gets_the_same_innovation_number() {
+        let mut tracker = InnovationTracker::new(0);
+        let first = tracker.connection_innovation(0, 1);
+        let second = tracker.connection_innovation(0, 1);
+        assert_eq!(first, second);
+        let third = tracker.connection_innovation(1, 0);
+        assert_ne!(first, third);
+    }
+
+    #[test]
+    fn test_mutate_add_node_disables_the_split_connection() {
+        let mut tracker = InnovationTracker::new(0);
+        let genome = NeatGenome::minimal(1, 1, &mut tracker);
+        let mutated = mutate_add_node(&genome, &mut tracker).unwrap();
+        assert!(!mutated.connections[0].enabled);
+        assert_eq!(mutated.nodes.len(), 3);
+        assert_eq!(mutated.connections.len(), 3);
+    }
+
+    #[test]
+    fn test_mutate_add_connection_returns_none_when_fully_connected() {
+        let mut tracker = InnovationTracker::new(0);
+        let genome = NeatGenome::minimal(1, 1, &mut tracker);
+        assert!(mutate_add_connection(&genome, &mut tracker).is_none());
+    }
+
+    #[test]
+    fn test_compatibility_distance_is_zero_for_identical_genomes() {
+        let mut tracker = InnovationTracker::new(0);
+        let genome = NeatGenome::minimal(2, 2, &mut tracker);
+        assert_eq!(compatibility_distance(&genome, &genome, 1.0, 1.0, 0.4), 0.0);
+    }
+
+    #[test]
+    fn test_compatibility_distance_grows_with_disjoint_genes() {
+        let mut tracker = InnovationTracker::new(0);
+        let a = NeatGenome::minimal(1, 1, &mut tracker);
+        let mutated = mutate_add_node(&a, &mut tracker).unwrap();
+        assert!(compatibility_distance(&a, &mutated, 1.0, 1.0, 0.4) > 0.0);
+    }
+
+    #[test]
+    fn test_crossover_inherits_matching_genes_from_either_parent() {
+        let mut tracker = InnovationTracker::new(0);
+        let a = NeatGenome::minimal(1, 1, &mut tracker);
+        let mut b = a.clone();
+        b.connections[0].weight = 99.0;
+        let child = crossover(&a, 0.0, &b, 1.0);
+        assert_eq!(child.connections.len(), 1);
+    }
+}
@@ -0,0 +1,268 @@
+//! An index-permutation genome (`PermutationGenome`) that upholds the
+//! "every index appears exactly once" invariant on construction, so
+//! permutation problems (TSP, scheduling, ...) don't need the
+//! static-lifetime `Vec<&'static City>` plumbing the TSP example resorts to
+//! just to keep a `Vec<usize>` around that looks up into a shared city list.
+
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::ga::subject::GaSubject;
+use crate::util::rng;
+
+/// A permutation of `0..len`. Every crate-provided constructor and operator
+/// in this module upholds "each index in `0..len` appears exactly once";
+/// [`Self::is_valid`] is there for callers building one by hand.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PermutationGenome {
+    indices: Vec<usize>,
+}
+
+impl PermutationGenome {
+    /// The identity permutation `[0, 1, ..., len - 1]`.
+    pub fn identity(len: usize) -> Self {
+        Self { indices: (0..len).collect() }
+    }
+
+    /// A uniformly random shuffle of `0..len`.
+    pub fn random(len: usize) -> Self {
+        let mut indices: Vec<usize> = (0..len).collect();
+        indices.shuffle(&mut rng::thread_rng());
+        Self { indices }
+    }
+
+    /// Builds a genome from an existing sequence. Panics if `indices` is not
+    /// a permutation of `0..indices.len()` — see [`Self::is_valid`].
+    pub fn new(indices: Vec<usize>) -> Self {
+        assert!(Self::is_valid(&indices), "indices must be a permutation of 0..len");
+        Self { indices }
+    }
+
+    /// `true` if `indices` contains every value in `0..indices.len()`
+    /// exactly once.
+    pub fn is_valid(indices: &[usize]) -> bool {
+        let len = indices.len();
+        let unique: HashSet<usize> = indices.iter().copied().collect();
+        unique.len() == len && indices.iter().all(|&index| index < len)
+    }
+
+    pub fn as_slice(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Number of positions at which two equal-length permutations disagree.
+    pub fn swap_distance(&self, other: &Self) -> usize {
+        assert_eq!(self.len(), other.len(), "swap_distance requires equal-length genomes");
+        self.indices
+            .iter()
+            .zip(other.indices.iter())
+            .filter(|(a, b)| a != b)
+            .count()
+    }
+
+    /// Number of pairs `(i, j)` with `i < j` whose relative order differs
+    /// between the two permutations; `0` means identical order, the maximum
+    /// (`len * (len - 1) / 2`) means fully reversed relative order.
+    pub fn inversion_distance(&self, other: &Self) -> usize {
+        assert_eq!(self.len(), other.len(), "inversion_distance requires equal-length genomes");
+        let position_in_other: Vec<usize> = {
+            let mut position = vec![0usize; other.len()];
+            for (rank, &value) in other.indices.iter().enumerate() {
+                position[value] = rank;
+            }
+            self.indices.iter().map(|&value| position[value]).collect()
+        };
+        let mut count = 0;
+        for i in 0..position_in_other.len() {
+            for j in (i + 1)..position_in_other.len() {
+                if position_in_other[i] > position_in_other[j] {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// A canonical form that is equal for any two permutations which encode
+    /// the same cyclic tour (same order up to rotation and direction), for
+    /// deduping tours where only the cycle matters, not the starting city or
+    /// traversal direction. Rotates so the smallest index comes first, then
+    /// picks whichever direction yields the lexicographically smaller
+    /// sequence.
+    pub fn canonical_cycle(&self) -> Vec<usize> {
+        let len = self.indices.len();
+        if len == 0 {
+            return vec![];
+        }
+        let min_pos = self
+            .indices
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, &value)| value)
+            .map(|(pos, _)| pos)
+            .unwrap_or(0);
+        let forward: Vec<usize> = (0..len).map(|offset| self.indices[(min_pos + offset) % len]).collect();
+        let backward: Vec<usize> = (0..len)
+            .map(|offset| self.indices[(min_pos + len - offset) % len])
+            .collect();
+        forward.min(backward)
+    }
+}
+
+impl GaSubject for PermutationGenome {}
+
+/// Order crossover (OX): copies a random contiguous slice from `a` into the
+/// child at the same positions, then fills the remaining positions with `b`'s
+/// values in `b`'s order, skipping ones already placed. Produces a single
+/// valid child; call twice with `a`/`b` swapped for the complementary child.
+/// Panics if the two genomes have different lengths.
+pub fn order_crossover(a: &PermutationGenome, b: &PermutationGenome) -> PermutationGenome {
+    assert_eq!(a.len(), b.len(), "order_crossover requires equal-length genomes");
+    let len = a.len();
+    if len == 0 {
+        return PermutationGenome::identity(0);
+    }
+    let mut rand = rng::thread_rng();
+    let mut bounds = [rand.gen_range(0..len), rand.gen_range(0..len)];
+    bounds.sort_unstable();
+    let [start, end] = bounds;
+
+    let mut child = vec![None; len];
+    let mut used = HashSet::with_capacity(len);
+    for (position, slot) in child.iter_mut().enumerate().take(end + 1).skip(start) {
+        *slot = Some(a.indices[position]);
+        used.insert(a.indices[position]);
+    }
+
+    let mut remaining = b.indices.iter().filter(|value| !used.contains(value));
+    for position in (0..len).cycle().skip(end + 1).take(len) {
+        if child[position].is_some() {
+            continue;
+        }
+        if let Some(&value) = remaining.next() {
+            child[position] = Some(value);
+        }
+    }
+
+    PermutationGenome::new(child.into_iter().map(|value| value.expect("every position filled")).collect())
+}
+
+/// Swaps two randomly chosen positions.
+pub fn swap_mutation(genome: &PermutationGenome) -> PermutationGenome {
+    let mut mutated = genome.clone();
+    let len = mutated.indices.len();
+    if len < 2 {
+        return mutated;
+    }
+    let mut rand = rng::thread_rng();
+    let i = rand.gen_range(0..len);
+    let j = rand.gen_range(0..len);
+    mutated.indices.swap(i, j);
+    mutated
+}
+
+/// Reverses a randomly chosen contiguous slice.
+pub fn inversion_mutation(genome: &PermutationGenome) -> PermutationGenome {
+    let mut mutated = genome.clone();
+    let len = mutated.indices.len();
+    if len < 2 {
+        return mutated;
+    }
+    let mut rand = rng::thread_rng();
+    let mut bounds = [rand.gen_range(0..len), rand.gen_range(0..len)];
+    bounds.sort_unstable();
+    let [start, end] = bounds;
+    mutated.indices[start..=end].reverse();
+    mutated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_identity_is_valid() {
+        let genome = PermutationGenome::identity(5);
+        assert_eq!(genome.as_slice(), &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be a permutation")]
+    fn test_new_rejects_duplicate_indices() {
+        PermutationGenome::new(vec![0, 0, 2]);
+    }
+
+    #[test]
+    fn test_swap_distance_counts_mismatched_positions() {
+        let a = PermutationGenome::new(vec![0, 1, 2, 3]);
+        let b = PermutationGenome::new(vec![0, 2, 1, 3]);
+        assert_eq!(a.swap_distance(&b), 2);
+    }
+
+    #[test]
+    fn test_inversion_distance_is_zero_for_identical_order() {
+        let a = PermutationGenome::new(vec![2, 0, 1]);
+        assert_eq!(a.inversion_distance(&a), 0);
+    }
+
+    #[test]
+    fn test_canonical_cycle_matches_across_rotation_and_direction() {
+        let a = PermutationGenome::new(vec![0, 1, 2, 3]);
+        let rotated = PermutationGenome::new(vec![2, 3, 0, 1]);
+        let reversed = PermutationGenome::new(vec![0, 3, 2, 1]);
+        assert_eq!(a.canonical_cycle(), rotated.canonical_cycle());
+        assert_eq!(a.canonical_cycle(), reversed.canonical_cycle());
+    }
+
+    #[test]
+    fn test_order_crossover_produces_a_valid_permutation() {
+        let a = PermutationGenome::new(vec![0, 1, 2, 3, 4]);
+        let b = PermutationGenome::new(vec![4, 3, 2, 1, 0]);
+        let child = order_crossover(&a, &b);
+        assert!(PermutationGenome::is_valid(child.as_slice()));
+    }
+
+    #[test]
+    fn test_swap_mutation_preserves_validity() {
+        let genome = PermutationGenome::identity(6);
+        let mutated = swap_mutation(&genome);
+        assert!(PermutationGenome::is_valid(mutated.as_slice()));
+    }
+
+    #[test]
+    fn test_inversion_mutation_preserves_validity() {
+        let genome = PermutationGenome::identity(6);
+        let mutated = inversion_mutation(&genome);
+        assert!(PermutationGenome::is_valid(mutated.as_slice()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let genome = PermutationGenome::random(8);
+        let json = serde_json::to_string(&genome).unwrap();
+        let round_tripped: PermutationGenome = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped, genome);
+    }
+
+    #[test]
+    fn test_random_is_always_valid() {
+        let mut rand = rng::thread_rng();
+        for _ in 0..10 {
+            let len = rand.gen_range(0..20);
+            assert!(PermutationGenome::is_valid(PermutationGenome::random(len).as_slice()));
+        }
+    }
+}
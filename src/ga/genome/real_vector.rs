@@ -0,0 +1,289 @@
+//! A bounded real-valued vector genome (`RealGenome`) with SBX, arithmetic,
+//! and blend crossover plus Gaussian, polynomial, and uniform-reset mutation
+//! operators, so continuous benchmarks and engineering-optimization problems
+//! are first-class citizens rather than user-rolled `Vec<f64>` code.
+
+use std::ops::RangeInclusive;
+
+use rand::Rng;
+
+use crate::ga::subject::GaSubject;
+use crate::util::rng;
+use crate::util::{coin_flip, Odds};
+
+/// A fixed-length vector of `f64` values, each clamped to its own
+/// `bounds[i]` range. `values.len()` and `bounds.len()` are always equal.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RealGenome {
+    pub values: Vec<f64>,
+    bounds: Vec<RangeInclusive<f64>>,
+}
+
+impl RealGenome {
+    pub fn new(values: Vec<f64>, bounds: Vec<RangeInclusive<f64>>) -> Self {
+        assert_eq!(values.len(), bounds.len(), "values and bounds must be the same length");
+        let mut genome = Self { values, bounds };
+        genome.clamp();
+        genome
+    }
+
+    /// A genome with every value drawn uniformly from its own bound.
+    pub fn random(bounds: Vec<RangeInclusive<f64>>) -> Self {
+        let mut rand = rng::thread_rng();
+        let values = bounds.iter().map(|bound| rand.gen_range(bound.clone())).collect();
+        Self { values, bounds }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn bounds(&self) -> &[RangeInclusive<f64>] {
+        &self.bounds
+    }
+
+    /// Clamps every value back into its own bound, in place. Operators in
+    /// this module always leave a genome clamped; this is for callers that
+    /// mutate `values` directly.
+    pub fn clamp(&mut self) {
+        for (value, bound) in self.values.iter_mut().zip(self.bounds.iter()) {
+            *value = value.clamp(*bound.start(), *bound.end());
+        }
+    }
+
+    /// Euclidean distance between two genomes. Panics if their lengths differ.
+    pub fn distance(&self, other: &Self) -> f64 {
+        assert_eq!(self.len(), other.len(), "distance requires equal-length genomes");
+        self.values
+            .iter()
+            .zip(other.values.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+impl GaSubject for RealGenome {}
+
+/// Simulated binary crossover (Deb & Agrawal): produces two children
+/// distributed around the parents the way a real-coded analogue of
+/// single-point crossover on binary strings would, controlled by the
+/// distribution index `eta` (larger values produce children closer to the
+/// parents). Panics if the two genomes have different bounds.
+pub fn sbx_crossover(a: &RealGenome, b: &RealGenome, eta: f64) -> (RealGenome, RealGenome) {
+    assert_eq!(a.bounds, b.bounds, "sbx_crossover requires genomes with matching bounds");
+    let mut rand = rng::thread_rng();
+    let mut child_a_values = Vec::with_capacity(a.len());
+    let mut child_b_values = Vec::with_capacity(a.len());
+    for (&x1, &x2) in a.values.iter().zip(b.values.iter()) {
+        let u: f64 = rand.gen_range(0.0..1.0);
+        let beta = if u <= 0.5 {
+            (2.0 * u).powf(1.0 / (eta + 1.0))
+        } else {
+            (1.0 / (2.0 * (1.0 - u))).powf(1.0 / (eta + 1.0))
+        };
+        let c1 = 0.5 * ((1.0 + beta) * x1 + (1.0 - beta) * x2);
+        let c2 = 0.5 * ((1.0 - beta) * x1 + (1.0 + beta) * x2);
+        child_a_values.push(c1);
+        child_b_values.push(c2);
+    }
+    (
+        RealGenome::new(child_a_values, a.bounds.clone()),
+        RealGenome::new(child_b_values, a.bounds.clone()),
+    )
+}
+
+/// Perturbs each value independently with probability `rate` by adding
+/// Gaussian noise with standard deviation `std_dev`, then clamps back to
+/// bounds.
+pub fn gaussian_mutation(genome: &RealGenome, rate: Odds, std_dev: f64) -> RealGenome {
+    use rand_distr::{Distribution, Normal};
+    let mut mutated = genome.clone();
+    let normal = Normal::new(0.0, std_dev).expect("std_dev must be finite and non-negative");
+    let mut rand = rng::thread_rng();
+    for value in mutated.values.iter_mut() {
+        if coin_flip(rate) {
+            *value += normal.sample(&mut rand);
+        }
+    }
+    mutated.clamp();
+    mutated
+}
+
+/// Polynomial mutation (Deb & Goyal): like [`gaussian_mutation`] but the
+/// perturbation is drawn from a distribution shaped by `eta` (larger values
+/// bias perturbations closer to zero) and scaled to each value's own bound
+/// width, so values near a narrow bound aren't perturbed out of proportion.
+pub fn polynomial_mutation(genome: &RealGenome, rate: Odds, eta: f64) -> RealGenome {
+    let mut mutated = genome.clone();
+    let mut rand = rng::thread_rng();
+    for (value, bound) in mutated.values.iter_mut().zip(mutated.bounds.iter()) {
+        if !coin_flip(rate) {
+            continue;
+        }
+        let (lower, upper) = (*bound.start(), *bound.end());
+        let span = upper - lower;
+        if span <= 0.0 {
+            continue;
+        }
+        let u: f64 = rand.gen_range(0.0..1.0);
+        let delta = if u < 0.5 {
+            (2.0 * u).powf(1.0 / (eta + 1.0)) - 1.0
+        } else {
+            1.0 - (2.0 * (1.0 - u)).powf(1.0 / (eta + 1.0))
+        };
+        *value += delta * span;
+        *value = value.clamp(lower, upper);
+    }
+    mutated
+}
+
+/// Resets each value independently with probability `rate` to a fresh
+/// uniform draw from its own bound, discarding it entirely rather than
+/// perturbing it — useful for escaping a value stuck at a local optimum in a
+/// way [`gaussian_mutation`]'s small local steps can't.
+pub fn uniform_reset_mutation(genome: &RealGenome, rate: Odds) -> RealGenome {
+    let mut rand = rng::thread_rng();
+    let mut mutated = genome.clone();
+    for (value, bound) in mutated.values.iter_mut().zip(genome.bounds.iter()) {
+        if coin_flip(rate) {
+            *value = rand.gen_range(bound.clone());
+        }
+    }
+    mutated
+}
+
+/// Whole-arithmetic crossover: each child is a weighted blend of both
+/// parents at every gene, `alpha` controlling the mix (`0.5` averages the
+/// two parents). Panics if the two genomes have different bounds.
+pub fn arithmetic_crossover(a: &RealGenome, b: &RealGenome, alpha: f64) -> (RealGenome, RealGenome) {
+    assert_eq!(a.bounds, b.bounds, "arithmetic_crossover requires genomes with matching bounds");
+    let mut child_a_values = Vec::with_capacity(a.len());
+    let mut child_b_values = Vec::with_capacity(a.len());
+    for (&x1, &x2) in a.values.iter().zip(b.values.iter()) {
+        child_a_values.push(alpha * x1 + (1.0 - alpha) * x2);
+        child_b_values.push(alpha * x2 + (1.0 - alpha) * x1);
+    }
+    (
+        RealGenome::new(child_a_values, a.bounds.clone()),
+        RealGenome::new(child_b_values, a.bounds.clone()),
+    )
+}
+
+/// BLX-`alpha` blend crossover (Eshelman & Schaffer): each child gene is
+/// drawn uniformly from an interval spanning the two parents' values widened
+/// by `alpha` times their gap, so children can land slightly outside the
+/// parents' range (before being clamped back to bounds) rather than always
+/// between them. Panics if the two genomes have different bounds.
+pub fn blend_crossover(a: &RealGenome, b: &RealGenome, alpha: f64) -> (RealGenome, RealGenome) {
+    assert_eq!(a.bounds, b.bounds, "blend_crossover requires genomes with matching bounds");
+    let mut rand = rng::thread_rng();
+    let mut child_a_values = Vec::with_capacity(a.len());
+    let mut child_b_values = Vec::with_capacity(a.len());
+    for (&x1, &x2) in a.values.iter().zip(b.values.iter()) {
+        let (lower, upper) = (x1.min(x2), x1.max(x2));
+        let span = (upper - lower) * alpha;
+        let range = (lower - span)..=(upper + span);
+        child_a_values.push(rand.gen_range(range.clone()));
+        child_b_values.push(rand.gen_range(range));
+    }
+    (
+        RealGenome::new(child_a_values, a.bounds.clone()),
+        RealGenome::new(child_b_values, a.bounds.clone()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(n: usize) -> Vec<RangeInclusive<f64>> {
+        vec![0.0..=10.0; n]
+    }
+
+    #[test]
+    fn test_new_clamps_out_of_bounds_values() {
+        let genome = RealGenome::new(vec![-5.0, 20.0], bounds(2));
+        assert_eq!(genome.values, vec![0.0, 10.0]);
+    }
+
+    #[test]
+    fn test_distance_is_euclidean() {
+        let a = RealGenome::new(vec![0.0, 0.0], bounds(2));
+        let b = RealGenome::new(vec![3.0, 4.0], bounds(2));
+        assert_eq!(a.distance(&b), 5.0);
+    }
+
+    #[test]
+    fn test_sbx_crossover_children_stay_within_bounds() {
+        let a = RealGenome::new(vec![2.0, 8.0], bounds(2));
+        let b = RealGenome::new(vec![9.0, 1.0], bounds(2));
+        let (child_a, child_b) = sbx_crossover(&a, &b, 2.0);
+        for value in child_a.values.iter().chain(child_b.values.iter()) {
+            assert!((0.0..=10.0).contains(value));
+        }
+    }
+
+    #[test]
+    fn test_gaussian_mutation_stays_within_bounds() {
+        let genome = RealGenome::new(vec![5.0, 5.0], bounds(2));
+        let mutated = gaussian_mutation(&genome, 1.0, 100.0);
+        for value in mutated.values.iter() {
+            assert!((0.0..=10.0).contains(value));
+        }
+    }
+
+    #[test]
+    fn test_polynomial_mutation_stays_within_bounds() {
+        let genome = RealGenome::new(vec![5.0, 5.0], bounds(2));
+        let mutated = polynomial_mutation(&genome, 1.0, 20.0);
+        for value in mutated.values.iter() {
+            assert!((0.0..=10.0).contains(value));
+        }
+    }
+
+    #[test]
+    fn test_uniform_reset_mutation_stays_within_bounds() {
+        let genome = RealGenome::new(vec![5.0, 5.0], bounds(2));
+        let mutated = uniform_reset_mutation(&genome, 1.0);
+        for value in mutated.values.iter() {
+            assert!((0.0..=10.0).contains(value));
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_crossover_averages_at_half() {
+        let a = RealGenome::new(vec![2.0, 8.0], bounds(2));
+        let b = RealGenome::new(vec![4.0, 0.0], bounds(2));
+        let (child_a, child_b) = arithmetic_crossover(&a, &b, 0.5);
+        assert_eq!(child_a.values, vec![3.0, 4.0]);
+        assert_eq!(child_b.values, vec![3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_blend_crossover_children_stay_within_bounds() {
+        let a = RealGenome::new(vec![2.0, 8.0], bounds(2));
+        let b = RealGenome::new(vec![9.0, 1.0], bounds(2));
+        let (child_a, child_b) = blend_crossover(&a, &b, 0.5);
+        for value in child_a.values.iter().chain(child_b.values.iter()) {
+            assert!((0.0..=10.0).contains(value));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let genome = RealGenome::random(bounds(3));
+        let json = serde_json::to_string(&genome).unwrap();
+        let round_tripped: RealGenome = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped.bounds(), genome.bounds());
+        for (a, b) in round_tripped.values.iter().zip(genome.values.iter()) {
+            assert!((a - b).abs() < 1e-9);
+        }
+    }
+}
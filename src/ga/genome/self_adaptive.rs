@@ -0,0 +1,124 @@
+//! A self-adaptive wrapper genome (`SelfAdaptive<G>`) that carries its own
+//! mutation-strength parameter (`sigma`) alongside the wrapped genome, the
+//! classic evolution-strategy trick of letting strategy parameters evolve
+//! per-subject instead of coming from a single crate-wide
+//! `overall_mutation_chance`.
+
+use rand_distr::{Distribution, Normal};
+
+use crate::ga::fitness::{Fit, Fitness};
+use crate::ga::mutation::ApplyMutation;
+use crate::ga::subject::GaSubject;
+use crate::ga::GaContext;
+
+/// A genome paired with its own `sigma` strategy parameter.
+/// [`SelfAdaptiveMutation`] mutates `sigma` first via a log-normal update,
+/// then mutates `genome` using the freshly adapted value, so subjects whose
+/// lineage found a productive step size keep it instead of every subject
+/// sharing one global rate.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SelfAdaptive<G> {
+    pub genome: G,
+    pub sigma: f64,
+}
+
+impl<G> SelfAdaptive<G> {
+    pub fn new(genome: G, sigma: f64) -> Self {
+        Self { genome, sigma }
+    }
+}
+
+impl<G: Send + Sync> GaSubject for SelfAdaptive<G> {}
+
+impl<G: Fit<Fitness>> Fit<Fitness> for SelfAdaptive<G> {
+    fn measure(&self) -> Fitness {
+        self.genome.measure()
+    }
+}
+
+/// Mutates a [`SelfAdaptive`] genome: `sigma' = sigma * exp(tau * N(0, 1))`,
+/// floored at `min_sigma` so it can't collapse to (or below) zero and stop
+/// mutation entirely, then `mutate(&genome, sigma')` produces the new inner
+/// genome. `mutate` is the caller's own operator (e.g. [`super::real_vector::gaussian_mutation`]
+/// partially applied over its `rate`), since how `sigma` is used to perturb
+/// a genome is specific to the wrapped genome type.
+pub struct SelfAdaptiveMutation<G, F> {
+    pub tau: f64,
+    pub min_sigma: f64,
+    mutate: F,
+    _genome: std::marker::PhantomData<G>,
+}
+
+impl<G, F> SelfAdaptiveMutation<G, F>
+where
+    F: Fn(&G, f64) -> G,
+{
+    pub fn new(tau: f64, min_sigma: f64, mutate: F) -> Self {
+        Self {
+            tau,
+            min_sigma,
+            mutate,
+            _genome: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<G, F> ApplyMutation for SelfAdaptiveMutation<G, F>
+where
+    G: Fit<Fitness> + Send + Sync,
+    F: Fn(&G, f64) -> G,
+{
+    type Subject = SelfAdaptive<G>;
+
+    fn apply(&self, context: &GaContext, subject: &Self::Subject) -> Self::Subject {
+        let z: f64 = Normal::new(0.0, 1.0)
+            .expect("standard normal is always valid")
+            .sample(&mut *context.rng());
+        let sigma = (subject.sigma * (self.tau * z).exp()).max(self.min_sigma);
+        let genome = (self.mutate)(&subject.genome, sigma);
+        SelfAdaptive::new(genome, sigma)
+    }
+
+    fn fitness(subject: &Self::Subject) -> Fitness {
+        subject.measure()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Scalar(f64);
+
+    impl Fit<Fitness> for Scalar {
+        fn measure(&self) -> Fitness {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_measure_delegates_to_inner_genome() {
+        let subject = SelfAdaptive::new(Scalar(3.0), 1.0);
+        assert_eq!(subject.measure(), 3.0);
+    }
+
+    #[test]
+    fn test_apply_floors_sigma_at_min_sigma() {
+        let mutation = SelfAdaptiveMutation::new(0.0, 0.5, |genome: &Scalar, sigma| Scalar(genome.0 + sigma));
+        let subject = SelfAdaptive::new(Scalar(0.0), 0.1);
+        let mutated = mutation.apply(&GaContext::default(), &subject);
+        assert_eq!(mutated.sigma, 0.5);
+        assert_eq!(mutated.genome.0, 0.5);
+    }
+
+    #[test]
+    fn test_apply_uses_updated_sigma_to_mutate_inner_genome() {
+        let mutation = SelfAdaptiveMutation::new(0.0, 0.0, |genome: &Scalar, sigma| Scalar(genome.0 + sigma));
+        let subject = SelfAdaptive::new(Scalar(1.0), 2.0);
+        let mutated = mutation.apply(&GaContext::default(), &subject);
+        assert_eq!(mutated.sigma, 2.0);
+        assert_eq!(mutated.genome.0, 3.0);
+    }
+}
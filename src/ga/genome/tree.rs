@@ -0,0 +1,303 @@
+//! Typed genetic-programming expression trees: subtree crossover, point/
+//! subtree/hoist mutation, depth/size limits, and an evaluation interface,
+//! so symbolic-regression-style problems can be built on a [`TreeGenome`]
+//! instead of reaching for a separate GP crate.
+
+use std::hash::Hash;
+
+use rand::Rng;
+
+use crate::ga::subject::GaSubject;
+use crate::util::rng;
+
+/// A single operator/terminal in a [`TreeGenome`], e.g. an `Add`/`Mul`
+/// variant or a `Const(f64)`/`Var(usize)` leaf in a problem-specific enum.
+pub trait TreeGene: Clone + std::fmt::Debug + PartialEq + Send + Sync {
+    type Context;
+    type Value;
+    /// Number of children this gene expects: `0` for a terminal/leaf.
+    fn arity(&self) -> usize;
+    /// Combines this gene with its already-evaluated children.
+    fn eval(&self, children: &[Self::Value], context: &Self::Context) -> Self::Value;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TreeNode<Gene> {
+    pub gene: Gene,
+    pub children: Vec<TreeNode<Gene>>,
+}
+
+impl<Gene: TreeGene> TreeNode<Gene> {
+    pub fn new(gene: Gene, children: Vec<TreeNode<Gene>>) -> Self {
+        debug_assert_eq!(
+            gene.arity(),
+            children.len(),
+            "gene arity does not match child count"
+        );
+        Self { gene, children }
+    }
+
+    pub fn leaf(gene: Gene) -> Self {
+        Self::new(gene, vec![])
+    }
+
+    /// `1` for a single leaf, growing by one per level of nesting.
+    pub fn depth(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(TreeNode::depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Total number of nodes in the tree, including this one.
+    pub fn size(&self) -> usize {
+        1 + self.children.iter().map(TreeNode::size).sum::<usize>()
+    }
+
+    pub fn eval(&self, context: &Gene::Context) -> Gene::Value {
+        let children: Vec<Gene::Value> = self.children.iter().map(|c| c.eval(context)).collect();
+        self.gene.eval(&children, context)
+    }
+
+    /// The node at `target` in a depth-first, pre-order walk (`0` = the root
+    /// itself). Panics if `target >= self.size()`.
+    pub fn subtree_at(&self, target: usize) -> &TreeNode<Gene> {
+        fn go<'a, Gene>(
+            node: &'a TreeNode<Gene>,
+            current: &mut usize,
+            target: usize,
+        ) -> Option<&'a TreeNode<Gene>> {
+            let this_index = *current;
+            *current += 1;
+            if this_index == target {
+                return Some(node);
+            }
+            node.children.iter().find_map(|c| go(c, current, target))
+        }
+        let mut current = 0;
+        go(self, &mut current, target).expect("index out of bounds for tree")
+    }
+
+    /// A clone of the whole tree with the node at `target` (same pre-order
+    /// numbering as [`Self::subtree_at`]) replaced by `replacement`.
+    pub fn with_subtree_replaced(&self, target: usize, replacement: TreeNode<Gene>) -> TreeNode<Gene> {
+        fn go<Gene: Clone>(
+            node: &TreeNode<Gene>,
+            current: &mut usize,
+            target: usize,
+            replacement: &TreeNode<Gene>,
+        ) -> TreeNode<Gene> {
+            let this_index = *current;
+            *current += 1;
+            if this_index == target {
+                return replacement.clone();
+            }
+            TreeNode {
+                gene: node.gene.clone(),
+                children: node
+                    .children
+                    .iter()
+                    .map(|c| go(c, current, target, replacement))
+                    .collect(),
+            }
+        }
+        let mut current = 0;
+        go(self, &mut current, target, &replacement)
+    }
+}
+
+/// Swaps a random subtree between `a` and `b`. If either child would exceed
+/// `max_depth`, the corresponding parent is returned unchanged instead
+/// (rejecting the crossover for that side only).
+pub fn subtree_crossover<Gene: TreeGene>(
+    a: &TreeNode<Gene>,
+    b: &TreeNode<Gene>,
+    max_depth: usize,
+) -> (TreeNode<Gene>, TreeNode<Gene>) {
+    let mut rand = rng::thread_rng();
+    let a_index = rand.gen_range(0..a.size());
+    let b_index = rand.gen_range(0..b.size());
+    let a_subtree = a.subtree_at(a_index).clone();
+    let b_subtree = b.subtree_at(b_index).clone();
+    let child_a = a.with_subtree_replaced(a_index, b_subtree);
+    let child_b = b.with_subtree_replaced(b_index, a_subtree);
+    let new_a = if child_a.depth() <= max_depth { child_a } else { a.clone() };
+    let new_b = if child_b.depth() <= max_depth { child_b } else { b.clone() };
+    (new_a, new_b)
+}
+
+/// Replaces a single randomly chosen gene with one produced by `new_gene`,
+/// keeping the rest of the tree (including that node's children) intact.
+/// `new_gene` is handed the arity of the gene it's replacing, so it knows
+/// how many children to expect.
+pub fn point_mutation<Gene: TreeGene>(
+    tree: &TreeNode<Gene>,
+    new_gene: impl Fn(usize) -> Gene,
+) -> TreeNode<Gene> {
+    fn go<Gene: TreeGene>(
+        node: &TreeNode<Gene>,
+        current: &mut usize,
+        target: usize,
+        new_gene: &impl Fn(usize) -> Gene,
+    ) -> TreeNode<Gene> {
+        let this_index = *current;
+        *current += 1;
+        let children: Vec<_> = node
+            .children
+            .iter()
+            .map(|c| go(c, current, target, new_gene))
+            .collect();
+        if this_index == target {
+            TreeNode::new(new_gene(node.gene.arity()), children)
+        } else {
+            TreeNode { gene: node.gene.clone(), children }
+        }
+    }
+    let mut rand = rng::thread_rng();
+    let target = rand.gen_range(0..tree.size());
+    let mut current = 0;
+    go(tree, &mut current, target, &new_gene)
+}
+
+/// Replaces a randomly chosen subtree with a freshly generated one.
+/// `generate` is handed the remaining depth budget for the new subtree.
+pub fn subtree_mutation<Gene: TreeGene>(
+    tree: &TreeNode<Gene>,
+    max_depth: usize,
+    generate: impl Fn(usize) -> TreeNode<Gene>,
+) -> TreeNode<Gene> {
+    // depth (root = 1) of the node at `target`, same pre-order numbering as `subtree_at`
+    fn depth_at<Gene>(node: &TreeNode<Gene>, current: &mut usize, target: usize, depth: usize) -> Option<usize> {
+        let this_index = *current;
+        *current += 1;
+        if this_index == target {
+            return Some(depth);
+        }
+        node.children.iter().find_map(|c| depth_at(c, current, target, depth + 1))
+    }
+    let mut rand = rng::thread_rng();
+    let target = rand.gen_range(0..tree.size());
+    let target_depth = depth_at(tree, &mut 0, target, 1).expect("index out of bounds for tree");
+    tree.with_subtree_replaced(target, generate(max_depth.saturating_sub(target_depth - 1)))
+}
+
+/// Promotes a randomly chosen non-root subtree to become the new root,
+/// the standard antidote to bloat (trees accreting dead weight that never
+/// affects fitness).
+pub fn hoist_mutation<Gene: TreeGene>(tree: &TreeNode<Gene>) -> TreeNode<Gene> {
+    if tree.size() <= 1 {
+        return tree.clone();
+    }
+    let mut rand = rng::thread_rng();
+    let target = rand.gen_range(1..tree.size());
+    tree.subtree_at(target).clone()
+}
+
+/// A [`GaSubject`] wrapping a GP expression tree, with an enforced maximum
+/// depth that crossover/mutation operators in this module respect.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TreeGenome<Gene> {
+    pub root: TreeNode<Gene>,
+    pub max_depth: usize,
+}
+
+impl<Gene: TreeGene> TreeGenome<Gene> {
+    pub fn new(root: TreeNode<Gene>, max_depth: usize) -> Self {
+        Self { root, max_depth }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.root.depth()
+    }
+
+    pub fn size(&self) -> usize {
+        self.root.size()
+    }
+
+    pub fn eval(&self, context: &Gene::Context) -> Gene::Value {
+        self.root.eval(context)
+    }
+}
+
+impl<Gene: TreeGene> GaSubject for TreeGenome<Gene> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    enum Op {
+        Add,
+        Const(i64),
+    }
+
+    impl TreeGene for Op {
+        type Context = ();
+        type Value = i64;
+
+        fn arity(&self) -> usize {
+            match self {
+                Op::Add => 2,
+                Op::Const(_) => 0,
+            }
+        }
+
+        fn eval(&self, children: &[Self::Value], _context: &Self::Context) -> Self::Value {
+            match self {
+                Op::Add => children.iter().sum(),
+                Op::Const(value) => *value,
+            }
+        }
+    }
+
+    fn add(left: TreeNode<Op>, right: TreeNode<Op>) -> TreeNode<Op> {
+        TreeNode::new(Op::Add, vec![left, right])
+    }
+
+    fn c(value: i64) -> TreeNode<Op> {
+        TreeNode::leaf(Op::Const(value))
+    }
+
+    #[test]
+    fn test_depth_and_size() {
+        let tree = add(c(1), add(c(2), c(3)));
+        assert_eq!(tree.size(), 5);
+        assert_eq!(tree.depth(), 3);
+    }
+
+    #[test]
+    fn test_eval_combines_children() {
+        let tree = add(c(1), add(c(2), c(3)));
+        assert_eq!(tree.eval(&()), 6);
+    }
+
+    #[test]
+    fn test_with_subtree_replaced_swaps_only_target_node() {
+        let tree = add(c(1), c(2));
+        let replaced = tree.with_subtree_replaced(1, c(99));
+        assert_eq!(replaced.eval(&()), 101);
+    }
+
+    #[test]
+    fn test_hoist_mutation_promotes_non_root_subtree() {
+        let tree = add(c(1), c(2));
+        let hoisted = hoist_mutation(&tree);
+        assert!(hoisted.size() < tree.size());
+    }
+
+    #[test]
+    fn test_subtree_crossover_preserves_validity() {
+        let a = add(c(1), c(2));
+        let b = add(c(3), add(c(4), c(5)));
+        let (child_a, child_b) = subtree_crossover(&a, &b, 10);
+        // every node's arity still matches its child count after crossover
+        fn check(node: &TreeNode<Op>) {
+            assert_eq!(node.gene.arity(), node.children.len());
+            node.children.iter().for_each(check);
+        }
+        check(&child_a);
+        check(&child_b);
+    }
+}
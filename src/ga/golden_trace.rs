@@ -0,0 +1,278 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::fs::File;
+use std::hash::Hash;
+use std::io;
+use std::io::{BufReader, BufWriter};
+use std::marker::PhantomData;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ga::fitness::Fitness;
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+
+/// One recorded generation in a [`GoldenTrace`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoldenTraceStep {
+    pub generation: usize,
+    pub best_fitness: Fitness,
+    /// Free-form description of the action decisions that generation, from
+    /// [`GoldenTraceRecorder`]'s optional `note` fn pointer. `None` if the
+    /// recorder wasn't given one.
+    pub note: Option<String>,
+}
+
+/// A deterministic run's sequence of best-fitness-by-generation (and
+/// optionally action decisions), for later comparison via [`verify`] to
+/// catch behavioral regressions when operators change. Requires the run
+/// itself to be deterministic (see the `deterministic-rng` feature) —
+/// comparing traces from two non-deterministic runs will report spurious
+/// mismatches.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoldenTrace {
+    pub steps: Vec<GoldenTraceStep>,
+}
+
+pub fn write_trace(trace: &GoldenTrace, path: impl AsRef<Path>) -> io::Result<()> {
+    let file = File::create(path)?;
+    serde_json::to_writer(BufWriter::new(file), trace)?;
+    Ok(())
+}
+
+pub fn read_trace(path: impl AsRef<Path>) -> io::Result<GoldenTrace> {
+    let file = File::open(path)?;
+    let trace = serde_json::from_reader(BufReader::new(file))?;
+    Ok(trace)
+}
+
+/// Why [`verify`] considers `actual` to have diverged from `golden`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceMismatch {
+    LengthMismatch { golden: usize, actual: usize },
+    FitnessMismatch { generation: usize, golden: Fitness, actual: Fitness },
+    NoteMismatch { generation: usize, golden: Option<String>, actual: Option<String> },
+}
+
+impl fmt::Display for TraceMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceMismatch::LengthMismatch { golden, actual } => write!(
+                f,
+                "trace length mismatch: golden has {golden} steps, actual has {actual}"
+            ),
+            TraceMismatch::FitnessMismatch {
+                generation,
+                golden,
+                actual,
+            } => write!(
+                f,
+                "fitness mismatch at generation {generation}: golden={golden}, actual={actual}"
+            ),
+            TraceMismatch::NoteMismatch {
+                generation,
+                golden,
+                actual,
+            } => write!(
+                f,
+                "note mismatch at generation {generation}: golden={golden:?}, actual={actual:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TraceMismatch {}
+
+/// Compares `actual` against `golden` step by step, allowing `tolerance` of
+/// absolute difference between fitness values (deterministic floating-point
+/// arithmetic should reproduce exactly, but `0.0` is brittle across
+/// toolchains/targets; pass a small epsilon if that matters to you).
+/// Returns the first divergence found, if any.
+pub fn verify_with_tolerance(
+    golden: &GoldenTrace,
+    actual: &GoldenTrace,
+    tolerance: Fitness,
+) -> Result<(), TraceMismatch> {
+    if golden.steps.len() != actual.steps.len() {
+        return Err(TraceMismatch::LengthMismatch {
+            golden: golden.steps.len(),
+            actual: actual.steps.len(),
+        });
+    }
+    for (golden_step, actual_step) in golden.steps.iter().zip(&actual.steps) {
+        if (golden_step.best_fitness - actual_step.best_fitness).abs() > tolerance {
+            return Err(TraceMismatch::FitnessMismatch {
+                generation: golden_step.generation,
+                golden: golden_step.best_fitness,
+                actual: actual_step.best_fitness,
+            });
+        }
+        if golden_step.note != actual_step.note {
+            return Err(TraceMismatch::NoteMismatch {
+                generation: golden_step.generation,
+                golden: golden_step.note.clone(),
+                actual: actual_step.note.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// [`verify_with_tolerance`] with `tolerance` of `0.0`.
+pub fn verify(golden: &GoldenTrace, actual: &GoldenTrace) -> Result<(), TraceMismatch> {
+    verify_with_tolerance(golden, actual, 0.0)
+}
+
+/// Records one [`GoldenTraceStep`] per generation into an in-memory
+/// [`GoldenTrace`], for later comparison via [`verify`] or persistence via
+/// [`write_trace`]. Each step's `best_fitness` is the lowest fitness in the
+/// population; reverse (maximizing) mode is not taken into account.
+///
+/// Register it as an action the same way [`crate::ga::csv_stats::CsvStatsRecorder`]
+/// is registered, then call [`Self::into_trace`] once the run has finished.
+pub struct GoldenTraceRecorder<Subject> {
+    trace: RefCell<GoldenTrace>,
+    note: Option<fn(&Population<Subject>) -> String>,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> GoldenTraceRecorder<Subject> {
+    pub fn new(note: Option<fn(&Population<Subject>) -> String>) -> Self {
+        Self {
+            trace: RefCell::new(GoldenTrace::default()),
+            note,
+            _subject: PhantomData,
+        }
+    }
+
+    pub fn into_trace(self) -> GoldenTrace {
+        self.trace.into_inner()
+    }
+}
+
+impl<Subject> Default for GoldenTraceRecorder<Subject> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+macro_rules! impl_record_generation {
+    () => {
+        fn record_generation(&self, context: &GaContext, population: &Population<Subject>) {
+            let Some(best) = population.subjects.iter().min_by(|a, b| {
+                a.fitness()
+                    .partial_cmp(&b.fitness())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }) else {
+                return;
+            };
+            let note = self.note.map(|note_fn| note_fn(population));
+            self.trace.borrow_mut().steps.push(GoldenTraceStep {
+                generation: context.generation,
+                best_fitness: best.fitness(),
+                note,
+            });
+        }
+    };
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq> GoldenTraceRecorder<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync> GoldenTraceRecorder<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq> GaAction for GoldenTraceRecorder<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("golden_trace", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync> GaAction for GoldenTraceRecorder<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("golden_trace", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::fitness::FitnessWrapped;
+    use crate::ga::golden_trace::{verify, verify_with_tolerance, GoldenTrace, GoldenTraceRecorder, GoldenTraceStep, TraceMismatch};
+    use crate::ga::population::Population;
+    use crate::ga::{GaAction, GaContext};
+
+    fn population_of(fitnesses: &[u32]) -> Population<u32> {
+        Population {
+            pool_size: fitnesses.len(),
+            subjects: fitnesses
+                .iter()
+                .map(|&f| FitnessWrapped::new(f, f as f64))
+                .collect(),
+            memory_budget_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_recorder_records_best_fitness_per_generation() {
+        let recorder = GoldenTraceRecorder::<u32>::default();
+        recorder.perform_action(&GaContext::new(0), &mut population_of(&[3, 1, 2]));
+        recorder.perform_action(&GaContext::new(1), &mut population_of(&[5, 4]));
+
+        let trace = recorder.into_trace();
+        assert_eq!(
+            trace.steps,
+            vec![
+                GoldenTraceStep { generation: 0, best_fitness: 1.0, note: None },
+                GoldenTraceStep { generation: 1, best_fitness: 4.0, note: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_passes_for_identical_traces() {
+        let trace = GoldenTrace {
+            steps: vec![GoldenTraceStep { generation: 0, best_fitness: 1.0, note: None }],
+        };
+        assert_eq!(verify(&trace, &trace.clone()), Ok(()));
+    }
+
+    #[test]
+    fn test_verify_reports_fitness_mismatch() {
+        let golden = GoldenTrace {
+            steps: vec![GoldenTraceStep { generation: 0, best_fitness: 1.0, note: None }],
+        };
+        let actual = GoldenTrace {
+            steps: vec![GoldenTraceStep { generation: 0, best_fitness: 2.0, note: None }],
+        };
+        assert_eq!(
+            verify(&golden, &actual),
+            Err(TraceMismatch::FitnessMismatch { generation: 0, golden: 1.0, actual: 2.0 })
+        );
+    }
+
+    #[test]
+    fn test_verify_with_tolerance_allows_small_drift() {
+        let golden = GoldenTrace {
+            steps: vec![GoldenTraceStep { generation: 0, best_fitness: 1.0, note: None }],
+        };
+        let actual = GoldenTrace {
+            steps: vec![GoldenTraceStep { generation: 0, best_fitness: 1.0000001, note: None }],
+        };
+        assert_eq!(verify_with_tolerance(&golden, &actual, 0.001), Ok(()));
+    }
+}
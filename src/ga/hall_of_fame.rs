@@ -0,0 +1,194 @@
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::ga::fitness::FitnessWrapped;
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+
+/// A fixed-capacity archive of the best subjects ever seen across a run,
+/// ordered best-first by raw fitness (like the other observer actions, this
+/// doesn't account for reverse/maximizing mode — see
+/// [`crate::ga::golden_trace::GoldenTraceRecorder`] for the same caveat).
+/// Membership is by subject equality ([`FitnessWrapped`]'s `PartialEq`
+/// ignores fitness), so a subject that reappears after being pruned,
+/// deduped, or lost to a restart doesn't occupy a second slot.
+#[derive(Debug, Clone)]
+pub struct HallOfFame<Subject> {
+    capacity: usize,
+    // kept sorted ascending by fitness; entries[0] is the best-ever subject.
+    entries: Vec<FitnessWrapped<Subject>>,
+}
+
+impl<Subject: PartialEq> HallOfFame<Subject> {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, entries: Vec::with_capacity(capacity) }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The best subject ever archived, or `None` if nothing has been
+    /// archived yet.
+    pub fn best(&self) -> Option<&FitnessWrapped<Subject>> {
+        self.entries.first()
+    }
+
+    /// Archived subjects, best-first.
+    pub fn iter(&self) -> impl Iterator<Item = &FitnessWrapped<Subject>> {
+        self.entries.iter()
+    }
+
+    /// Offers `subject` to the archive. Does nothing if an equal subject is
+    /// already archived, if the archive is full and `subject` is no better
+    /// than the current worst entry, or if `capacity` is `0`. Returns
+    /// `true` if `subject` was archived.
+    pub fn consider(&mut self, subject: FitnessWrapped<Subject>) -> bool {
+        if self.capacity == 0 || self.entries.contains(&subject) {
+            return false;
+        }
+        if self.entries.len() < self.capacity {
+            self.insert_sorted(subject);
+            return true;
+        }
+        let worst_is_better_or_equal = self
+            .entries
+            .last()
+            .is_some_and(|worst| worst.fitness() <= subject.fitness());
+        if worst_is_better_or_equal {
+            return false;
+        }
+        self.entries.pop();
+        self.insert_sorted(subject);
+        true
+    }
+
+    fn insert_sorted(&mut self, subject: FitnessWrapped<Subject>) {
+        let position = self
+            .entries
+            .partition_point(|entry| entry.fitness() <= subject.fitness());
+        self.entries.insert(position, subject);
+    }
+
+    /// Offers every subject in `population` to the archive.
+    pub fn consider_population(&mut self, population: &Population<Subject>)
+    where
+        Subject: Clone,
+    {
+        for subject in population.subjects.iter() {
+            self.consider(subject.clone());
+        }
+    }
+}
+
+/// Updates a [`HallOfFame`] from the population every generation. Register
+/// it as an action the same way [`crate::ga::golden_trace::GoldenTraceRecorder`]
+/// is registered, then call [`Self::into_hall_of_fame`] once the run has
+/// finished to include the archive in whatever report the caller assembles.
+pub struct HallOfFameRecorder<Subject> {
+    hall_of_fame: RefCell<HallOfFame<Subject>>,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> HallOfFameRecorder<Subject> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            hall_of_fame: RefCell::new(HallOfFame { capacity, entries: Vec::with_capacity(capacity) }),
+            _subject: PhantomData,
+        }
+    }
+
+    pub fn into_hall_of_fame(self) -> HallOfFame<Subject> {
+        self.hall_of_fame.into_inner()
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + Clone> GaAction for HallOfFameRecorder<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("hall_of_fame", context, population, |population| {
+            self.hall_of_fame.borrow_mut().consider_population(population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + Clone + Send + Sync> GaAction for HallOfFameRecorder<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("hall_of_fame", context, population, |population| {
+            self.hall_of_fame.borrow_mut().consider_population(population);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::fitness::Fitness;
+
+    fn wrapped(fitness: Fitness) -> FitnessWrapped<u32> {
+        FitnessWrapped::new(fitness as u32, fitness)
+    }
+
+    #[test]
+    fn test_consider_keeps_only_the_best_up_to_capacity() {
+        let mut hof = HallOfFame::new(2);
+        assert!(hof.consider(wrapped(3.0)));
+        assert!(hof.consider(wrapped(1.0)));
+        assert!(!hof.consider(wrapped(5.0)));
+        assert_eq!(hof.len(), 2);
+        assert_eq!(hof.best().unwrap().fitness(), 1.0);
+    }
+
+    #[test]
+    fn test_consider_evicts_worst_when_full() {
+        let mut hof = HallOfFame::new(2);
+        hof.consider(wrapped(3.0));
+        hof.consider(wrapped(4.0));
+        assert!(hof.consider(wrapped(1.0)));
+        let fitnesses: Vec<Fitness> = hof.iter().map(|entry| entry.fitness()).collect();
+        assert_eq!(fitnesses, vec![1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_consider_rejects_duplicate_subject() {
+        let mut hof = HallOfFame::new(5);
+        hof.consider(FitnessWrapped::new(42u32, 1.0));
+        assert!(!hof.consider(FitnessWrapped::new(42u32, 0.5)));
+        assert_eq!(hof.len(), 1);
+    }
+
+    #[test]
+    fn test_zero_capacity_archives_nothing() {
+        let mut hof: HallOfFame<u32> = HallOfFame::new(0);
+        assert!(!hof.consider(wrapped(1.0)));
+        assert!(hof.is_empty());
+    }
+
+    #[test]
+    fn test_recorder_archives_best_across_generations() {
+        let recorder = HallOfFameRecorder::<u32>::new(2);
+        let population = Population {
+            pool_size: 3,
+            subjects: vec![wrapped(3.0), wrapped(1.0), wrapped(2.0)],
+            memory_budget_bytes: None,
+        };
+        recorder.perform_action(&GaContext::new(0), &mut population.clone());
+        let hof = recorder.into_hall_of_fame();
+        assert_eq!(hof.best().unwrap().fitness(), 1.0);
+        assert_eq!(hof.len(), 2);
+    }
+}
@@ -1,6 +1,7 @@
 use std::hash::Hash;
+use std::marker::PhantomData;
 
-use crate::ga::fitness::{Fit, Fitness};
+use crate::ga::fitness::{wrap_batch, Fit, FitBatch, Fitness};
 use crate::ga::population::Population;
 use crate::ga::subject::GaSubject;
 use crate::ga::{GaAction, GaContext};
@@ -11,6 +12,31 @@ pub trait InflateTarget {
     fn inflate(&self, params: &Self::Params, target: &mut Self::Target);
 }
 
+/// A no-op [`InflateTarget`]/[`GaAction`], for callers of
+/// [`crate::ga::action::DefaultActionsBuilder`] whose population never
+/// shrinks below `pool_size` and so never need a real inflate stage. Leaves
+/// `target` untouched.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct EmptyInflate<Subject>(PhantomData<Subject>);
+
+impl<Subject> InflateTarget for EmptyInflate<Subject> {
+    type Params = GaContext;
+    type Target = Population<Subject>;
+    fn inflate(&self, _params: &Self::Params, _target: &mut Self::Target) {
+        // no op
+    }
+}
+
+impl<Subject> GaAction for EmptyInflate<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("inflate", context, population, |_population| {
+            // no op
+        });
+    }
+}
+
 #[derive(Debug, Copy, Clone, Default)]
 pub struct InflateUntilFull<F>(pub F);
 
@@ -38,6 +64,45 @@ where
     type Subject = Subject;
 
     fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
-        self.inflate(context, population);
+        crate::ga::instrument_action("inflate", context, population, |population| {
+            self.inflate(context, population);
+        });
+    }
+}
+
+/// Batched counterpart to [`InflateUntilFull`] for [`FitBatch`] subjects:
+/// creates every subject needed to fill the population first, then scores
+/// them all in one [`FitBatch::measure_batch`] call instead of scoring each
+/// one as it's created.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct InflateUntilFullBatched<F>(pub F);
+
+impl<Subject, CreateSubjectFunc> InflateTarget for InflateUntilFullBatched<CreateSubjectFunc>
+where
+    Subject: GaSubject + Hash + Eq + PartialEq + FitBatch,
+    CreateSubjectFunc: Fn(&GaContext) -> Subject,
+{
+    type Params = GaContext;
+    type Target = Population<Subject>;
+    fn inflate(&self, params: &Self::Params, target: &mut Self::Target) {
+        let missing = target.pool_size.saturating_sub(target.subjects.len());
+        let new_subjects: Vec<Subject> = (0..missing).map(|_| self.0(params)).collect();
+        for wrapped in wrap_batch(new_subjects) {
+            target.add(wrapped);
+        }
+    }
+}
+
+impl<Subject, CreateSubjectFunc> GaAction for InflateUntilFullBatched<CreateSubjectFunc>
+where
+    Subject: GaSubject + Hash + Eq + PartialEq + FitBatch,
+    CreateSubjectFunc: Fn(&GaContext) -> Subject,
+{
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("inflate", context, population, |population| {
+            self.inflate(context, population);
+        });
     }
 }
@@ -0,0 +1,308 @@
+use std::hash::Hash;
+
+use derivative::Derivative;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::ga::fitness::{Fit, Fitness, FitnessWrapped};
+use crate::ga::ga_iterator::{GaIterOptions, GaIterState, GaIterator};
+use crate::ga::population::Population;
+use crate::ga::subject::GaSubject;
+use crate::ga::{GaAction, GaContext, GeneticAlgorithmOptions};
+
+/// How migrants are routed between islands in [`run_islands`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MigrationTopology {
+    /// Each island sends migrants only to the next island in index order,
+    /// wrapping around (a single directed ring).
+    Ring,
+    /// Every island sends migrants to every other island.
+    FullyConnected,
+}
+
+impl MigrationTopology {
+    fn targets(&self, island_count: usize, island_index: usize) -> Vec<usize> {
+        if island_count <= 1 {
+            return vec![];
+        }
+        match self {
+            MigrationTopology::Ring => vec![(island_index + 1) % island_count],
+            MigrationTopology::FullyConnected => {
+                (0..island_count).filter(|&i| i != island_index).collect()
+            }
+        }
+    }
+}
+
+#[derive(Derivative, Clone)]
+#[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IslandRunnerOptions<Subject> {
+    #[derivative(Debug = "ignore")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub debug_print: Option<fn(&Subject)>,
+    /// Migrate every `migration_interval` generations, counted against the
+    /// first island's generation counter, which stops advancing once that
+    /// island has terminated. `0` disables migration.
+    pub migration_interval: usize,
+    /// How many of an island's best subjects (by raw fitness — like the
+    /// other observer actions, this doesn't account for reverse/maximizing
+    /// mode) are sent to each of its migration targets.
+    pub migration_count: usize,
+    pub topology: MigrationTopology,
+}
+
+impl<Subject> Default for IslandRunnerOptions<Subject> {
+    fn default() -> Self {
+        Self {
+            debug_print: None,
+            migration_interval: 0,
+            migration_count: 0,
+            topology: MigrationTopology::Ring,
+        }
+    }
+}
+
+fn new_iterators<Subject, Actions>(
+    ga_options: &GeneticAlgorithmOptions<Actions>,
+    runner_options: &IslandRunnerOptions<Subject>,
+    islands: Vec<Population<Subject>>,
+) -> Vec<GaIterator<Subject, Actions>>
+where
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq,
+    Actions: GaAction<Subject = Subject> + Clone,
+{
+    islands
+        .into_iter()
+        .map(|population| {
+            GaIterator::new_with_options(
+                ga_options.clone(),
+                GaIterState::new(GaContext::default(), population),
+                GaIterOptions {
+                    debug_print: runner_options.debug_print,
+                    track_genealogy: false,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Sends each island's `migration_count` best subjects to its neighbors per
+/// `topology`. Migrants are simply [`Population::add`]ed into the target's
+/// pool, the same way freshly produced children are added during
+/// reproduction; whatever prune action the target's own `Actions` pipeline
+/// runs will bring the pool back down to `pool_size` on its next generation,
+/// so no separate eviction step is needed here.
+fn migrate<Subject, Actions>(
+    iterators: &mut [GaIterator<Subject, Actions>],
+    topology: MigrationTopology,
+    migration_count: usize,
+) where
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq,
+    Actions: GaAction<Subject = Subject>,
+{
+    let island_count = iterators.len();
+    let outgoing: Vec<Vec<FitnessWrapped<Subject>>> = iterators
+        .iter()
+        .map(|iter| {
+            let mut subjects: Vec<&FitnessWrapped<Subject>> =
+                iter.state().population.subjects.iter().collect();
+            subjects.sort_by(|a, b| {
+                a.fitness()
+                    .partial_cmp(&b.fitness())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            subjects
+                .into_iter()
+                .take(migration_count)
+                .cloned()
+                .collect()
+        })
+        .collect();
+    for (island_index, migrants) in outgoing.into_iter().enumerate() {
+        for target in topology.targets(island_count, island_index) {
+            for migrant in &migrants {
+                iterators[target]
+                    .state_mut()
+                    .population
+                    .add(migrant.clone());
+            }
+        }
+    }
+}
+
+fn is_done<Subject, Actions>(iter: &GaIterator<Subject, Actions>) -> bool
+where
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq,
+    Actions: GaAction<Subject = Subject>,
+{
+    !iter.is_fitness_within_range() || iter.is_fitness_at_target()
+}
+
+/// Runs several independent [`Population`]s ("islands") through the normal
+/// action pipeline, migrating each island's best subjects to its neighbors
+/// (per `runner_options.topology`) every `migration_interval` generations.
+/// All islands share one `ga_options` template (cloned per island via
+/// `Actions: Clone`, the same way [`GeneticAlgorithmOptions`] is already
+/// `Clone` when its `Actions` are), so they start with the same fitness
+/// target/range and action configuration but evolve independently between
+/// migrations. Returns each island's final [`GaIterState`] once every island
+/// has terminated (gone out of range or reached its target).
+#[cfg(not(feature = "parallel"))]
+pub fn run_islands<Subject, Actions>(
+    ga_options: GeneticAlgorithmOptions<Actions>,
+    runner_options: IslandRunnerOptions<Subject>,
+    islands: Vec<Population<Subject>>,
+) -> Vec<GaIterState<Subject>>
+where
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq,
+    Actions: GaAction<Subject = Subject> + Clone,
+{
+    let mut iterators = new_iterators(&ga_options, &runner_options, islands);
+    loop {
+        let mut any_active = false;
+        for iter in iterators.iter_mut() {
+            if is_done(iter) {
+                continue;
+            }
+            any_active = true;
+            iter.next_generation();
+        }
+        if !any_active {
+            break;
+        }
+        maybe_migrate(&mut iterators, &runner_options);
+    }
+    iterators.into_iter().map(GaIterator::into_state).collect()
+}
+
+/// Parallel counterpart of the non-`parallel` [`run_islands`]: each island
+/// advances its generation on the shared rayon pool, since islands don't
+/// touch each other's state except during the (sequential) migration step.
+#[cfg(feature = "parallel")]
+pub fn run_islands<Subject, Actions>(
+    ga_options: GeneticAlgorithmOptions<Actions>,
+    runner_options: IslandRunnerOptions<Subject>,
+    islands: Vec<Population<Subject>>,
+) -> Vec<GaIterState<Subject>>
+where
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq + Send + Sync,
+    Actions: GaAction<Subject = Subject> + Clone + Send,
+{
+    let mut iterators = new_iterators(&ga_options, &runner_options, islands);
+    loop {
+        let any_active = iterators
+            .par_iter_mut()
+            .map(|iter| {
+                if is_done(iter) {
+                    false
+                } else {
+                    iter.next_generation();
+                    true
+                }
+            })
+            .reduce(|| false, |a, b| a || b);
+        if !any_active {
+            break;
+        }
+        maybe_migrate(&mut iterators, &runner_options);
+    }
+    iterators.into_iter().map(GaIterator::into_state).collect()
+}
+
+fn maybe_migrate<Subject, Actions>(
+    iterators: &mut [GaIterator<Subject, Actions>],
+    runner_options: &IslandRunnerOptions<Subject>,
+) where
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq,
+    Actions: GaAction<Subject = Subject>,
+{
+    if runner_options.migration_interval == 0 || runner_options.migration_count == 0 {
+        return;
+    }
+    let Some(generation) = iterators.first().map(|iter| iter.state().context().generation) else {
+        return;
+    };
+    if generation % runner_options.migration_interval != 0 {
+        return;
+    }
+    migrate(iterators, runner_options.topology, runner_options.migration_count);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::{Hash, Hasher};
+
+    use crate::ga::fitness::Fit;
+    use crate::ga::island::{run_islands, IslandRunnerOptions, MigrationTopology};
+    use crate::ga::population::Population;
+    use crate::ga::{GaAction, GaContext, GeneticAlgorithmOptions};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Num(u32);
+
+    impl Fit<f64> for Num {
+        fn measure(&self) -> f64 {
+            self.0 as f64
+        }
+    }
+
+    impl Hash for Num {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.0.hash(state);
+        }
+    }
+    impl Eq for Num {}
+    impl crate::ga::subject::GaSubject for Num {}
+
+    #[derive(Debug, Default, Clone)]
+    struct NoopAction;
+
+    impl GaAction for NoopAction {
+        type Subject = Num;
+        fn perform_action(&self, _context: &GaContext, _population: &mut Population<Num>) {}
+    }
+
+    fn island_of(values: &[u32]) -> Population<Num> {
+        Population {
+            pool_size: values.len(),
+            subjects: values.iter().map(|&v| Num(v).into()).collect(),
+            memory_budget_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_run_islands_terminates_all_islands_out_of_range() {
+        // `NoopAction` never changes a population, so the only way this loop
+        // terminates is the best fitness falling outside `fitness_range` on
+        // the very first generation (it can never reach `target_fitness`
+        // either, since nothing ever changes it).
+        let ga_options = GeneticAlgorithmOptions {
+            fitness_initial_to_target_range: 0.0..0.0,
+            fitness_range: 5.0..10.0,
+            target_fitness_epsilon: 0.0,
+            actions: NoopAction,
+            seed: None,
+        };
+        let islands = vec![island_of(&[1, 2, 3]), island_of(&[4, 5, 6])];
+        let results = run_islands(ga_options, IslandRunnerOptions::default(), islands);
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|state| state.termination_reason().is_some()));
+    }
+
+    #[test]
+    fn test_migration_topology_ring_wraps_around() {
+        assert_eq!(MigrationTopology::Ring.targets(3, 2), vec![0]);
+    }
+
+    #[test]
+    fn test_migration_topology_fully_connected_includes_all_others() {
+        assert_eq!(
+            MigrationTopology::FullyConnected.targets(3, 0),
+            vec![1, 2]
+        );
+    }
+}
@@ -0,0 +1,355 @@
+use std::cell::RefCell;
+use std::fs::{File, OpenOptions};
+use std::hash::Hash;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::ga::fitness::Fitness;
+use crate::ga::population::Population;
+use crate::ga::stats::compute_stats;
+use crate::ga::termination::TerminationReason;
+use crate::ga::{GaAction, GaContext};
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum LogEvent<'a, Subject> {
+    Generation {
+        generation: usize,
+        population_size: usize,
+        stats: crate::ga::stats::PopulationStats,
+    },
+    Snapshot {
+        generation: usize,
+        best_fitness: crate::ga::fitness::Fitness,
+        subject: &'a Subject,
+    },
+}
+
+/// Appends a JSON-lines event per generation to `path`, suitable for
+/// ingestion by external analysis tools: a `generation` event with summary
+/// statistics every call, plus a `snapshot` event with the best subject
+/// every `snapshot_interval` generations (when `Some`).
+///
+/// There's no termination event: `GaAction::perform_action` only runs while
+/// `GaRunner::run_generations` keeps iterating, so a logger driven by an
+/// action never observes the loop ending, only the generations inside it.
+/// Emitting one would need a hook into `GaRunner` itself, which only exposes
+/// `fn` pointers today (see [`crate::ga::ga_runner::GaRunnerOptions`]), not
+/// something that can carry this logger's open file handle.
+pub struct JsonlRunLogger<Subject> {
+    path: PathBuf,
+    snapshot_interval: Option<usize>,
+    writer: RefCell<Option<BufWriter<File>>>,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> JsonlRunLogger<Subject> {
+    pub fn new(path: impl Into<PathBuf>, snapshot_interval: Option<usize>) -> Self {
+        Self {
+            path: path.into(),
+            snapshot_interval,
+            writer: RefCell::new(None),
+            _subject: PhantomData,
+        }
+    }
+
+    fn append_event(&self, event: &LogEvent<Subject>) -> io::Result<()>
+    where
+        Subject: Serialize,
+    {
+        let mut writer_slot = self.writer.borrow_mut();
+        if writer_slot.is_none() {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            *writer_slot = Some(BufWriter::new(file));
+        }
+        let writer = writer_slot.as_mut().expect("writer was just initialized");
+        serde_json::to_writer(&mut *writer, event)?;
+        writer.write_all(b"\n")?;
+        writer.flush()
+    }
+}
+
+macro_rules! impl_record_generation {
+    () => {
+        fn record_generation(&self, context: &GaContext, population: &Population<Subject>) {
+            let Some(stats) = compute_stats(population) else {
+                return;
+            };
+            let event = LogEvent::Generation {
+                generation: context.generation,
+                population_size: population.subjects.len(),
+                stats,
+            };
+            if let Err(err) = self.append_event(&event) {
+                tracing::log::warn!("failed to append JSONL generation event: {err}");
+            }
+
+            let should_snapshot = self
+                .snapshot_interval
+                .map(|interval| interval != 0 && context.generation % interval == 0)
+                .unwrap_or(false);
+            if should_snapshot {
+                if let Some(best) = population.iter().min_by(|a, b| {
+                    a.fitness()
+                        .partial_cmp(&b.fitness())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                }) {
+                    let event = LogEvent::Snapshot {
+                        generation: context.generation,
+                        best_fitness: best.fitness(),
+                        subject: best.subject_ref(),
+                    };
+                    if let Err(err) = self.append_event(&event) {
+                        tracing::log::warn!("failed to append JSONL snapshot event: {err}");
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq + Serialize> JsonlRunLogger<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync + Serialize> JsonlRunLogger<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq + Serialize> GaAction for JsonlRunLogger<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("jsonl_log", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync + Serialize> GaAction for JsonlRunLogger<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("jsonl_log", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ReportEvent {
+    Generation {
+        generation: usize,
+        best_fitness: Fitness,
+        mean_fitness: Fitness,
+        stddev_fitness: Fitness,
+        population_size: usize,
+        elapsed_ms: u128,
+    },
+    Summary {
+        generations: usize,
+        best_fitness: Option<Fitness>,
+        termination_reason: TerminationReason,
+        elapsed_ms: u128,
+    },
+}
+
+/// Appends one JSON-lines `generation` record per generation, plus a final
+/// `summary` record once the run stops, to `path`.
+///
+/// The run-level counterpart to [`JsonlRunLogger`]: that's a [`GaAction`]
+/// and so only ever sees a `&Population`, with no notion of elapsed time, a
+/// reverse-mode-aware best-so-far fitness, or the run ending (its own doc
+/// comment calls out exactly this gap). `JsonlReporter` is instead driven
+/// directly by [`crate::ga::ga_runner::GaRunner`] via
+/// [`crate::ga::ga_runner::GaRunnerOptions::jsonl_report_path`], which has
+/// all three — mirroring [`crate::ga::csv_stats::CsvReporter`]'s
+/// relationship to [`crate::ga::csv_stats::CsvStatsRecorder`]. Not generic
+/// over `Subject`: like `CsvReporter`, it only ever sees fitness values and
+/// counts, never a subject itself.
+pub struct JsonlReporter {
+    path: PathBuf,
+    writer: RefCell<Option<BufWriter<File>>>,
+}
+
+impl JsonlReporter {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            writer: RefCell::new(None),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn report_generation(
+        &self,
+        generation: usize,
+        best_fitness: Fitness,
+        mean_fitness: Fitness,
+        stddev_fitness: Fitness,
+        population_size: usize,
+        elapsed: Duration,
+    ) {
+        let event = ReportEvent::Generation {
+            generation,
+            best_fitness,
+            mean_fitness,
+            stddev_fitness,
+            population_size,
+            elapsed_ms: elapsed.as_millis(),
+        };
+        if let Err(err) = self.append_event(&event) {
+            tracing::log::warn!("failed to append JSONL generation event: {err}");
+        }
+    }
+
+    pub fn report_summary(
+        &self,
+        generations: usize,
+        best_fitness: Option<Fitness>,
+        termination_reason: TerminationReason,
+        elapsed: Duration,
+    ) {
+        let event = ReportEvent::Summary {
+            generations,
+            best_fitness,
+            termination_reason,
+            elapsed_ms: elapsed.as_millis(),
+        };
+        if let Err(err) = self.append_event(&event) {
+            tracing::log::warn!("failed to append JSONL summary event: {err}");
+        }
+    }
+
+    fn append_event(&self, event: &ReportEvent) -> io::Result<()> {
+        let mut writer_slot = self.writer.borrow_mut();
+        if writer_slot.is_none() {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)?;
+            *writer_slot = Some(BufWriter::new(file));
+        }
+        let writer = writer_slot.as_mut().expect("writer was just initialized");
+        serde_json::to_writer(&mut *writer, event)?;
+        writer.write_all(b"\n")?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::fitness::FitnessWrapped;
+    use crate::ga::jsonl_log::JsonlRunLogger;
+    use crate::ga::population::Population;
+    use crate::ga::{GaAction, GaContext};
+
+    fn population_of(fitnesses: &[u32]) -> Population<u32> {
+        Population {
+            pool_size: fitnesses.len(),
+            subjects: fitnesses
+                .iter()
+                .map(|&f| FitnessWrapped::new(f, f as f64))
+                .collect(),
+            memory_budget_bytes: None,
+        }
+    }
+
+    fn read_lines(path: &std::path::Path) -> Vec<serde_json::Value> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_writes_generation_event_every_call() {
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_jsonl_log_test_{:?}.jsonl",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let logger = JsonlRunLogger::new(&path, None);
+        logger.perform_action(&GaContext::new(0), &mut population_of(&[1, 2, 3]));
+        logger.perform_action(&GaContext::new(1), &mut population_of(&[4, 5, 6]));
+
+        let events = read_lines(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0]["type"], "generation");
+        assert_eq!(events[1]["generation"], 1);
+    }
+
+    #[test]
+    fn test_writes_snapshot_event_on_interval() {
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_jsonl_log_snapshot_test_{:?}.jsonl",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let logger = JsonlRunLogger::new(&path, Some(2));
+        logger.perform_action(&GaContext::new(0), &mut population_of(&[3, 1, 2]));
+        logger.perform_action(&GaContext::new(1), &mut population_of(&[3, 1, 2]));
+        logger.perform_action(&GaContext::new(2), &mut population_of(&[3, 1, 2]));
+
+        let events = read_lines(&path);
+        std::fs::remove_file(&path).unwrap();
+        let snapshot_generations: Vec<i64> = events
+            .iter()
+            .filter(|event| event["type"] == "snapshot")
+            .map(|event| event["generation"].as_i64().unwrap())
+            .collect();
+        assert_eq!(snapshot_generations, vec![0, 2]);
+        let snapshot = events
+            .iter()
+            .find(|event| event["type"] == "snapshot")
+            .unwrap();
+        assert_eq!(snapshot["best_fitness"], 1.0);
+        assert_eq!(snapshot["subject"], 1);
+    }
+
+    #[test]
+    fn test_jsonl_reporter_writes_a_generation_record_per_call_and_a_final_summary() {
+        use crate::ga::jsonl_log::JsonlReporter;
+        use crate::ga::termination::TerminationReason;
+        use std::time::Duration;
+
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_jsonl_reporter_test_{:?}.jsonl",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let reporter = JsonlReporter::new(&path);
+        reporter.report_generation(0, 1.0, 2.0, 0.5, 3, Duration::from_millis(10));
+        reporter.report_generation(1, 0.5, 1.5, 0.25, 3, Duration::from_millis(20));
+        reporter.report_summary(2, Some(0.5), TerminationReason::TargetReached, Duration::from_millis(20));
+
+        let events = read_lines(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0]["type"], "generation");
+        assert_eq!(events[1]["generation"], 1);
+        assert_eq!(events[2]["type"], "summary");
+        assert_eq!(events[2]["generations"], 2);
+        assert_eq!(events[2]["best_fitness"], 0.5);
+    }
+}
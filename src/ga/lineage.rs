@@ -0,0 +1,195 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
+
+use crate::ga::fitness::FitnessWrapped;
+
+/// Opaque identifier for a subject in a [`LineageGraph`].
+/// [`FitnessWrapped::new`]/[`FitnessWrapped::new_with_parentage`] give every
+/// wrapped subject a fresh one, and [`Genealogy`] records them into a graph
+/// automatically. A caller building a [`LineageGraph`] by hand can use any
+/// scheme of its own (a counter, a UUID, a content hash) and call
+/// [`LineageGraph::record`] as each child is created.
+pub type LineageId = u64;
+
+/// One node's recorded ancestry: which operator produced it, and from which
+/// parent(s) (empty for an initial-population member).
+#[derive(Debug, Clone)]
+pub struct LineageRecord {
+    pub operator: String,
+    pub parents: Vec<LineageId>,
+}
+
+/// An in-memory ancestry DAG, built up by calling [`Self::record`] once per
+/// subject as it's created, so [`write_dot`] can trace a node's ancestors
+/// back to the initial population.
+#[derive(Debug, Default)]
+pub struct LineageGraph {
+    records: BTreeMap<LineageId, LineageRecord>,
+}
+
+impl LineageGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that `id` was produced by `operator` from `parents`.
+    pub fn record(&mut self, id: LineageId, operator: impl Into<String>, parents: Vec<LineageId>) {
+        self.records.insert(
+            id,
+            LineageRecord {
+                operator: operator.into(),
+                parents,
+            },
+        );
+    }
+
+    /// Walks backwards from `root` collecting every ancestor reachable
+    /// through recorded parent links, including `root` itself. Ancestors
+    /// with no recorded entry (the edge of what was tracked) are omitted.
+    fn ancestors_of(&self, root: LineageId) -> BTreeMap<LineageId, &LineageRecord> {
+        let mut visited = BTreeMap::new();
+        let mut stack = vec![root];
+        while let Some(id) = stack.pop() {
+            if visited.contains_key(&id) {
+                continue;
+            }
+            let Some(record) = self.records.get(&id) else {
+                continue;
+            };
+            stack.extend(record.parents.iter().copied());
+            visited.insert(id, record);
+        }
+        visited
+    }
+}
+
+/// Auto-populated counterpart to [`LineageGraph`]: rather than a caller
+/// having to assign and thread [`LineageId`]s through their own actions,
+/// [`Self::observe`] scans a generation's subjects and records any id/parents
+/// pair it hasn't seen yet directly from [`FitnessWrapped::id`]/
+/// [`FitnessWrapped::parents`]/[`FitnessWrapped::created_by`] — the fields
+/// [`crate::ga::mutation::mutate_one`]/[`crate::ga::reproduction::apply_reproductions`]
+/// already populate via [`FitnessWrapped::new_with_parentage`]. Lives on
+/// [`crate::ga::ga_iterator::GaIterState`], updated once per generation
+/// while [`crate::ga::ga_iterator::GaIterOptions::track_genealogy`] is set.
+#[derive(Debug, Default)]
+pub struct Genealogy {
+    graph: LineageGraph,
+    seen: HashSet<LineageId>,
+}
+
+impl Genealogy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The underlying DAG, for [`write_dot`] or direct ancestry queries.
+    pub fn graph(&self) -> &LineageGraph {
+        &self.graph
+    }
+
+    /// Records any subject in `subjects` whose id hasn't been observed yet.
+    /// Subjects built via [`FitnessWrapped::new`] (no recorded operator) are
+    /// recorded with an empty parent list and the operator `"unknown"`,
+    /// matching an initial-population member.
+    pub fn observe<Subject>(&mut self, subjects: &[FitnessWrapped<Subject>]) {
+        for wrapped in subjects {
+            let id = wrapped.id();
+            if self.seen.insert(id) {
+                self.graph
+                    .record(id, wrapped.created_by().unwrap_or("unknown"), wrapped.parents().to_vec());
+            }
+        }
+    }
+}
+
+/// Renders the ancestry DAG of `roots` (typically the final best subject, or
+/// every subject in a hall of fame) as Graphviz DOT: one node per subject
+/// labeled with its id, one edge per parent -> child link labeled with the
+/// operator that produced the child, for visual analysis of how a solution
+/// was constructed.
+pub fn write_dot(graph: &LineageGraph, roots: &[LineageId]) -> String {
+    let mut nodes: BTreeMap<LineageId, &LineageRecord> = BTreeMap::new();
+    for &root in roots {
+        nodes.extend(graph.ancestors_of(root));
+    }
+
+    let mut dot = String::from("digraph lineage {\n");
+    for &id in nodes.keys() {
+        let _ = writeln!(dot, "    \"{id}\";");
+    }
+    for (&id, record) in &nodes {
+        for &parent in &record.parents {
+            let _ = writeln!(
+                dot,
+                "    \"{parent}\" -> \"{id}\" [label=\"{}\"];",
+                record.operator
+            );
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::fitness::FitnessWrapped;
+    use crate::ga::lineage::{write_dot, Genealogy, LineageGraph};
+
+    #[test]
+    fn test_genealogy_observe_records_parentage_from_fitness_wrapped() {
+        let mut genealogy = Genealogy::new();
+        let parent = FitnessWrapped::new(1, 1.0);
+        let child = FitnessWrapped::new_with_parentage(2, 2.0, vec![parent.id()], "crossover");
+        let child_id = child.id();
+        genealogy.observe(&[parent.clone(), child]);
+
+        let dot = write_dot(genealogy.graph(), &[child_id]);
+        assert!(dot.contains(&format!("\"{}\" -> \"{child_id}\" [label=\"crossover\"];", parent.id())));
+    }
+
+    #[test]
+    fn test_genealogy_observe_ignores_already_seen_ids() {
+        let mut genealogy = Genealogy::new();
+        let subject = FitnessWrapped::new(1, 1.0);
+        genealogy.observe(std::slice::from_ref(&subject));
+        genealogy.observe(std::slice::from_ref(&subject));
+        assert_eq!(genealogy.graph().records.len(), 1);
+    }
+
+    #[test]
+    fn test_write_dot_includes_ancestors_and_operator_labels() {
+        let mut graph = LineageGraph::new();
+        graph.record(1, "initial", vec![]);
+        graph.record(2, "initial", vec![]);
+        graph.record(3, "crossover", vec![1, 2]);
+
+        let dot = write_dot(&graph, &[3]);
+
+        assert!(dot.starts_with("digraph lineage {"));
+        assert!(dot.contains("\"1\";"));
+        assert!(dot.contains("\"2\";"));
+        assert!(dot.contains("\"3\";"));
+        assert!(dot.contains("\"1\" -> \"3\" [label=\"crossover\"];"));
+        assert!(dot.contains("\"2\" -> \"3\" [label=\"crossover\"];"));
+    }
+
+    #[test]
+    fn test_write_dot_omits_untracked_nodes() {
+        let graph = LineageGraph::new();
+        let dot = write_dot(&graph, &[42]);
+        assert_eq!(dot, "digraph lineage {\n}\n");
+    }
+
+    #[test]
+    fn test_write_dot_unions_multiple_roots() {
+        let mut graph = LineageGraph::new();
+        graph.record(1, "initial", vec![]);
+        graph.record(2, "initial", vec![]);
+
+        let dot = write_dot(&graph, &[1, 2]);
+
+        assert!(dot.contains("\"1\";"));
+        assert!(dot.contains("\"2\";"));
+    }
+}
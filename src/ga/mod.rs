@@ -1,3 +1,4 @@
+use std::sync::{Mutex, MutexGuard};
 use std::hash::{Hash, Hasher};
 use std::ops::Range;
 use std::usize;
@@ -5,24 +6,80 @@ use std::usize;
 use derivative::Derivative;
 use rand::distributions::WeightedIndex;
 use rand::prelude::Distribution;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 use crate::ga::fitness::{Fit, Fitness, FitnessWrapped};
 use crate::ga::population::Population;
 use crate::util::{coin_flip, rng, Odds};
 
 pub mod action;
+pub mod annealing;
+#[cfg(feature = "arena")]
+pub mod arena;
+pub mod archive;
+#[cfg(feature = "async")]
+pub mod async_runner;
+pub mod bandit;
+
+pub mod cellular;
+pub mod cma_es;
+#[cfg(feature = "checkpoint")]
+pub mod checkpoint;
+#[cfg(feature = "codec-json")]
+pub mod codec;
+pub mod coevolution;
+pub mod csv_stats;
 pub mod dedupe;
+pub mod differential_evolution;
+pub mod diversity;
+pub mod elite;
+pub mod event;
 pub mod fitness;
 pub mod ga_iterator;
 pub mod ga_runner;
+pub mod genome;
+#[cfg(feature = "golden-trace")]
+pub mod golden_trace;
+pub mod hall_of_fame;
 pub mod inflate;
+pub mod interactive;
+pub mod island;
+#[cfg(feature = "jsonl-log")]
+pub mod jsonl_log;
+pub mod lineage;
+pub mod multi_objective;
 pub mod mutation;
+pub mod niching;
+pub mod operator_stats;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+pub mod pipeline;
+#[cfg(feature = "plot")]
+pub mod plot;
 pub mod population;
 pub mod probability;
+pub mod progress;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
 pub mod prune;
+#[cfg(feature = "remote-control")]
+pub mod remote_control;
+pub mod repair;
 pub mod reproduction;
 pub mod select;
+pub mod species;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod stats;
+pub mod steady_state;
 pub mod subject;
+pub mod termination;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod tuning;
 
 #[derive(Derivative, Clone)]
 #[derivative(Debug)]
@@ -32,6 +89,7 @@ pub struct CreatePopulationOptions<SubjectFn> {
     pub create_subject_fn: SubjectFn,
 }
 
+#[cfg(not(feature = "parallel"))]
 pub fn create_population_pool<Subject: Fit<Fitness>>(
     options: CreatePopulationOptions<impl Fn(&GaContext) -> Subject>,
 ) -> Population<Subject> {
@@ -44,6 +102,32 @@ pub fn create_population_pool<Subject: Fit<Fitness>>(
     Population {
         subjects,
         pool_size: options.population_size,
+        memory_budget_bytes: None,
+    }
+}
+
+/// Creates each subject (and evaluates its initial fitness) in parallel via
+/// rayon, mirroring [`crate::ga::mutation::apply_mutations`]'s parallel
+/// variant. `create_subject_fn` only ever sees a fresh, per-call
+/// [`GaContext`] (generation `0`) rather than one shared, mutated context,
+/// since a `&mut GaContext` threaded through a parallel fan-out can't
+/// observe a single running sequence the way the serial variant's loop can.
+#[cfg(feature = "parallel")]
+pub fn create_population_pool<Subject: Fit<Fitness> + Send + Sync>(
+    options: CreatePopulationOptions<impl Fn(&GaContext) -> Subject + Sync>,
+) -> Population<Subject> {
+    let subjects: Vec<FitnessWrapped<Subject>> = (0..options.population_size)
+        .into_par_iter()
+        .map(|_| {
+            let context = GaContext::default();
+            let subject = (options.create_subject_fn)(&context);
+            FitnessWrapped::from(subject)
+        })
+        .collect();
+    Population {
+        subjects,
+        pool_size: options.population_size,
+        memory_budget_bytes: None,
     }
 }
 
@@ -67,8 +151,20 @@ pub struct WeightedActionsSampleAll<Action>(pub Vec<WeightedAction<Action>>);
 impl<Action: Clone> SampleSelf for WeightedActionsSampleOne<Action> {
     type Output = Vec<Action>;
     fn sample_self(&self) -> Self::Output {
+        self.try_sample_self().expect("Weights/Odds should not be all zero")
+    }
+}
+
+impl<Action: Clone> WeightedActionsSampleOne<Action> {
+    /// Fallible counterpart to [`SampleSelf::sample_self`] for callers that
+    /// can't tolerate a panic when every action's weight is `0.0` (or the
+    /// list is empty) — e.g. weights computed from live
+    /// [`crate::ga::operator_stats::OperatorStats`] that can all decay to
+    /// zero. Returns [`crate::error::Error::EmptyWeights`] instead of
+    /// panicking in that case.
+    pub fn try_sample_self(&self) -> crate::error::Result<Vec<Action>> {
         if self.0.is_empty() {
-            return vec![];
+            return Ok(vec![]);
         }
         let rng = &mut rng::thread_rng();
         let weights: Vec<f64> = self
@@ -76,9 +172,9 @@ impl<Action: Clone> SampleSelf for WeightedActionsSampleOne<Action> {
             .iter()
             .map(|weighted_action| weighted_action.weight)
             .collect();
-        let dist = WeightedIndex::new(weights).expect("Weights/Odds should not be all zero");
+        let dist = WeightedIndex::new(weights).map_err(|_| crate::error::Error::EmptyWeights)?;
         let index = dist.sample(rng);
-        vec![self.0[index].action.clone()]
+        Ok(vec![self.0[index].action.clone()])
     }
 }
 
@@ -104,6 +200,7 @@ impl<Action: Clone> SampleSelf for WeightedActionsSampleAll<Action> {
 }
 
 #[derive(Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WeightedAction<Action> {
     pub action: Action,
     pub weight: Odds,
@@ -136,12 +233,33 @@ impl<Action> From<(Action, Odds)> for WeightedAction<Action> {
 
 #[derive(Derivative, Clone, Default)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GeneticAlgorithmOptions<Actions> {
     /// initial fitness to target fitness
     pub fitness_initial_to_target_range: Range<Fitness>,
     /// min and max fitness range to terminate the loop
     pub fitness_range: Range<Fitness>,
+    /// How close `current_fitness` must get to [`Self::target_fitness`] for
+    /// [`crate::ga::ga_iterator::GaIterator::is_fitness_at_target`] to report
+    /// done, checked as `(current_fitness - target_fitness).abs() <=
+    /// target_fitness_epsilon`. Defaults to `0.0`, i.e. exact equality,
+    /// which is what continuous-fitness problems (see
+    /// [`crate::ga::genome::real_vector`]) almost never hit; set this to a
+    /// small tolerance (e.g. `1e-9`) for those instead of relying on the
+    /// float landing on the target bit-for-bit.
+    pub target_fitness_epsilon: Fitness,
     pub actions: Actions,
+    /// Seeds the run's [`GaContext::rng`] and, on the thread that calls
+    /// [`crate::ga::ga_runner::GaRunner::run`], `crate::util::rng::thread_rng()`
+    /// (via `crate::util::rng::seed_thread_rng`), so a published seed
+    /// reproduces the exact sequence of mutation, reproduction, selection,
+    /// and coin-flip/biased-index decisions the run made. `None` leaves both
+    /// OS-seeded, as before. [`crate::ga::ga_runner::GaRunner::resume`] does
+    /// not reseed the `GaContext` carried by the `GaIterState` it continues;
+    /// under the `parallel` feature, with no `GaRunnerOptions::thread_pool`
+    /// set, it still uses this seed to seed each rayon worker's
+    /// `crate::util::rng::thread_rng()`.
+    pub seed: Option<u64>,
 }
 
 impl<Actions> GeneticAlgorithmOptions<Actions> {
@@ -153,12 +271,165 @@ impl<Actions> GeneticAlgorithmOptions<Actions> {
     }
 }
 
-#[derive(Debug, Default)]
+/// Per-run mutable state threaded through the action pipeline. `rng` is the
+/// seedable RNG that [`crate::ga::mutation::ApplyMutation::apply`],
+/// [`crate::ga::reproduction::ApplyReproduction::apply`], create-subject
+/// functions (see [`CreatePopulationOptions`]), and
+/// [`crate::ga::select::SelectOther`]/[`crate::ga::select::SelectOtherRandom`]
+/// draw from, in place of the free-standing `crate::util::rng::thread_rng()`
+/// handles those used to create individually — sharing one `GaContext` per
+/// run (rather than one `ThreadRng` per call site) is what makes a
+/// **sequential** run reproducible from a seed. Held behind a `Mutex`
+/// (rather than a lighter `RefCell`) so `.rng()` can hand out `&mut` access
+/// through the `&GaContext` these call sites already receive without a
+/// breaking change to their signatures, while keeping `GaContext` itself
+/// `Sync` for the `parallel` feature's rayon closures.
+///
+/// That `Sync`-via-`Mutex` access is a single shared stream, though: under
+/// `parallel`, every rayon worker calling `.rng()` from
+/// `par_iter_mut`/`par_extend` (see [`crate::ga::mutation::apply_mutations`],
+/// [`crate::ga::reproduction::apply_reproductions`]) contends on the same
+/// lock, and which worker's draw lands first is decided by OS scheduling,
+/// not the seed. A `parallel` run with a seed still terminates and is a
+/// valid run, but is **not** guaranteed to reproduce the same sequence of
+/// mutation/reproduction/selection draws (or even the same result) across
+/// runs — only a non-`parallel` run gets that guarantee. Reproducible
+/// parallel draws would need a per-worker sub-streamed `GaContext` instead
+/// of one shared instance, which this crate doesn't do (yet).
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GaContext {
     pub generation: usize,
+    #[cfg_attr(feature = "serde", serde(skip, default = "GaContext::fresh_rng"))]
+    rng: Mutex<StdRng>,
+}
+
+impl GaContext {
+    pub fn new(generation: usize) -> Self {
+        Self {
+            generation,
+            rng: Self::fresh_rng(),
+        }
+    }
+
+    /// Like [`Self::new`], but seeds `rng` from `seed` instead of OS entropy,
+    /// so a [`GeneticAlgorithmOptions::seed`] run reproduces the same sequence
+    /// of mutation/reproduction/selection draws every time.
+    pub fn with_seed(generation: usize, seed: u64) -> Self {
+        Self {
+            generation,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+
+    fn fresh_rng() -> Mutex<StdRng> {
+        Mutex::new(StdRng::from_entropy())
+    }
+
+    /// Mutable access to this context's RNG, e.g. `context.rng().gen_range(0..n)`.
+    pub fn rng(&self) -> MutexGuard<'_, StdRng> {
+        self.rng.lock().expect("GaContext's RNG mutex was poisoned by a panicking holder")
+    }
+}
+
+impl Clone for GaContext {
+    fn clone(&self) -> Self {
+        Self {
+            generation: self.generation,
+            rng: Mutex::new(self.rng().clone()),
+        }
+    }
+}
+
+impl Default for GaContext {
+    fn default() -> Self {
+        Self::new(0)
+    }
 }
 
 pub trait GaAction {
     type Subject;
     fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>);
 }
+
+/// Wraps a [`GaAction`]'s body in a tracing span carrying the structured
+/// fields a subscriber needs to make sense of a run: which action, which
+/// generation, how the population size changed, and how long it took.
+/// Every crate-provided `GaAction` calls this instead of logging ad-hoc
+/// strings, so `tracing-subscriber`/OpenTelemetry backends get real fields
+/// rather than having to parse them back out of a message.
+pub(crate) fn instrument_action<Subject>(
+    action: &'static str,
+    context: &GaContext,
+    population: &mut Population<Subject>,
+    perform: impl FnOnce(&mut Population<Subject>),
+) {
+    let span = tracing::info_span!(
+        "ga_action",
+        action,
+        generation = context.generation,
+        population_size_before = tracing::field::Empty,
+        population_size_after = tracing::field::Empty,
+        subjects_delta = tracing::field::Empty,
+        duration_ms = tracing::field::Empty,
+    );
+    let _enter = span.enter();
+    let population_size_before = population.subjects.len();
+    span.record("population_size_before", population_size_before);
+    let start = std::time::Instant::now();
+    perform(population);
+    let population_size_after = population.subjects.len();
+    span.record("population_size_after", population_size_after);
+    span.record(
+        "subjects_delta",
+        population_size_after as i64 - population_size_before as i64,
+    );
+    span.record("duration_ms", start.elapsed().as_secs_f64() * 1000.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::GaContext;
+    #[cfg(feature = "serde")]
+    use crate::ga::{GeneticAlgorithmOptions, WeightedAction};
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_weighted_action_serde_round_trip() {
+        let action = WeightedAction {
+            action: 42,
+            weight: 0.5,
+        };
+        let json = serde_json::to_string(&action).unwrap();
+        let round_tripped: WeightedAction<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped.action, action.action);
+        assert_eq!(round_tripped.weight, action.weight);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_genetic_algorithm_options_serde_round_trip() {
+        let options = GeneticAlgorithmOptions {
+            fitness_initial_to_target_range: 0.0..100.0,
+            fitness_range: 0.0..200.0,
+            target_fitness_epsilon: 0.0,
+            actions: 7,
+            seed: Some(42),
+        };
+        let json = serde_json::to_string(&options).unwrap();
+        let round_tripped: GeneticAlgorithmOptions<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_tripped.target_fitness(), options.target_fitness());
+        assert_eq!(round_tripped.actions, options.actions);
+        assert_eq!(round_tripped.seed, options.seed);
+    }
+
+    #[test]
+    fn test_context_with_seed_is_reproducible() {
+        let draw = |seed: u64| -> f64 {
+            use rand::Rng;
+            GaContext::with_seed(0, seed).rng().gen()
+        };
+        assert_eq!(draw(42), draw(42));
+        assert_ne!(draw(1), draw(2));
+    }
+}
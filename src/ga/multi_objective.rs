@@ -0,0 +1,190 @@
+//! Multi-objective fitness support: NSGA-II non-dominated sorting and
+//! crowding distance, for subjects judged along more than one objective.
+//!
+//! `Population`/`FitnessWrapped` are generic over a single scalar
+//! [`crate::ga::fitness::Fitness`] throughout the crate — sorting, pruning,
+//! and selection all compare two `f64`s — so making `Population<Subject>`
+//! itself natively multi-objective would mean every one of those call sites
+//! learning to compare [`MultiFitness`] vectors instead: a breaking change
+//! to a core type, not a new module. What's provided here is the NSGA-II
+//! ranking pipeline itself: run [`rank_and_crowding`] over your
+//! `MultiFitness` vector to get a front rank and crowding distance per
+//! subject, then fold that into a single scalar `Fitness` with
+//! [`scalarize`] (lower rank first, broken by higher crowding distance) to
+//! assign via your own `Fit`/`FitBatch` impl — exactly the shape
+//! `GenericReproducer`/`PruneAction`/`ga::select` already expect.
+
+use crate::ga::fitness::Fitness;
+
+/// A subject's score along every objective. All objectives are assumed to
+/// be minimized, matching this crate's single-objective convention.
+pub type MultiFitness = Vec<f64>;
+
+/// Whether `a` Pareto-dominates `b`: at least as good in every objective,
+/// and strictly better in at least one.
+pub fn dominates(a: &[f64], b: &[f64]) -> bool {
+    let mut strictly_better_in_one = false;
+    for (&a_objective, &b_objective) in a.iter().zip(b.iter()) {
+        if a_objective > b_objective {
+            return false;
+        }
+        if a_objective < b_objective {
+            strictly_better_in_one = true;
+        }
+    }
+    strictly_better_in_one
+}
+
+/// Splits `objectives` into Pareto fronts: front 0 is the non-dominated set,
+/// front 1 is what's non-dominated once front 0 is removed, and so on.
+/// Returned fronts hold indices into `objectives`.
+pub fn fast_non_dominated_sort(objectives: &[MultiFitness]) -> Vec<Vec<usize>> {
+    let n = objectives.len();
+    let mut domination_count = vec![0usize; n];
+    let mut dominated_by_p: Vec<Vec<usize>> = vec![vec![]; n];
+    let mut fronts: Vec<Vec<usize>> = vec![vec![]];
+
+    for p in 0..n {
+        for q in 0..n {
+            if p == q {
+                continue;
+            }
+            if dominates(&objectives[p], &objectives[q]) {
+                dominated_by_p[p].push(q);
+            } else if dominates(&objectives[q], &objectives[p]) {
+                domination_count[p] += 1;
+            }
+        }
+        if domination_count[p] == 0 {
+            fronts[0].push(p);
+        }
+    }
+
+    let mut i = 0;
+    while !fronts[i].is_empty() {
+        let mut next_front = vec![];
+        for &p in &fronts[i] {
+            for &q in &dominated_by_p[p] {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next_front.push(q);
+                }
+            }
+        }
+        i += 1;
+        fronts.push(next_front);
+    }
+    fronts.pop(); // trailing empty front left by the loop's termination check
+    fronts
+}
+
+/// Per-member crowding distance within a single front: how isolated a
+/// member is from its neighbors along each objective, summed. Boundary
+/// members (best/worst in any objective) get `f64::INFINITY` so they're
+/// always preferred, preserving the extremes of the front.
+pub fn crowding_distance(front: &[usize], objectives: &[MultiFitness]) -> Vec<f64> {
+    let n = front.len();
+    if n == 0 {
+        return vec![];
+    }
+    let num_objectives = objectives[front[0]].len();
+    let mut distance = vec![0.0; n];
+    // `objective` indexes a different container on every use below (`front`,
+    // `order`, `objectives[front[..]]`), not a single one clippy's suggested
+    // iterator rewrite could stand in for.
+    #[allow(clippy::needless_range_loop)]
+    for objective in 0..num_objectives {
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| {
+            objectives[front[a]][objective]
+                .partial_cmp(&objectives[front[b]][objective])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        distance[order[0]] = f64::INFINITY;
+        distance[order[n - 1]] = f64::INFINITY;
+        let min = objectives[front[order[0]]][objective];
+        let max = objectives[front[order[n - 1]]][objective];
+        let range = max - min;
+        if range == 0.0 {
+            continue;
+        }
+        for w in 1..n.saturating_sub(1) {
+            let prev = objectives[front[order[w - 1]]][objective];
+            let next = objectives[front[order[w + 1]]][objective];
+            distance[order[w]] += (next - prev) / range;
+        }
+    }
+    distance
+}
+
+/// Runs the full NSGA-II ranking pipeline, returning `(rank, crowding_distance)`
+/// per individual, indexed the same as `objectives` (rank `0` is the best
+/// front).
+pub fn rank_and_crowding(objectives: &[MultiFitness]) -> Vec<(usize, f64)> {
+    let fronts = fast_non_dominated_sort(objectives);
+    let mut result = vec![(0usize, 0.0); objectives.len()];
+    for (rank, front) in fronts.iter().enumerate() {
+        let distances = crowding_distance(front, objectives);
+        for (&index, &distance) in front.iter().zip(distances.iter()) {
+            result[index] = (rank, distance);
+        }
+    }
+    result
+}
+
+/// Folds an NSGA-II `(rank, crowding_distance)` pair into a single scalar
+/// `Fitness`, ordering by rank first (lower is better) and, within a rank,
+/// by crowding distance (higher is better).
+pub fn scalarize(rank: usize, crowding_distance: f64) -> Fitness {
+    // stays within (rank, rank + 0.5], so even an infinite-distance boundary
+    // member never ties the next-better rank
+    rank as Fitness + 1.0 / (2.0 + crowding_distance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dominates_requires_at_least_as_good_in_every_objective() {
+        assert!(dominates(&[1.0, 1.0], &[2.0, 2.0]));
+        assert!(!dominates(&[1.0, 2.0], &[2.0, 1.0])); // each better in a different objective
+        assert!(!dominates(&[1.0, 1.0], &[1.0, 1.0])); // equal, not strictly better in any
+    }
+
+    #[test]
+    fn test_fast_non_dominated_sort_separates_fronts() {
+        let objectives: Vec<MultiFitness> = vec![
+            vec![0.0, 3.0], // front 0
+            vec![1.0, 1.0], // front 0
+            vec![3.0, 0.0], // front 0
+            vec![2.0, 2.0], // dominated by (1,1)
+        ];
+        let fronts = fast_non_dominated_sort(&objectives);
+        assert_eq!(fronts[0].len(), 3);
+        assert_eq!(fronts[1], vec![3]);
+    }
+
+    #[test]
+    fn test_crowding_distance_marks_boundaries_as_infinite() {
+        let objectives: Vec<MultiFitness> = vec![vec![0.0], vec![1.0], vec![2.0]];
+        let distances = crowding_distance(&[0, 1, 2], &objectives);
+        assert_eq!(distances[0], f64::INFINITY);
+        assert_eq!(distances[2], f64::INFINITY);
+        assert!(distances[1].is_finite());
+    }
+
+    #[test]
+    fn test_scalarize_orders_by_rank_before_crowding_distance() {
+        let best_of_rank_1 = scalarize(1, f64::INFINITY);
+        let worst_of_rank_0 = scalarize(0, 0.0);
+        assert!(worst_of_rank_0 < best_of_rank_1);
+    }
+
+    #[test]
+    fn test_scalarize_prefers_more_crowding_distance_within_a_rank() {
+        let isolated = scalarize(0, 10.0);
+        let crowded = scalarize(0, 0.0);
+        assert!(isolated < crowded);
+    }
+}
@@ -1,8 +1,14 @@
+pub mod operators;
+
+use std::cell::RefCell;
 use std::marker::PhantomData;
+use std::num::NonZeroUsize;
 
 use derivative::Derivative;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-use crate::ga::fitness::{Fitness, FitnessWrapped};
+use crate::ga::fitness::{wrap_batch, FitBatch, Fitness, FitnessWrapped};
 use crate::ga::population::Population;
 use crate::ga::subject::GaSubject;
 use crate::ga::{GaAction, GaContext, SampleSelf};
@@ -13,6 +19,8 @@ pub struct GenericMutator<Mutator, Subject, Actions> {
     _subject: PhantomData<Subject>,
     _mutator: PhantomData<Mutator>,
     options: ApplyMutationOptions<Actions>,
+    // reused across generations to avoid a fresh Vec allocation per `perform_action` call
+    appended_scratch: RefCell<Vec<FitnessWrapped<Subject>>>,
 }
 
 impl<Mutator, Subject, Actions> GenericMutator<Mutator, Subject, Actions> {
@@ -21,6 +29,7 @@ impl<Mutator, Subject, Actions> GenericMutator<Mutator, Subject, Actions> {
             _subject: PhantomData,
             _mutator: PhantomData,
             options,
+            appended_scratch: RefCell::new(Vec::new()),
         }
     }
 }
@@ -38,11 +47,18 @@ where
 
 #[derive(Derivative, Clone, Default)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ApplyMutationOptions<Actions> {
     pub overall_mutation_chance: Odds,
     #[derivative(Debug = "ignore")]
     pub mutation_actions: Actions,
     pub clone_on_mutation: bool,
+    /// Processes subjects in fixed-size, contiguous chunks instead of one
+    /// arbitrary-order pass, for cache-friendlier access to large subjects.
+    /// Under the `parallel` feature this also becomes rayon's minimum split
+    /// length, keeping each worker's slice a contiguous run. `None` leaves
+    /// the access pattern unchunked.
+    pub chunk_size: Option<NonZeroUsize>,
 }
 
 pub trait ApplyMutation {
@@ -51,6 +67,32 @@ pub trait ApplyMutation {
     fn fitness(subject: &Self::Subject) -> Fitness;
 }
 
+fn mutate_one<Subject, Mutator: ApplyMutation<Subject = Subject>, Actions: SampleSelf<Output = Vec<Mutator>>>(
+    context: &GaContext,
+    wrapped_subject: &mut FitnessWrapped<Subject>,
+    options: &ApplyMutationOptions<Actions>,
+) -> Vec<FitnessWrapped<Subject>> {
+    let mut appended_subjects = vec![];
+    if !coin_flip(options.overall_mutation_chance) {
+        return appended_subjects;
+    }
+    for mutator in options.mutation_actions.sample_self().iter() {
+        let parent_id = wrapped_subject.id();
+        let subject = wrapped_subject.subject_ref();
+        let mutated_subject = mutator.apply(context, subject);
+        let fitness = Mutator::fitness(&mutated_subject);
+        let mut fw = FitnessWrapped::new_with_parentage(mutated_subject, fitness, vec![parent_id], std::any::type_name::<Mutator>());
+        fw.set_generation_born(context.generation);
+        if options.clone_on_mutation {
+            appended_subjects.push(fw);
+        } else {
+            *wrapped_subject = fw;
+        }
+    }
+    appended_subjects
+}
+
+#[cfg(not(feature = "parallel"))]
 pub fn apply_mutations<
     Subject,
     Mutator: ApplyMutation<Subject = Subject>,
@@ -59,35 +101,215 @@ pub fn apply_mutations<
     context: &GaContext,
     population: &mut Population<Subject>,
     options: &ApplyMutationOptions<Actions>,
+    appended_scratch: &mut Vec<FitnessWrapped<Subject>>,
 ) {
-    let mut appended_subjects = vec![];
-    for wrapped_subject in population.subjects.iter_mut() {
-        if !coin_flip(options.overall_mutation_chance) {
-            continue;
-        }
-        for mutator in options.mutation_actions.sample_self().iter() {
-            let subject = &wrapped_subject.subject();
-            let mutated_subject = mutator.apply(context, subject);
-            let fitness = Mutator::fitness(&mutated_subject);
-            let fw = FitnessWrapped::new(mutated_subject, fitness);
-            if options.clone_on_mutation {
-                appended_subjects.push(fw);
-            } else {
-                *wrapped_subject = fw;
+    appended_scratch.clear();
+    match options.chunk_size {
+        Some(chunk_size) => {
+            for chunk in population.subjects.chunks_mut(chunk_size.get()) {
+                appended_scratch.extend(
+                    chunk
+                        .iter_mut()
+                        .flat_map(|wrapped_subject| mutate_one(context, wrapped_subject, options)),
+                );
             }
         }
+        None => {
+            appended_scratch.extend(
+                population
+                    .subjects
+                    .iter_mut()
+                    .flat_map(|wrapped_subject| mutate_one(context, wrapped_subject, options)),
+            );
+        }
+    }
+    population.subjects.append(appended_scratch);
+}
+
+/// Mutates each subject (and evaluates its fitness) in parallel via rayon,
+/// since fitness functions are typically the dominant per-generation cost.
+#[cfg(feature = "parallel")]
+pub fn apply_mutations<
+    Subject: Send + Sync,
+    Mutator: ApplyMutation<Subject = Subject> + Sync,
+    Actions: SampleSelf<Output = Vec<Mutator>> + Sync,
+>(
+    context: &GaContext,
+    population: &mut Population<Subject>,
+    options: &ApplyMutationOptions<Actions>,
+    appended_scratch: &mut Vec<FitnessWrapped<Subject>>,
+) {
+    appended_scratch.clear();
+    let min_len = options.chunk_size.map(NonZeroUsize::get).unwrap_or(1);
+    appended_scratch.par_extend(
+        population
+            .subjects
+            .par_iter_mut()
+            .with_min_len(min_len)
+            .flat_map_iter(|wrapped_subject| mutate_one(context, wrapped_subject, options)),
+    );
+    population.subjects.append(appended_scratch);
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Mutator, Subject, MutatorActions> GaAction for GenericMutator<Mutator, Subject, MutatorActions>
+where
+    Mutator: ApplyMutation<Subject = Subject>,
+    MutatorActions: SampleSelf<Output = Vec<Mutator>>,
+{
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("mutation", context, population, |population| {
+            apply_mutations(
+                context,
+                population,
+                &self.options,
+                &mut self.appended_scratch.borrow_mut(),
+            );
+        });
     }
-    population.subjects.extend(appended_subjects);
 }
 
+#[cfg(feature = "parallel")]
 impl<Mutator, Subject, MutatorActions> GaAction for GenericMutator<Mutator, Subject, MutatorActions>
 where
+    Subject: Send + Sync,
+    Mutator: ApplyMutation<Subject = Subject> + Sync,
+    MutatorActions: SampleSelf<Output = Vec<Mutator>> + Sync,
+{
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("mutation", context, population, |population| {
+            apply_mutations(
+                context,
+                population,
+                &self.options,
+                &mut self.appended_scratch.borrow_mut(),
+            );
+        });
+    }
+}
+
+/// Same mutation as [`mutate_one`], but returns the raw, unscored
+/// replacement/offspring instead of calling [`ApplyMutation::fitness`]
+/// itself, so [`apply_mutations_batched`] can score every mutated subject
+/// this generation in one [`FitBatch::measure_batch`] call. Note this also
+/// means offspring built from it are wrapped via [`FitnessWrapped::new`]
+/// rather than [`FitnessWrapped::new_with_parentage`], so a
+/// [`crate::ga::lineage::Genealogy`] won't see their parent/operator —
+/// callers that need lineage tracking should use [`apply_mutations`] instead.
+fn mutate_one_raw<Subject, Mutator: ApplyMutation<Subject = Subject>, Actions: SampleSelf<Output = Vec<Mutator>>>(
+    context: &GaContext,
+    original: &Subject,
+    options: &ApplyMutationOptions<Actions>,
+) -> (Option<Subject>, Vec<Subject>) {
+    let mut appended = vec![];
+    let mut replacement: Option<Subject> = None;
+    if !coin_flip(options.overall_mutation_chance) {
+        return (replacement, appended);
+    }
+    for mutator in options.mutation_actions.sample_self().iter() {
+        let current = replacement.as_ref().unwrap_or(original);
+        let mutated_subject = mutator.apply(context, current);
+        if options.clone_on_mutation {
+            appended.push(mutated_subject);
+        } else {
+            replacement = Some(mutated_subject);
+        }
+    }
+    (replacement, appended)
+}
+
+/// Batched counterpart to [`apply_mutations`] for [`FitBatch`] subjects:
+/// gathers every mutated subject this generation first, then scores them all
+/// in one [`FitBatch::measure_batch`] call instead of one
+/// [`ApplyMutation::fitness`] call per mutation — bypassing `Mutator::fitness`
+/// entirely in favor of `Subject::measure_batch`, so a mutator relying on a
+/// fitness definition that differs from the subject's own should keep using
+/// [`apply_mutations`] instead.
+pub fn apply_mutations_batched<
+    Subject: FitBatch,
+    Mutator: ApplyMutation<Subject = Subject>,
+    Actions: SampleSelf<Output = Vec<Mutator>>,
+>(
+    context: &GaContext,
+    population: &mut Population<Subject>,
+    options: &ApplyMutationOptions<Actions>,
+    appended_scratch: &mut Vec<FitnessWrapped<Subject>>,
+) {
+    appended_scratch.clear();
+    let mut replacement_indices = vec![];
+    let mut batch = vec![];
+    let mut new_subjects = vec![];
+    for (index, wrapped_subject) in population.subjects.iter().enumerate() {
+        let (replacement, appended) = mutate_one_raw(context, wrapped_subject.subject_ref(), options);
+        if let Some(replacement) = replacement {
+            replacement_indices.push(index);
+            batch.push(replacement);
+        }
+        new_subjects.extend(appended);
+    }
+    batch.extend(new_subjects);
+    let mut scored = wrap_batch(batch).into_iter();
+    for index in replacement_indices {
+        population.subjects[index] = scored.next().expect("one scored subject per replacement");
+    }
+    appended_scratch.extend(scored);
+    population.subjects.append(appended_scratch);
+}
+
+/// [`GaAction`] wrapper around [`apply_mutations_batched`], for subjects whose
+/// fitness is worth vectorizing (SIMD, GPU, one DB round-trip) rather than
+/// measuring one mutation at a time — see [`GenericMutator`] for the
+/// per-subject equivalent.
+#[derive(Clone)]
+pub struct GenericBatchMutator<Mutator, Subject, Actions> {
+    _subject: PhantomData<Subject>,
+    _mutator: PhantomData<Mutator>,
+    options: ApplyMutationOptions<Actions>,
+    appended_scratch: RefCell<Vec<FitnessWrapped<Subject>>>,
+}
+
+impl<Mutator, Subject, Actions> GenericBatchMutator<Mutator, Subject, Actions> {
+    pub fn new(options: ApplyMutationOptions<Actions>) -> Self {
+        Self {
+            _subject: PhantomData,
+            _mutator: PhantomData,
+            options,
+            appended_scratch: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<Mutator, Subject, Actions> Default for GenericBatchMutator<Mutator, Subject, Actions>
+where
+    Subject: Default,
+    Mutator: Default,
+    Actions: Default,
+{
+    fn default() -> Self {
+        Self::new(ApplyMutationOptions::<Actions>::default())
+    }
+}
+
+impl<Mutator, Subject, MutatorActions> GaAction for GenericBatchMutator<Mutator, Subject, MutatorActions>
+where
+    Subject: FitBatch,
     Mutator: ApplyMutation<Subject = Subject>,
     MutatorActions: SampleSelf<Output = Vec<Mutator>>,
 {
     type Subject = Subject;
 
     fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
-        apply_mutations(context, population, &self.options);
+        crate::ga::instrument_action("mutation", context, population, |population| {
+            apply_mutations_batched(
+                context,
+                population,
+                &self.options,
+                &mut self.appended_scratch.borrow_mut(),
+            );
+        });
     }
 }
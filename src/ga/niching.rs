@@ -0,0 +1,110 @@
+//! Fitness sharing: rescales each subject's fitness by how crowded its
+//! neighborhood is, so a population doesn't collapse onto a single optimum
+//! — crowded subjects are penalized relative to isolated ones, preserving
+//! diversity across generations.
+
+use crate::ga::fitness::FitnessWrapped;
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+
+/// A measure of how far apart two subjects are in genotype space, for
+/// niching/speciation purposes. Smaller is more similar; `0.0` for
+/// identical genotypes.
+pub trait GenotypeDistance {
+    fn genotype_distance(&self, other: &Self) -> f64;
+}
+
+/// Multiplies each subject's fitness by its niche count — the sum of a
+/// triangular sharing function over every subject within `radius`, itself
+/// included —
+/// so subjects with many close neighbors end up with a worse (larger)
+/// fitness than an equally-fit but isolated subject. Assumes non-negative
+/// fitness, same as every other error/distance-style fitness landscape this
+/// crate's examples use; a multiplicative penalty on a negative fitness
+/// would make it *better*, which isn't what niching is for.
+pub struct FitnessSharingAction<Subject> {
+    radius: f64,
+    alpha: f64,
+    _marker: std::marker::PhantomData<Subject>,
+}
+
+impl<Subject> FitnessSharingAction<Subject> {
+    /// `alpha` shapes the sharing function's falloff; `1.0` (linear) is the
+    /// standard choice and what [`Self::new`] uses.
+    pub fn new(radius: f64) -> Self {
+        Self::with_alpha(radius, 1.0)
+    }
+
+    pub fn with_alpha(radius: f64, alpha: f64) -> Self {
+        Self { radius, alpha, _marker: std::marker::PhantomData }
+    }
+}
+
+impl<Subject: GenotypeDistance + Clone> GaAction for FitnessSharingAction<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("fitness_sharing", context, population, |population| {
+            let n = population.subjects.len();
+            let niche_counts: Vec<f64> = (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| {
+                            let distance = population.subjects[i]
+                                .subject_ref()
+                                .genotype_distance(population.subjects[j].subject_ref());
+                            if distance < self.radius {
+                                1.0 - (distance / self.radius).powf(self.alpha)
+                            } else {
+                                0.0
+                            }
+                        })
+                        .sum()
+                })
+                .collect();
+            for (wrapped, niche_count) in population.subjects.iter_mut().zip(niche_counts) {
+                let shared_fitness = wrapped.fitness() * niche_count.max(1.0);
+                *wrapped = FitnessWrapped::new(wrapped.subject_ref().clone(), shared_fitness);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Point(f64);
+
+    impl GenotypeDistance for Point {
+        fn genotype_distance(&self, other: &Self) -> f64 {
+            (self.0 - other.0).abs()
+        }
+    }
+
+    fn population(values: Vec<(f64, f64)>) -> Population<Point> {
+        let subjects = values
+            .into_iter()
+            .map(|(genotype, fitness)| FitnessWrapped::new(Point(genotype), fitness))
+            .collect();
+        Population { pool_size: 0, subjects, memory_budget_bytes: None }
+    }
+
+    #[test]
+    fn test_isolated_subject_fitness_is_unchanged() {
+        let mut population = population(vec![(0.0, 10.0), (100.0, 10.0)]);
+        let action = FitnessSharingAction::new(5.0);
+        action.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects[0].fitness(), 10.0);
+        assert_eq!(population.subjects[1].fitness(), 10.0);
+    }
+
+    #[test]
+    fn test_crowded_subjects_get_a_worse_fitness() {
+        let mut population = population(vec![(0.0, 10.0), (0.1, 10.0), (0.2, 10.0)]);
+        let action = FitnessSharingAction::new(5.0);
+        action.perform_action(&GaContext::default(), &mut population);
+        assert!(population.subjects[0].fitness() > 10.0);
+    }
+}
@@ -0,0 +1,246 @@
+//! Per-operator success/failure counters for mutation and reproduction
+//! actions, so a [`crate::ga::WeightedAction`]'s weights can be tuned
+//! empirically instead of by feel.
+//!
+//! Recording this automatically for every operator call would mean
+//! `ApplyMutation`/`ApplyReproduction::apply` reporting a reward on every
+//! invocation, a breaking change to those traits (see
+//! [`crate::ga::bandit`]'s docs for the same tradeoff). Instead,
+//! [`TrackedMutator`]/[`TrackedReproducer`] wrap an existing operator and
+//! record whether each offspring improved on its parent(s) themselves,
+//! following the `Rc<RefCell<State>>`-handle pattern already used by
+//! [`crate::ga::cma_es::CmaEsAction`] and [`crate::ga::species::Speciation`]:
+//! keep a clone of [`TrackedMutator::stats`]/[`TrackedReproducer::stats`] to
+//! read the counters back, rather than reading them off `GaIterState`
+//! (which has no room for arbitrary per-action extension state without a
+//! breaking change to every `GaAction`).
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::ga::fitness::Fitness;
+use crate::ga::mutation::ApplyMutation;
+use crate::ga::reproduction::{ApplyReproduction, ReproductionResult};
+use crate::ga::GaContext;
+
+/// How many times an operator's offspring did (`successes`) or didn't
+/// improve on their parent(s), out of `attempts` total.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperatorStats {
+    pub attempts: u64,
+    pub successes: u64,
+}
+
+impl OperatorStats {
+    fn record(&mut self, improved: bool) {
+        self.attempts += 1;
+        if improved {
+            self.successes += 1;
+        }
+    }
+
+    /// `successes / attempts`, or `0.0` before the first attempt.
+    pub fn success_rate(&self) -> f64 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            self.successes as f64 / self.attempts as f64
+        }
+    }
+}
+
+/// Wraps an [`ApplyMutation`] operator, recording in [`OperatorStats`]
+/// whether each mutated offspring's fitness improved on its parent's (lower
+/// is better, the crate-wide fitness convention).
+pub struct TrackedMutator<Mutator> {
+    mutator: Mutator,
+    stats: Rc<RefCell<OperatorStats>>,
+}
+
+impl<Mutator> TrackedMutator<Mutator> {
+    pub fn new(mutator: Mutator) -> Self {
+        Self {
+            mutator,
+            stats: Rc::new(RefCell::new(OperatorStats::default())),
+        }
+    }
+
+    pub fn stats(&self) -> Rc<RefCell<OperatorStats>> {
+        self.stats.clone()
+    }
+}
+
+impl<Mutator: Clone> Clone for TrackedMutator<Mutator> {
+    fn clone(&self) -> Self {
+        Self {
+            mutator: self.mutator.clone(),
+            stats: self.stats.clone(),
+        }
+    }
+}
+
+impl<Mutator: ApplyMutation> ApplyMutation for TrackedMutator<Mutator> {
+    type Subject = Mutator::Subject;
+
+    fn apply(&self, context: &GaContext, subject: &Self::Subject) -> Self::Subject {
+        let parent_fitness = Mutator::fitness(subject);
+        let offspring = self.mutator.apply(context, subject);
+        let offspring_fitness = Mutator::fitness(&offspring);
+        self.stats.borrow_mut().record(offspring_fitness < parent_fitness);
+        offspring
+    }
+
+    fn fitness(subject: &Self::Subject) -> Fitness {
+        Mutator::fitness(subject)
+    }
+}
+
+/// Wraps an [`ApplyReproduction`] operator, recording in [`OperatorStats`]
+/// whether each offspring's fitness improved on the better of its two
+/// parents' (lower is better).
+pub struct TrackedReproducer<Reproducer> {
+    reproducer: Reproducer,
+    stats: Rc<RefCell<OperatorStats>>,
+}
+
+impl<Reproducer> TrackedReproducer<Reproducer> {
+    pub fn new(reproducer: Reproducer) -> Self {
+        Self {
+            reproducer,
+            stats: Rc::new(RefCell::new(OperatorStats::default())),
+        }
+    }
+
+    pub fn stats(&self) -> Rc<RefCell<OperatorStats>> {
+        self.stats.clone()
+    }
+}
+
+impl<Reproducer: Clone> Clone for TrackedReproducer<Reproducer> {
+    fn clone(&self) -> Self {
+        Self {
+            reproducer: self.reproducer.clone(),
+            stats: self.stats.clone(),
+        }
+    }
+}
+
+impl<Reproducer: ApplyReproduction> ApplyReproduction for TrackedReproducer<Reproducer> {
+    type Subject = Reproducer::Subject;
+
+    fn apply(
+        &self,
+        context: &GaContext,
+        subject_a: &Self::Subject,
+        subject_b: &Self::Subject,
+    ) -> Option<ReproductionResult<Self::Subject>> {
+        let parent_fitness = Reproducer::fitness(subject_a).min(Reproducer::fitness(subject_b));
+        let result = self.reproducer.apply(context, subject_a, subject_b)?;
+        let offspring: Vec<&Self::Subject> = match &result {
+            ReproductionResult::Single(a) => vec![a],
+            ReproductionResult::Double(a, b) => vec![a, b],
+            ReproductionResult::Triple(a, b, c) => vec![a, b, c],
+            ReproductionResult::Quad(a, b, c, d) => vec![a, b, c, d],
+        };
+        let mut stats = self.stats.borrow_mut();
+        for child in offspring {
+            stats.record(Reproducer::fitness(child) < parent_fitness);
+        }
+        drop(stats);
+        Some(result)
+    }
+
+    fn fitness(subject: &Self::Subject) -> Fitness {
+        Reproducer::fitness(subject)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+    struct Count(i32);
+    impl crate::ga::subject::GaSubject for Count {}
+
+    #[derive(Clone)]
+    struct AlwaysImprove;
+    impl ApplyMutation for AlwaysImprove {
+        type Subject = Count;
+        fn apply(&self, _context: &GaContext, subject: &Count) -> Count {
+            Count(subject.0 - 1)
+        }
+        fn fitness(subject: &Count) -> Fitness {
+            subject.0 as Fitness
+        }
+    }
+
+    #[derive(Clone)]
+    struct AlwaysWorsen;
+    impl ApplyMutation for AlwaysWorsen {
+        type Subject = Count;
+        fn apply(&self, _context: &GaContext, subject: &Count) -> Count {
+            Count(subject.0 + 1)
+        }
+        fn fitness(subject: &Count) -> Fitness {
+            subject.0 as Fitness
+        }
+    }
+
+    #[derive(Clone)]
+    struct SumReproducer;
+    impl ApplyReproduction for SumReproducer {
+        type Subject = Count;
+        fn apply(&self, _context: &GaContext, a: &Count, b: &Count) -> Option<ReproductionResult<Count>> {
+            Some(ReproductionResult::Single(Count(a.0 + b.0)))
+        }
+        fn fitness(subject: &Count) -> Fitness {
+            subject.0 as Fitness
+        }
+    }
+
+    #[test]
+    fn test_tracked_mutator_records_improving_offspring() {
+        let tracked = TrackedMutator::new(AlwaysImprove);
+        tracked.apply(&GaContext::default(), &Count(10));
+        tracked.apply(&GaContext::default(), &Count(10));
+        let stats = tracked.stats();
+        let stats = stats.borrow();
+        assert_eq!(stats.attempts, 2);
+        assert_eq!(stats.successes, 2);
+        assert_eq!(stats.success_rate(), 1.0);
+    }
+
+    #[test]
+    fn test_tracked_mutator_records_worsening_offspring() {
+        let tracked = TrackedMutator::new(AlwaysWorsen);
+        tracked.apply(&GaContext::default(), &Count(10));
+        let stats = tracked.stats();
+        let stats = stats.borrow();
+        assert_eq!(stats.attempts, 1);
+        assert_eq!(stats.successes, 0);
+        assert_eq!(stats.success_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_tracked_reproducer_records_each_offspring() {
+        let tracked = TrackedReproducer::new(SumReproducer);
+        // parents 1 and 2: min parent fitness is 1, offspring is 3 (worse)
+        tracked.apply(&GaContext::default(), &Count(1), &Count(2));
+        // parents -5 and -3: min parent fitness is -5, offspring is -8 (better)
+        tracked.apply(&GaContext::default(), &Count(-5), &Count(-3));
+        let stats = tracked.stats();
+        let stats = stats.borrow();
+        assert_eq!(stats.attempts, 2);
+        assert_eq!(stats.successes, 1);
+    }
+
+    #[test]
+    fn test_stats_handle_is_shared_across_clones() {
+        let tracked = TrackedMutator::new(AlwaysImprove);
+        let clone = tracked.clone();
+        tracked.apply(&GaContext::default(), &Count(10));
+        assert_eq!(clone.stats().borrow().attempts, 1);
+    }
+}
@@ -0,0 +1,224 @@
+use std::cell::RefCell;
+use std::fs::File;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, Float64Array, Float64Builder, ListBuilder, UInt64Array};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+
+use crate::ga::population::Population;
+use crate::ga::stats::compute_stats;
+use crate::ga::{GaAction, GaContext};
+
+/// Writes one Parquet row per generation (population summary statistics from
+/// [`crate::ga::stats`], and optionally the full per-subject fitness vector)
+/// to `path`, so large experiment histories can be loaded into pandas/polars
+/// without a custom conversion step.
+///
+/// Register it as an action the same way [`crate::ga::csv_stats::CsvStatsRecorder`]
+/// is registered. Unlike `CsvStatsRecorder`, the writer must be closed to
+/// produce a valid file; this happens automatically on drop, logging (rather
+/// than propagating) any close error, so call [`Self::close`] directly if you
+/// need to observe it.
+pub struct ParquetHistoryRecorder<Subject> {
+    schema: SchemaRef,
+    include_fitness_vectors: bool,
+    writer: RefCell<Option<ArrowWriter<File>>>,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> ParquetHistoryRecorder<Subject> {
+    pub fn new(
+        path: impl AsRef<Path>,
+        include_fitness_vectors: bool,
+    ) -> parquet::errors::Result<Self> {
+        let schema = Arc::new(Self::schema(include_fitness_vectors));
+        let file = File::create(path)?;
+        let writer = ArrowWriter::try_new(file, schema.clone(), None)?;
+        Ok(Self {
+            schema,
+            include_fitness_vectors,
+            writer: RefCell::new(Some(writer)),
+            _subject: PhantomData,
+        })
+    }
+
+    fn schema(include_fitness_vectors: bool) -> Schema {
+        let mut fields = vec![
+            Field::new("generation", DataType::UInt64, false),
+            Field::new("population_size", DataType::UInt64, false),
+            Field::new("min_fitness", DataType::Float64, false),
+            Field::new("max_fitness", DataType::Float64, false),
+            Field::new("mean_fitness", DataType::Float64, false),
+            Field::new("stddev_fitness", DataType::Float64, false),
+            Field::new("diversity", DataType::Float64, false),
+        ];
+        if include_fitness_vectors {
+            fields.push(Field::new(
+                "fitness_vector",
+                DataType::List(Arc::new(Field::new("item", DataType::Float64, true))),
+                false,
+            ));
+        }
+        Schema::new(fields)
+    }
+
+    /// Flushes and closes the underlying Parquet writer. Idempotent; also
+    /// called on drop.
+    pub fn close(&self) -> parquet::errors::Result<()> {
+        if let Some(writer) = self.writer.borrow_mut().take() {
+            writer.close()?;
+        }
+        Ok(())
+    }
+}
+
+impl<Subject> Drop for ParquetHistoryRecorder<Subject> {
+    fn drop(&mut self) {
+        if let Err(err) = self.close() {
+            tracing::log::warn!("failed to close Parquet writer: {err}");
+        }
+    }
+}
+
+macro_rules! impl_record_generation {
+    () => {
+        fn record_generation(&self, context: &GaContext, population: &Population<Subject>) {
+            let Some(stats) = compute_stats(population) else {
+                return;
+            };
+            let mut columns: Vec<ArrayRef> = vec![
+                Arc::new(UInt64Array::from(vec![context.generation as u64])),
+                Arc::new(UInt64Array::from(vec![population.subjects.len() as u64])),
+                Arc::new(Float64Array::from(vec![stats.min_fitness])),
+                Arc::new(Float64Array::from(vec![stats.max_fitness])),
+                Arc::new(Float64Array::from(vec![stats.mean_fitness])),
+                Arc::new(Float64Array::from(vec![stats.stddev_fitness])),
+                Arc::new(Float64Array::from(vec![stats.diversity])),
+            ];
+            if self.include_fitness_vectors {
+                let mut builder = ListBuilder::new(Float64Builder::new());
+                builder.append_value(population.subjects.iter().map(|s| Some(s.fitness())));
+                columns.push(Arc::new(builder.finish()));
+            }
+            let batch = match RecordBatch::try_new(self.schema.clone(), columns) {
+                Ok(batch) => batch,
+                Err(err) => {
+                    tracing::log::warn!("failed to build Parquet record batch: {err}");
+                    return;
+                }
+            };
+            let mut writer_slot = self.writer.borrow_mut();
+            let Some(writer) = writer_slot.as_mut() else {
+                return;
+            };
+            if let Err(err) = writer.write(&batch) {
+                tracing::log::warn!("failed to write Parquet row: {err}");
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash> ParquetHistoryRecorder<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Send + Sync> ParquetHistoryRecorder<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash> GaAction for ParquetHistoryRecorder<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("parquet", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Send + Sync> GaAction for ParquetHistoryRecorder<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("parquet", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    use crate::ga::fitness::FitnessWrapped;
+    use crate::ga::parquet::ParquetHistoryRecorder;
+    use crate::ga::population::Population;
+    use crate::ga::{GaAction, GaContext};
+
+    fn population_of(fitnesses: &[u32]) -> Population<u32> {
+        Population {
+            pool_size: fitnesses.len(),
+            subjects: fitnesses
+                .iter()
+                .map(|&f| FitnessWrapped::new(f, f as f64))
+                .collect(),
+            memory_budget_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_writes_one_row_per_generation() {
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_parquet_test_{:?}.parquet",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let recorder = ParquetHistoryRecorder::new(&path, false).unwrap();
+            recorder.perform_action(&GaContext::new(0), &mut population_of(&[1, 2, 3]));
+            recorder.perform_action(&GaContext::new(1), &mut population_of(&[4, 5, 6]));
+        }
+
+        let file = std::fs::File::open(&path).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        let total_rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(total_rows, 2);
+    }
+
+    #[test]
+    fn test_skips_row_for_empty_population() {
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_parquet_empty_test_{:?}.parquet",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        {
+            let recorder = ParquetHistoryRecorder::new(&path, false).unwrap();
+            recorder.perform_action(&GaContext::new(0), &mut population_of(&[]));
+        }
+
+        let file = std::fs::File::open(&path).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        let total_rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(total_rows, 0);
+    }
+}
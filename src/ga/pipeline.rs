@@ -0,0 +1,315 @@
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+
+/// A boxed, type-erased [`GaAction`], for [`ActionPipeline`] entries
+/// assembled at runtime (e.g. from config) rather than known at compile
+/// time the way [`crate::ga::action::DefaultActions`]'s nine type
+/// parameters are.
+pub type DynGaAction<Subject> = Box<dyn GaAction<Subject = Subject> + Send + Sync>;
+
+/// Runs an ordered, runtime-assembled list of [`GaAction`]s, instead of the
+/// fixed prune/mutate/reproduce/dedupe/inflate order
+/// [`crate::ga::action::DefaultActions`] hard-codes across nine generic type
+/// parameters. Useful when the set and order of stages needs to come from
+/// config rather than being nailed down at compile time — at the cost of a
+/// vtable call per stage per generation.
+#[derive(Default)]
+pub struct ActionPipeline<Subject> {
+    actions: Vec<DynGaAction<Subject>>,
+}
+
+impl<Subject> ActionPipeline<Subject> {
+    pub fn new() -> Self {
+        Self { actions: Vec::new() }
+    }
+
+    /// Appends `action` to the end of the pipeline, to run after everything
+    /// already pushed.
+    pub fn push(&mut self, action: impl GaAction<Subject = Subject> + Send + Sync + 'static) -> &mut Self {
+        self.push_boxed(Box::new(action))
+    }
+
+    /// Like [`Self::push`], but takes an already-boxed action, for callers
+    /// (e.g. [`DefaultActionStages::into_pipeline`]) re-assembling actions
+    /// they've already boxed elsewhere.
+    pub fn push_boxed(&mut self, action: DynGaAction<Subject>) -> &mut Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Builder-style counterpart to [`Self::push`] for assembling a pipeline
+    /// in one chained expression.
+    pub fn with_action(mut self, action: impl GaAction<Subject = Subject> + Send + Sync + 'static) -> Self {
+        self.push(action);
+        self
+    }
+}
+
+impl<Subject> GaAction for ActionPipeline<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("action_pipeline", context, population, |population| {
+            for action in &self.actions {
+                action.perform_action(context, population);
+            }
+        });
+    }
+}
+
+/// One of the five stages [`crate::ga::action::DefaultActions`] runs, named
+/// so callers can declare a custom order for them (e.g. dedupe before
+/// prune, or reproduce before mutate) instead of the fixed
+/// prune -> mutate -> reproduce -> dedupe -> inflate order
+/// `DefaultActions::perform_action` hard-codes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActionStage {
+    Prune,
+    Mutate,
+    Reproduce,
+    Dedupe,
+    Inflate,
+}
+
+/// Boxed [`DefaultActions`](crate::ga::action::DefaultActions)-equivalent
+/// stages, held separately so [`Self::into_pipeline`] can assemble them into
+/// an [`ActionPipeline`] in whatever order a caller (e.g. one reading a
+/// `Vec<ActionStage>` from config) chooses, instead of `DefaultActions`'s
+/// fixed order. Any field left `None` is skipped rather than treated as an
+/// error, so a caller that doesn't need, say, a dedupe stage can leave it
+/// out.
+pub struct DefaultActionStages<Subject> {
+    pub prune: Option<DynGaAction<Subject>>,
+    pub mutate: Option<DynGaAction<Subject>>,
+    pub reproduce: Option<DynGaAction<Subject>>,
+    pub dedupe: Option<DynGaAction<Subject>>,
+    pub inflate: Option<DynGaAction<Subject>>,
+}
+
+impl<Subject> Default for DefaultActionStages<Subject> {
+    fn default() -> Self {
+        Self {
+            prune: None,
+            mutate: None,
+            reproduce: None,
+            dedupe: None,
+            inflate: None,
+        }
+    }
+}
+
+impl<Subject> DefaultActionStages<Subject> {
+    /// Assembles the stages present into an [`ActionPipeline`] in `order`.
+    /// A stage repeated in `order` runs only at its first position (later
+    /// occurrences are no-ops, since [`Option::take`] already moved it out);
+    /// a stage never listed in `order` never runs, even if set.
+    pub fn into_pipeline(mut self, order: &[ActionStage]) -> ActionPipeline<Subject> {
+        let mut pipeline = ActionPipeline::new();
+        for stage in order {
+            let action = match stage {
+                ActionStage::Prune => self.prune.take(),
+                ActionStage::Mutate => self.mutate.take(),
+                ActionStage::Reproduce => self.reproduce.take(),
+                ActionStage::Dedupe => self.dedupe.take(),
+                ActionStage::Inflate => self.inflate.take(),
+            };
+            if let Some(action) = action {
+                pipeline.push_boxed(action);
+            }
+        }
+        pipeline
+    }
+}
+
+/// Wraps `action` so it only runs every `n`th generation (`context.generation
+/// % n == 0`), skipping it the rest of the time. Useful for expensive stages
+/// (a full dedupe pass, archive maintenance, re-evaluation) that don't need
+/// to run every generation to be effective.
+///
+/// `n == 0` never runs `action`, since `generation % 0` would panic.
+#[derive(Debug, Copy, Clone)]
+pub struct EveryNGenerations<Action> {
+    n: usize,
+    action: Action,
+}
+
+impl<Action> EveryNGenerations<Action> {
+    pub fn new(n: usize, action: Action) -> Self {
+        Self { n, action }
+    }
+}
+
+impl<Action> GaAction for EveryNGenerations<Action>
+where
+    Action: GaAction,
+{
+    type Subject = Action::Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        if self.n != 0 && context.generation.is_multiple_of(self.n) {
+            self.action.perform_action(context, population);
+        }
+    }
+}
+
+/// Wraps `action` so it only runs once `context.generation >= n`, skipping
+/// it every generation before that. Useful for stages that only make sense
+/// once the population has had a chance to warm up (e.g. archive maintenance
+/// before there's anything worth archiving).
+#[derive(Debug, Copy, Clone)]
+pub struct AfterGeneration<Action> {
+    n: usize,
+    action: Action,
+}
+
+impl<Action> AfterGeneration<Action> {
+    pub fn new(n: usize, action: Action) -> Self {
+        Self { n, action }
+    }
+}
+
+impl<Action> GaAction for AfterGeneration<Action>
+where
+    Action: GaAction,
+{
+    type Subject = Action::Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        if context.generation >= self.n {
+            self.action.perform_action(context, population);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::fitness::{Fit, Fitness, FitnessWrapped};
+    use crate::ga::population::Population;
+    use crate::ga::subject::GaSubject;
+
+    use super::*;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    struct Tagged(i32);
+
+    impl GaSubject for Tagged {}
+
+    impl Fit<Fitness> for Tagged {
+        fn measure(&self) -> Fitness {
+            self.0 as Fitness
+        }
+    }
+
+    struct Double;
+    impl GaAction for Double {
+        type Subject = Tagged;
+        fn perform_action(&self, _context: &GaContext, population: &mut Population<Self::Subject>) {
+            for subject in &mut population.subjects {
+                let doubled = Tagged(subject.subject_ref().0 * 2);
+                let fitness = doubled.measure();
+                *subject = FitnessWrapped::new(doubled, fitness);
+            }
+        }
+    }
+
+    struct AddOne;
+    impl GaAction for AddOne {
+        type Subject = Tagged;
+        fn perform_action(&self, _context: &GaContext, population: &mut Population<Self::Subject>) {
+            for subject in &mut population.subjects {
+                let incremented = Tagged(subject.subject_ref().0 + 1);
+                let fitness = incremented.measure();
+                *subject = FitnessWrapped::new(incremented, fitness);
+            }
+        }
+    }
+
+    fn population() -> Population<Tagged> {
+        Population {
+            subjects: vec![FitnessWrapped::new(Tagged(1), 1.0)],
+            pool_size: 1,
+            memory_budget_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_pipeline_runs_actions_in_push_order() {
+        let mut pipeline = ActionPipeline::new();
+        pipeline.push(Double).push(AddOne);
+        let mut population = population();
+        pipeline.perform_action(&GaContext::default(), &mut population);
+        // (1 * 2) + 1 = 3
+        assert_eq!(population.subjects[0].subject_ref().0, 3);
+    }
+
+    #[test]
+    fn test_pipeline_order_is_significant() {
+        let pipeline = ActionPipeline::new().with_action(AddOne).with_action(Double);
+        let mut population = population();
+        pipeline.perform_action(&GaContext::default(), &mut population);
+        // (1 + 1) * 2 = 4
+        assert_eq!(population.subjects[0].subject_ref().0, 4);
+    }
+
+    #[test]
+    fn test_default_action_stages_honors_custom_order() {
+        let stages = DefaultActionStages {
+            mutate: Some(Box::new(AddOne)),
+            reproduce: Some(Box::new(Double)),
+            ..Default::default()
+        };
+        let pipeline = stages.into_pipeline(&[ActionStage::Reproduce, ActionStage::Mutate]);
+        let mut population = population();
+        pipeline.perform_action(&GaContext::default(), &mut population);
+        // reproduce (double) before mutate (add one): (1 * 2) + 1 = 3
+        assert_eq!(population.subjects[0].subject_ref().0, 3);
+    }
+
+    #[test]
+    fn test_default_action_stages_skips_unset_stages() {
+        let stages = DefaultActionStages {
+            mutate: Some(Box::new(AddOne)),
+            ..Default::default()
+        };
+        let pipeline = stages.into_pipeline(&[
+            ActionStage::Prune,
+            ActionStage::Mutate,
+            ActionStage::Dedupe,
+            ActionStage::Inflate,
+        ]);
+        let mut population = population();
+        pipeline.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects[0].subject_ref().0, 2);
+    }
+
+    #[test]
+    fn test_every_n_generations_runs_on_multiples_only() {
+        let action = EveryNGenerations::new(3, AddOne);
+        let mut population = population();
+        action.perform_action(&GaContext::new(1), &mut population);
+        assert_eq!(population.subjects[0].subject_ref().0, 1);
+        action.perform_action(&GaContext::new(3), &mut population);
+        assert_eq!(population.subjects[0].subject_ref().0, 2);
+    }
+
+    #[test]
+    fn test_every_n_generations_zero_never_runs() {
+        let action = EveryNGenerations::new(0, AddOne);
+        let mut population = population();
+        action.perform_action(&GaContext::new(0), &mut population);
+        assert_eq!(population.subjects[0].subject_ref().0, 1);
+    }
+
+    #[test]
+    fn test_after_generation_skips_before_threshold_and_runs_after() {
+        let action = AfterGeneration::new(5, AddOne);
+        let mut population = population();
+        action.perform_action(&GaContext::new(4), &mut population);
+        assert_eq!(population.subjects[0].subject_ref().0, 1);
+        action.perform_action(&GaContext::new(5), &mut population);
+        assert_eq!(population.subjects[0].subject_ref().0, 2);
+        action.perform_action(&GaContext::new(6), &mut population);
+        assert_eq!(population.subjects[0].subject_ref().0, 3);
+    }
+}
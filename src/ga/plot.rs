@@ -0,0 +1,392 @@
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+use plotters::prelude::*;
+
+use crate::ga::fitness::Fitness;
+use crate::ga::population::Population;
+use crate::ga::stats::{compute_stats, PopulationStats};
+use crate::ga::{GaAction, GaContext};
+
+/// Error rendering a [`RunReport`] to an image.
+#[derive(Debug)]
+pub enum PlotError {
+    UnsupportedExtension(String),
+    Draw(String),
+}
+
+impl std::fmt::Display for PlotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlotError::UnsupportedExtension(ext) => {
+                write!(f, "unsupported plot file extension: {ext}")
+            }
+            PlotError::Draw(message) => write!(f, "failed to render plot: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for PlotError {}
+
+/// Records per-generation [`PopulationStats`] in memory and renders them as a
+/// fitness-over-generations chart via `plotters`. Register it as an action
+/// the same way [`crate::ga::csv_stats::CsvStatsRecorder`] is registered, then
+/// call [`RunReport::plot_to`] once the run has finished.
+///
+/// Plots `min_fitness`, `max_fitness`, and `mean_fitness` rather than a
+/// single "best" line: `PopulationStats` doesn't know whether a run is
+/// minimizing or maximizing, so there's no way to pick the best extreme for
+/// the caller without guessing.
+pub struct RunReport<Subject> {
+    history: RefCell<Vec<(usize, PopulationStats)>>,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> Default for RunReport<Subject> {
+    fn default() -> Self {
+        Self {
+            history: RefCell::new(Vec::new()),
+            _subject: PhantomData,
+        }
+    }
+}
+
+impl<Subject> RunReport<Subject> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn plot_to(&self, path: impl AsRef<Path>) -> Result<(), PlotError> {
+        let path = path.as_ref();
+        let history = self.history.borrow();
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("svg") => {
+                let backend = SVGBackend::new(path, (960, 540)).into_drawing_area();
+                draw(&backend, &history)
+            }
+            Some("png") => {
+                let backend = BitMapBackend::new(path, (960, 540)).into_drawing_area();
+                draw(&backend, &history)
+            }
+            other => Err(PlotError::UnsupportedExtension(
+                other.unwrap_or("").to_string(),
+            )),
+        }
+    }
+}
+
+fn draw<DB: DrawingBackend>(
+    area: &DrawingArea<DB, plotters::coord::Shift>,
+    history: &[(usize, PopulationStats)],
+) -> Result<(), PlotError>
+where
+    DB::ErrorType: 'static,
+{
+    area.fill(&WHITE).map_err(draw_err)?;
+
+    let min_generation = history.iter().map(|(g, _)| *g).min().unwrap_or(0);
+    let max_generation = history
+        .iter()
+        .map(|(g, _)| *g)
+        .max()
+        .unwrap_or(1)
+        .max(min_generation + 1);
+    let min_fitness = history
+        .iter()
+        .map(|(_, s)| s.min_fitness)
+        .fold(f64::INFINITY, f64::min);
+    let max_fitness = history
+        .iter()
+        .map(|(_, s)| s.max_fitness)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (min_fitness, max_fitness) = if min_fitness.is_finite() && max_fitness.is_finite() {
+        (min_fitness, max_fitness)
+    } else {
+        (0.0, 1.0)
+    };
+
+    let mut chart = ChartBuilder::on(area)
+        .margin(20)
+        .x_label_area_size(30)
+        .y_label_area_size(50)
+        .caption("Fitness over generations", ("sans-serif", 24))
+        .build_cartesian_2d(min_generation..max_generation, min_fitness..max_fitness)
+        .map_err(draw_err)?;
+
+    chart.configure_mesh().draw().map_err(draw_err)?;
+
+    chart
+        .draw_series(LineSeries::new(
+            history.iter().map(|(g, s)| (*g, s.min_fitness)),
+            &BLUE,
+        ))
+        .map_err(draw_err)?
+        .label("min_fitness")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+    chart
+        .draw_series(LineSeries::new(
+            history.iter().map(|(g, s)| (*g, s.max_fitness)),
+            &RED,
+        ))
+        .map_err(draw_err)?
+        .label("max_fitness")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], RED));
+
+    chart
+        .draw_series(LineSeries::new(
+            history.iter().map(|(g, s)| (*g, s.mean_fitness)),
+            &GREEN,
+        ))
+        .map_err(draw_err)?
+        .label("mean_fitness")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], GREEN));
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()
+        .map_err(draw_err)?;
+
+    area.present().map_err(draw_err)?;
+    Ok(())
+}
+
+fn draw_err<E: std::fmt::Display>(err: E) -> PlotError {
+    PlotError::Draw(err.to_string())
+}
+
+macro_rules! impl_record_generation {
+    () => {
+        fn record_generation(&self, context: &GaContext, population: &Population<Subject>) {
+            if let Some(stats) = compute_stats(population) {
+                self.history.borrow_mut().push((context.generation, stats));
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash> RunReport<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Send + Sync> RunReport<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash> GaAction for RunReport<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("run_report", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Send + Sync> GaAction for RunReport<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("run_report", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+fn draw_best_mean<DB: DrawingBackend>(
+    area: &DrawingArea<DB, plotters::coord::Shift>,
+    history: &[(usize, Fitness, Fitness)],
+) -> Result<(), PlotError>
+where
+    DB::ErrorType: 'static,
+{
+    area.fill(&WHITE).map_err(draw_err)?;
+
+    let min_generation = history.iter().map(|(g, _, _)| *g).min().unwrap_or(0);
+    let max_generation = history
+        .iter()
+        .map(|(g, _, _)| *g)
+        .max()
+        .unwrap_or(1)
+        .max(min_generation + 1);
+    let min_fitness = history
+        .iter()
+        .flat_map(|(_, best, mean)| [*best, *mean])
+        .fold(f64::INFINITY, f64::min);
+    let max_fitness = history
+        .iter()
+        .flat_map(|(_, best, mean)| [*best, *mean])
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (min_fitness, max_fitness) = if min_fitness.is_finite() && max_fitness.is_finite() {
+        (min_fitness, max_fitness)
+    } else {
+        (0.0, 1.0)
+    };
+
+    let mut chart = ChartBuilder::on(area)
+        .margin(20)
+        .x_label_area_size(30)
+        .y_label_area_size(50)
+        .caption("Best/mean fitness over generations", ("sans-serif", 24))
+        .build_cartesian_2d(min_generation..max_generation, min_fitness..max_fitness)
+        .map_err(draw_err)?;
+
+    chart.configure_mesh().draw().map_err(draw_err)?;
+
+    chart
+        .draw_series(LineSeries::new(
+            history.iter().map(|(g, best, _)| (*g, *best)),
+            &BLUE,
+        ))
+        .map_err(draw_err)?
+        .label("best_fitness")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLUE));
+
+    chart
+        .draw_series(LineSeries::new(
+            history.iter().map(|(g, _, mean)| (*g, *mean)),
+            &GREEN,
+        ))
+        .map_err(draw_err)?
+        .label("mean_fitness")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], GREEN));
+
+    chart
+        .configure_series_labels()
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .draw()
+        .map_err(draw_err)?;
+
+    area.present().map_err(draw_err)?;
+    Ok(())
+}
+
+/// Accumulates `(generation, best_fitness, mean_fitness)` in memory and
+/// re-renders a two-line chart via `plotters` to `path` on every
+/// [`Self::record`] call.
+///
+/// The run-level counterpart to [`RunReport`]: that's a [`GaAction`] and so
+/// only ever sees a `&Population`, plotting `min`/`max` rather than a
+/// direction-aware "best" fitness (its own doc comment explains why).
+/// `PlotReporter` is instead driven directly by
+/// [`crate::ga::ga_runner::GaRunner`] via
+/// [`crate::ga::ga_runner::GaRunnerOptions::plot_report_path`]/
+/// [`crate::ga::ga_runner::GaRunnerOptions::plot_report_every`], which has
+/// [`crate::ga::ga_iterator::GaIterState::current_fitness`] — the
+/// reverse-mode-aware best-so-far fitness `RunReport` can't derive from a
+/// population alone — and renders once more when the run stops, regardless
+/// of `plot_report_every`. Mirrors [`crate::ga::csv_stats::CsvReporter`]'s
+/// relationship to [`crate::ga::csv_stats::CsvStatsRecorder`].
+pub struct PlotReporter {
+    path: PathBuf,
+    history: RefCell<Vec<(usize, Fitness, Fitness)>>,
+}
+
+impl PlotReporter {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            history: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Appends `(generation, best_fitness, mean_fitness)` and re-renders the
+    /// chart to `path`, logging (rather than propagating) a render failure,
+    /// the same as [`crate::ga::csv_stats::CsvReporter::report`] does for a
+    /// write failure — a run shouldn't abort over a reporting side effect.
+    pub fn record(&self, generation: usize, best_fitness: Fitness, mean_fitness: Fitness) {
+        self.history.borrow_mut().push((generation, best_fitness, mean_fitness));
+        if let Err(err) = self.render() {
+            tracing::log::warn!("failed to render fitness plot: {err}");
+        }
+    }
+
+    fn render(&self) -> Result<(), PlotError> {
+        let history = self.history.borrow();
+        match self.path.extension().and_then(|ext| ext.to_str()) {
+            Some("svg") => {
+                let backend = SVGBackend::new(&self.path, (960, 540)).into_drawing_area();
+                draw_best_mean(&backend, &history)
+            }
+            Some("png") => {
+                let backend = BitMapBackend::new(&self.path, (960, 540)).into_drawing_area();
+                draw_best_mean(&backend, &history)
+            }
+            other => Err(PlotError::UnsupportedExtension(
+                other.unwrap_or("").to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::fitness::FitnessWrapped;
+    use crate::ga::plot::RunReport;
+    use crate::ga::population::Population;
+    use crate::ga::{GaAction, GaContext};
+
+    fn population_of(fitnesses: &[u32]) -> Population<u32> {
+        Population {
+            pool_size: fitnesses.len(),
+            subjects: fitnesses
+                .iter()
+                .map(|&f| FitnessWrapped::new(f, f as f64))
+                .collect(),
+            memory_budget_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_plot_to_writes_svg_file() {
+        let report = RunReport::new();
+        for generation in 0..5 {
+            report.perform_action(&GaContext::new(generation), &mut population_of(&[1, 2, 3]));
+        }
+
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_run_report_test_{:?}.svg",
+            std::thread::current().id()
+        ));
+        report.plot_to(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(contents.contains("<svg"));
+    }
+
+    #[test]
+    fn test_plot_to_rejects_unsupported_extension() {
+        let report = RunReport::<u32>::new();
+        let err = report.plot_to("out.pdf").unwrap_err();
+        assert!(matches!(err, super::PlotError::UnsupportedExtension(_)));
+    }
+
+    #[test]
+    fn test_plot_reporter_renders_an_svg_on_each_record() {
+        use crate::ga::plot::PlotReporter;
+
+        let path = std::env::temp_dir().join(format!(
+            "simple_ga_plot_reporter_test_{:?}.svg",
+            std::thread::current().id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let reporter = PlotReporter::new(&path);
+        for generation in 0..5 {
+            reporter.record(generation, 1.0, 2.0);
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(contents.contains("<svg"));
+    }
+}
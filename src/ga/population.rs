@@ -14,11 +14,17 @@ use rayon::{
 use crate::ga::fitness::FitnessWrapped;
 use crate::ga::prune::PruneRandom;
 use crate::ga::select::SelectOtherRandom;
+use crate::ga::GaContext;
 
 #[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Population<Subject> {
     pub pool_size: usize,
     pub subjects: Vec<FitnessWrapped<Subject>>,
+    /// Caps the population's estimated in-memory footprint; once exceeded,
+    /// [`Self::enforce_memory_budget`] evicts the coldest (worst) subjects.
+    /// `None` leaves the population unbounded.
+    pub memory_budget_bytes: Option<usize>,
 }
 
 impl<Subject: Debug> Debug for Population<Subject> {
@@ -46,25 +52,68 @@ impl<Subject: Hash + Eq + PartialEq> Population<Subject> {
         pruner.prune_random(&mut self.subjects);
     }
 
-    pub fn select_random<'a, S>(&'a self, selector: S) -> S::Output
+    pub fn select_random<'a, S>(&'a self, context: &GaContext, selector: S) -> S::Output
     where
         S: SelectOtherRandom<&'a FitnessWrapped<Subject>>,
         Subject: 'a,
     {
-        selector.select_random(&self.subjects)
+        selector.select_random(context, &self.subjects)
+    }
+
+    /// Total-order comparison for fitness values, treating NaN as worse than
+    /// any real number regardless of sort direction, instead of panicking
+    /// the way `partial_cmp(...).unwrap()` would. A single bad fitness
+    /// evaluation (e.g. a `0.0 / 0.0` in a user's `Fit` impl) then just
+    /// sorts that subject to the back rather than aborting the run.
+    /// `rev` selects descending (`true`) vs ascending (`false`) for the
+    /// non-NaN case, matching [`Self::_sort`]/[`Self::_sort_rev`].
+    fn cmp_fitness_total_order(a: super::fitness::Fitness, b: super::fitness::Fitness, rev: bool) -> Ordering {
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) if rev => b.partial_cmp(&a).unwrap(),
+            (false, false) => a.partial_cmp(&b).unwrap(),
+        }
     }
 
     fn _sort(a: &FitnessWrapped<Subject>, b: &FitnessWrapped<Subject>) -> Ordering {
-        a.fitness().partial_cmp(&b.fitness()).unwrap()
+        Self::cmp_fitness_total_order(a.fitness(), b.fitness(), false)
     }
 
     fn _sort_rev(a: &FitnessWrapped<Subject>, b: &FitnessWrapped<Subject>) -> Ordering {
-        b.fitness().partial_cmp(&a.fitness()).unwrap()
+        Self::cmp_fitness_total_order(a.fitness(), b.fitness(), true)
     }
 
     pub fn add(&mut self, subject: FitnessWrapped<Subject>) {
         self.subjects.push(subject);
     }
+
+    /// Rough per-subject memory footprint used by [`Self::enforce_memory_budget`].
+    /// Only accounts for the subject's stack footprint, not any heap
+    /// allocations it owns (e.g. a `Vec`-backed genome), so it under-reports
+    /// real usage for subjects with heap data.
+    fn estimated_subject_bytes() -> usize {
+        std::mem::size_of::<FitnessWrapped<Subject>>() + std::mem::size_of::<Subject>()
+    }
+
+    /// Evicts the coldest (worst) subjects once the population's estimated
+    /// memory footprint exceeds `memory_budget_bytes`. Call after
+    /// `sort()`/`sort_rev()` so the worst subjects are trailing.
+    ///
+    /// This evicts rather than spills to disk: a transparent memory-mapped
+    /// reload would require subjects to be serializable, which `GaSubject`
+    /// does not guarantee, so that part is left for a future change.
+    pub fn enforce_memory_budget(&mut self) {
+        let Some(memory_budget_bytes) = self.memory_budget_bytes else {
+            return;
+        };
+        let per_subject = Self::estimated_subject_bytes().max(1);
+        let max_subjects = memory_budget_bytes / per_subject;
+        if self.subjects.len() > max_subjects {
+            self.subjects.truncate(max_subjects);
+        }
+    }
 }
 
 #[cfg(not(feature = "parallel"))]
@@ -99,6 +148,11 @@ where
     }
 }
 
+/// Below this population size, rayon's work-stealing overhead outweighs the
+/// benefit of sorting in parallel, so we fall back to a plain sequential sort.
+#[cfg(feature = "parallel")]
+const PARALLEL_SORT_THRESHOLD: usize = 100;
+
 #[cfg(feature = "parallel")]
 impl<Subject> Population<Subject>
 where
@@ -106,11 +160,19 @@ where
 {
     pub fn sort(&mut self) {
         let population = &mut self.subjects;
-        population.par_sort_by(Self::_sort);
+        if population.len() <= PARALLEL_SORT_THRESHOLD {
+            population.sort_by(Self::_sort);
+        } else {
+            population.par_sort_by(Self::_sort);
+        }
     }
     pub fn sort_rev(&mut self) {
         let population = &mut self.subjects;
-        population.par_sort_by(Self::_sort_rev);
+        if population.len() <= PARALLEL_SORT_THRESHOLD {
+            population.sort_by(Self::_sort_rev);
+        } else {
+            population.par_sort_by(Self::_sort_rev);
+        }
     }
 
     pub fn iter(&self) -> Iter<FitnessWrapped<Subject>> {
@@ -137,17 +199,28 @@ mod tests {
     use crate::ga::population::Population;
     use crate::ga::prune::PruneSingleBackSkipFirst;
     use crate::ga::select::{SelectOther, SelectRandomManyWithBias};
+    use crate::ga::GaContext;
     use crate::util::Bias;
 
     fn test_subject(id: u32) -> FitnessWrapped<u32> {
         FitnessWrapped::new(id, id as Fitness)
     }
 
+    // `FitnessWrapped` stores its subject behind an `Arc`, not an `Rc`, so
+    // `Population<Subject>` is genuinely `Send` for any `Send + Sync` subject,
+    // enabling background-thread and multi-population execution.
+    #[test]
+    fn test_population_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<Population<u32>>();
+    }
+
     impl From<Range<u32>> for Population<u32> {
         fn from(range: Range<u32>) -> Self {
             Population {
                 pool_size: range.len(),
                 subjects: range.into_iter().map(test_subject).collect(),
+                memory_budget_bytes: None,
             }
         }
     }
@@ -168,29 +241,33 @@ mod tests {
 
     #[test]
     fn test_generic_select() {
+        let context = GaContext::default();
         let population = make_population(2);
         for n in 0..=2 {
-            let selected = population.select_random(SelectRandomManyWithBias::new(n, Bias::Front));
+            let selected =
+                population.select_random(&context, SelectRandomManyWithBias::new(n, Bias::Front));
             assert_eq!(selected.len(), n);
         }
     }
 
     #[test]
     fn test_select_front() {
+        let context = GaContext::default();
         let population = make_population(2);
         for n in 0..=2 {
-            let selected =
-                SelectRandomManyWithBias::new(n, Bias::Front).select_from(&population.subjects);
+            let selected = SelectRandomManyWithBias::new(n, Bias::Front)
+                .select_from(&context, &population.subjects);
             assert_eq!(selected.len(), n);
         }
     }
 
     #[test]
     fn test_select_back() {
+        let context = GaContext::default();
         let population = make_population(2);
         for n in 0..=2 {
-            let selected =
-                SelectRandomManyWithBias::new(n, Bias::Back).select_from(&population.subjects);
+            let selected = SelectRandomManyWithBias::new(n, Bias::Back)
+                .select_from(&context, &population.subjects);
             assert_eq!(selected.len(), n);
         }
     }
@@ -210,6 +287,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sort_treats_nan_fitness_as_worst() {
+        let mut population = make_population(2);
+        population.subjects.push(FitnessWrapped::new(99, Fitness::NAN));
+        population.sort();
+        assert!(population.subjects.last().unwrap().fitness().is_nan());
+    }
+
+    #[test]
+    fn test_sort_rev_treats_nan_fitness_as_worst() {
+        let mut population = make_population(2);
+        population.subjects.push(FitnessWrapped::new(99, Fitness::NAN));
+        population.sort_rev();
+        assert!(population.subjects.last().unwrap().fitness().is_nan());
+    }
+
+    #[test]
+    fn test_sort_above_parallel_threshold() {
+        let size = 150;
+        let mut population = make_population(size);
+        population.subjects.reverse();
+        population.sort();
+        assert_eq!(
+            population.subjects,
+            (0..size as u32).map(test_subject).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_enforce_memory_budget_evicts_trailing_subjects() {
+        let mut population = make_population(5);
+        let per_subject = Population::<u32>::estimated_subject_bytes();
+        population.memory_budget_bytes = Some(per_subject * 3);
+        population.enforce_memory_budget();
+        assert_eq!(population.subjects.len(), 3);
+        assert_eq!(
+            population.subjects,
+            vec![test_subject(0), test_subject(1), test_subject(2)]
+        );
+    }
+
+    #[test]
+    fn test_enforce_memory_budget_is_noop_without_budget() {
+        let mut population = make_population(5);
+        population.enforce_memory_budget();
+        assert_eq!(population.subjects.len(), 5);
+    }
+
     #[test]
     fn test_add() {
         let mut population = make_population(0);
@@ -1,6 +1,7 @@
 use crate::util::Odds;
 
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Probability {
     /// Represents a probability of 0.0 (never happening).
     Never,
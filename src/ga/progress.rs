@@ -0,0 +1,102 @@
+//! [`ProgressReporter`]: a run-driven progress hook, so a caller can drive a
+//! progress bar, log line, or metrics sink from [`crate::ga::ga_runner::GaRunner`]
+//! without hand-rolling it in `before_each_generation`/`after_each_generation`
+//! (which only ever see a `&mut GaIterState`, not elapsed time or a
+//! completion fraction derived from the run's fitness range).
+
+use std::time::Duration;
+
+use crate::ga::fitness::Fitness;
+use crate::ga::termination::TerminationReason;
+
+/// Snapshot passed to [`ProgressReporter`] once per generation (and once
+/// more, unchanged aside from whatever the last generation produced, to
+/// [`ProgressReporter::on_finish`] when the run stops).
+#[derive(Debug, Clone, Copy)]
+pub struct ProgressUpdate {
+    pub generation: usize,
+    pub population_size: usize,
+    /// Wall-clock time since [`crate::ga::ga_runner::GaRunner::run`] started.
+    pub elapsed: Duration,
+    /// The reverse-mode-aware best fitness seen so far, per
+    /// [`crate::ga::ga_iterator::GaIterState::current_fitness`]. `None`
+    /// before the first generation completes.
+    pub best_fitness: Option<Fitness>,
+    /// How far [`Self::best_fitness`] has travelled from
+    /// `fitness_initial_to_target_range.start` towards `.end`, clamped to
+    /// `[0.0, 1.0]`. `None` if `best_fitness` is `None`, or the range has
+    /// zero width (nothing to divide by).
+    pub progress_fraction: Option<f64>,
+}
+
+/// Invoked by [`crate::ga::ga_runner::GaRunner`] via
+/// [`crate::ga::ga_runner::GaRunnerOptions::progress_reporter`] once per
+/// generation, and once more after the run terminates. For a built-in
+/// adapter suitable for an `indicatif` progress bar, see
+/// [`IndicatifProgressReporter`] (behind the `indicatif` feature).
+pub trait ProgressReporter {
+    fn on_generation(&self, update: &ProgressUpdate);
+
+    /// Called once, after the final generation, with the reason the run
+    /// stopped. Defaults to a no-op: a reporter that treats every
+    /// generation identically (e.g. a plain log line) doesn't need to do
+    /// anything different at run end.
+    fn on_finish(&self, update: &ProgressUpdate, termination_reason: TerminationReason) {
+        let _ = (update, termination_reason);
+    }
+}
+
+#[cfg(feature = "indicatif")]
+mod indicatif_adapter {
+    use indicatif::{ProgressBar, ProgressStyle};
+
+    use super::{ProgressReporter, ProgressUpdate};
+    use crate::ga::termination::TerminationReason;
+
+    /// Drives an `indicatif` [`ProgressBar`] from [`ProgressReporter`]
+    /// updates. Uses [`ProgressUpdate::progress_fraction`] for the bar's
+    /// position (0-100, with indicatif deriving its own ETA from observed
+    /// throughput) when it's available, and just advances the spinner on
+    /// the generation counter when it isn't (e.g. no meaningful fitness
+    /// range was configured).
+    pub struct IndicatifProgressReporter {
+        bar: ProgressBar,
+    }
+
+    impl IndicatifProgressReporter {
+        pub fn new() -> Self {
+            let bar = ProgressBar::new(100);
+            if let Ok(style) = ProgressStyle::with_template(
+                "{spinner:.green} gen {msg} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {percent}% (eta {eta})",
+            ) {
+                bar.set_style(style.progress_chars("#>-"));
+            }
+            Self { bar }
+        }
+    }
+
+    impl Default for IndicatifProgressReporter {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl ProgressReporter for IndicatifProgressReporter {
+        fn on_generation(&self, update: &ProgressUpdate) {
+            self.bar.set_message(update.generation.to_string());
+            if let Some(fraction) = update.progress_fraction {
+                self.bar.set_position((fraction.clamp(0.0, 1.0) * 100.0) as u64);
+            } else {
+                self.bar.tick();
+            }
+        }
+
+        fn on_finish(&self, update: &ProgressUpdate, termination_reason: TerminationReason) {
+            self.bar
+                .finish_with_message(format!("gen {} - {termination_reason}", update.generation));
+        }
+    }
+}
+
+#[cfg(feature = "indicatif")]
+pub use indicatif_adapter::IndicatifProgressReporter;
@@ -0,0 +1,207 @@
+use std::hash::Hash;
+use std::io;
+use std::marker::PhantomData;
+use std::net::ToSocketAddrs;
+use std::sync::{Arc, RwLock};
+use std::thread::JoinHandle;
+
+use crate::ga::population::Population;
+use crate::ga::stats::{compute_stats, PopulationStats};
+use crate::ga::{GaAction, GaContext};
+
+#[derive(Debug, Default, Clone, Copy)]
+struct PrometheusSnapshot {
+    generation: usize,
+    population_size: usize,
+    stats: Option<PopulationStats>,
+}
+
+/// Metrics state shared between [`PrometheusExporter`] (which updates it once
+/// per generation) and the background HTTP listener started by
+/// [`serve_metrics`] (which reads it on every scrape). Kept separate from the
+/// exporter itself so the listener doesn't need to outlive, or be driven by,
+/// the `GaAction` it reports on.
+#[derive(Default)]
+pub struct PrometheusMetrics {
+    snapshot: RwLock<PrometheusSnapshot>,
+}
+
+impl PrometheusMetrics {
+    fn record(&self, generation: usize, population_size: usize, stats: Option<PopulationStats>) {
+        *self.snapshot.write().expect("metrics lock poisoned") = PrometheusSnapshot {
+            generation,
+            population_size,
+            stats,
+        };
+    }
+
+    /// Renders the current snapshot in Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let snapshot = *self.snapshot.read().expect("metrics lock poisoned");
+        let mut out = String::new();
+        out.push_str("# HELP simple_ga_generation Current generation number.\n");
+        out.push_str("# TYPE simple_ga_generation gauge\n");
+        out.push_str(&format!("simple_ga_generation {}\n", snapshot.generation));
+        out.push_str("# HELP simple_ga_population_size Number of subjects in the population.\n");
+        out.push_str("# TYPE simple_ga_population_size gauge\n");
+        out.push_str(&format!(
+            "simple_ga_population_size {}\n",
+            snapshot.population_size
+        ));
+        if let Some(stats) = snapshot.stats {
+            out.push_str("# HELP simple_ga_min_fitness Minimum fitness in the population.\n");
+            out.push_str("# TYPE simple_ga_min_fitness gauge\n");
+            out.push_str(&format!("simple_ga_min_fitness {}\n", stats.min_fitness));
+            out.push_str("# HELP simple_ga_max_fitness Maximum fitness in the population.\n");
+            out.push_str("# TYPE simple_ga_max_fitness gauge\n");
+            out.push_str(&format!("simple_ga_max_fitness {}\n", stats.max_fitness));
+            out.push_str("# HELP simple_ga_mean_fitness Mean fitness of the population.\n");
+            out.push_str("# TYPE simple_ga_mean_fitness gauge\n");
+            out.push_str(&format!("simple_ga_mean_fitness {}\n", stats.mean_fitness));
+            out.push_str(
+                "# HELP simple_ga_stddev_fitness Standard deviation of fitness in the population.\n",
+            );
+            out.push_str("# TYPE simple_ga_stddev_fitness gauge\n");
+            out.push_str(&format!(
+                "simple_ga_stddev_fitness {}\n",
+                stats.stddev_fitness
+            ));
+            out.push_str("# HELP simple_ga_diversity Number of distinct subjects divided by population size.\n");
+            out.push_str("# TYPE simple_ga_diversity gauge\n");
+            out.push_str(&format!("simple_ga_diversity {}\n", stats.diversity));
+        }
+        out
+    }
+}
+
+/// Records per-generation [`PopulationStats`] into a shared
+/// [`PrometheusMetrics`], for scraping over HTTP via [`serve_metrics`] while
+/// the GA runs as a long-lived service rather than a one-shot batch job.
+/// Register it as an action the same way [`crate::ga::csv_stats::CsvStatsRecorder`]
+/// is registered, then pass [`PrometheusExporter::metrics`] to
+/// [`serve_metrics`] once, before the run starts.
+pub struct PrometheusExporter<Subject> {
+    metrics: Arc<PrometheusMetrics>,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> Default for PrometheusExporter<Subject> {
+    fn default() -> Self {
+        Self {
+            metrics: Arc::new(PrometheusMetrics::default()),
+            _subject: PhantomData,
+        }
+    }
+}
+
+impl<Subject> PrometheusExporter<Subject> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A handle to the shared metrics this exporter updates, to be scraped
+    /// via [`serve_metrics`] or rendered directly with [`PrometheusMetrics::render`].
+    pub fn metrics(&self) -> Arc<PrometheusMetrics> {
+        self.metrics.clone()
+    }
+}
+
+macro_rules! impl_record_generation {
+    () => {
+        fn record_generation(&self, context: &GaContext, population: &Population<Subject>) {
+            let stats = compute_stats(population);
+            self.metrics
+                .record(context.generation, population.subjects.len(), stats);
+        }
+    };
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash> PrometheusExporter<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Send + Sync> PrometheusExporter<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash> GaAction for PrometheusExporter<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("prometheus", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Send + Sync> GaAction for PrometheusExporter<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("prometheus", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+/// Handle to the background thread started by [`serve_metrics`]. Dropping it
+/// (or calling [`PrometheusServerHandle::stop`]) unblocks the listener and
+/// joins the thread.
+pub struct PrometheusServerHandle {
+    server: Arc<tiny_http::Server>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl PrometheusServerHandle {
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for PrometheusServerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Spawns a background thread that serves `metrics` as `GET /metrics` in
+/// Prometheus text exposition format at `addr`, independent of any request
+/// path or method (this is a metrics-only endpoint, not a general HTTP
+/// server). Intended to be started once, alongside a [`PrometheusExporter`]
+/// registered as an action, before calling `GaRunner::run_generations`.
+pub fn serve_metrics(
+    metrics: Arc<PrometheusMetrics>,
+    addr: impl ToSocketAddrs,
+) -> io::Result<PrometheusServerHandle> {
+    let server =
+        tiny_http::Server::http(addr).map_err(|err| io::Error::other(err.to_string()))?;
+    let server = Arc::new(server);
+    let listener = server.clone();
+    let thread = std::thread::spawn(move || {
+        for request in listener.incoming_requests() {
+            let body = metrics.render();
+            let header = tiny_http::Header::from_bytes(
+                &b"Content-Type"[..],
+                &b"text/plain; version=0.0.4"[..],
+            )
+            .expect("static header is valid");
+            let response = tiny_http::Response::from_string(body).with_header(header);
+            let _ = request.respond(response);
+        }
+    });
+    Ok(PrometheusServerHandle {
+        server,
+        thread: Some(thread),
+    })
+}
@@ -35,8 +35,10 @@ where
 {
     type Subject = Subject;
 
-    fn perform_action(&self, _context: &GaContext, population: &mut Population<Self::Subject>) {
-        self.action.prune(&mut population.subjects);
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("prune", context, population, |population| {
+            self.action.prune(&mut population.subjects);
+        });
     }
 }
 
@@ -68,7 +70,7 @@ macro_rules! create_sized_prune_skip_first {
                         Bias::Front | Bias::BackInverse => {
                             PruneSingleFrontSkipFirst.prune_random(items);
                         }
-                        Bias::Back | Bias::FrontInverse => {
+                        Bias::Back | Bias::FrontInverse | Bias::Middle | Bias::Uniform => {
                             PruneSingleBackSkipFirst.prune_random(items);
                         }
                     };
@@ -98,7 +100,7 @@ macro_rules! create_sized_prune {
                         Bias::Front | Bias::BackInverse => {
                             PruneSingleFront.prune_random(items);
                         }
-                        Bias::Back | Bias::FrontInverse => {
+                        Bias::Back | Bias::FrontInverse | Bias::Middle | Bias::Uniform => {
                             PruneSingleBack.prune_random(items);
                         }
                     };
@@ -278,6 +280,40 @@ impl<T> PruneRandom<Vec<T>> for PruneExtraFrontSkipFirst {
     }
 }
 
+/// Removes any subject older than `max_age` generations
+/// (`context.generation - subject.generation_born() > max_age`), regardless
+/// of fitness, so a long-lived elite that's stopped improving doesn't linger
+/// forever just because nothing else has beaten it yet. Implements
+/// [`GaAction`] directly rather than [`PruneOther`] since it needs
+/// [`GaContext::generation`], which [`PruneOther::prune`] doesn't receive.
+/// Pair with an elitism/inflate action that guarantees at least one survivor,
+/// since this can empty the population if every subject is stale.
+#[derive(Debug, Copy, Clone)]
+pub struct PruneOlderThan<Subject> {
+    pub max_age: usize,
+    _marker: PhantomData<Subject>,
+}
+
+impl<Subject> PruneOlderThan<Subject> {
+    pub fn new(max_age: usize) -> Self {
+        Self { max_age, _marker: PhantomData }
+    }
+}
+
+impl<Subject> GaAction for PruneOlderThan<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("prune_older_than", context, population, |population| {
+            let current_generation = context.generation;
+            let max_age = self.max_age;
+            population
+                .subjects
+                .retain(|wrapped| current_generation.saturating_sub(wrapped.generation_born()) <= max_age);
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -304,4 +340,37 @@ mod tests {
             assert_eq!(items, vec![1]);
         }
     }
+
+    mod prune_older_than {
+        use super::*;
+
+        fn population(generations_born: Vec<usize>) -> Population<i32> {
+            let subjects = generations_born
+                .into_iter()
+                .map(|generation_born| {
+                    let mut wrapped = FitnessWrapped::new(0, 0.0);
+                    wrapped.set_generation_born(generation_born);
+                    wrapped
+                })
+                .collect();
+            Population { pool_size: 0, subjects, memory_budget_bytes: None }
+        }
+
+        #[test]
+        fn test_removes_subjects_older_than_max_age() {
+            let mut population = population(vec![0, 5, 9, 10]);
+            let action = PruneOlderThan::new(2);
+            action.perform_action(&GaContext::new(10), &mut population);
+            let births: Vec<usize> = population.subjects.iter().map(|s| s.generation_born()).collect();
+            assert_eq!(births, vec![9, 10]);
+        }
+
+        #[test]
+        fn test_keeps_everything_within_max_age() {
+            let mut population = population(vec![8, 9, 10]);
+            let action = PruneOlderThan::new(2);
+            action.perform_action(&GaContext::new(10), &mut population);
+            assert_eq!(population.subjects.len(), 3);
+        }
+    }
 }
@@ -0,0 +1,296 @@
+use std::hash::Hash;
+use std::io;
+use std::marker::PhantomData;
+use std::net::ToSocketAddrs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tiny_http::Method;
+
+use crate::ga::population::Population;
+use crate::ga::stats::{compute_stats, PopulationStats};
+use crate::ga::{GaAction, GaContext};
+use crate::util::Odds;
+
+#[derive(Debug, Default, Clone, Serialize)]
+struct RemoteStatus {
+    generation: usize,
+    population_size: usize,
+    paused: bool,
+    stats: Option<PopulationStats>,
+}
+
+/// Shared state updated once per generation by [`RemoteControl`] and read (or
+/// written) by the HTTP server started with [`serve_remote_control`].
+#[derive(Default)]
+pub struct RemoteControlState {
+    status: RwLock<RemoteStatus>,
+    best_subject_json: RwLock<Option<String>>,
+    paused: AtomicBool,
+    mutation_chance_override: RwLock<Option<Odds>>,
+    checkpoint_requested: AtomicBool,
+}
+
+impl RemoteControlState {
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// The most recently requested overall mutation chance, set via
+    /// `POST /mutation-rate`. `GenericMutator`'s options are fixed at
+    /// construction with no live setter, so this crate can't apply the
+    /// override itself; a caller using the built-in mutator would read this
+    /// once per generation and feed it into their own mutation action.
+    pub fn mutation_chance_override(&self) -> Option<Odds> {
+        *self
+            .mutation_chance_override
+            .read()
+            .expect("remote control lock poisoned")
+    }
+
+    /// Returns `true`, and clears the flag, the first time it's read after a
+    /// `POST /checkpoint` request. Intended to be polled once per generation
+    /// by the caller's own loop, which performs the actual write via
+    /// [`crate::ga::checkpoint::write_checkpoint`] — this type only records
+    /// that a checkpoint was asked for, since it doesn't own the population
+    /// or options needed to build one.
+    pub fn take_checkpoint_request(&self) -> bool {
+        self.checkpoint_requested.swap(false, Ordering::SeqCst)
+    }
+}
+
+/// Records per-generation status into a shared [`RemoteControlState`] and
+/// pauses the calling (GA) thread in place while `paused` is set, so a
+/// headless run driven by a background thread can be inspected and steered
+/// over HTTP via [`serve_remote_control`].
+///
+/// Register it as an action the same way [`crate::ga::csv_stats::CsvStatsRecorder`]
+/// is registered.
+pub struct RemoteControl<Subject> {
+    state: Arc<RemoteControlState>,
+    pause_poll_interval: Duration,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> Default for RemoteControl<Subject> {
+    fn default() -> Self {
+        Self {
+            state: Arc::new(RemoteControlState::default()),
+            pause_poll_interval: Duration::from_millis(50),
+            _subject: PhantomData,
+        }
+    }
+}
+
+impl<Subject> RemoteControl<Subject> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A handle to the shared state this action updates, to be passed to
+    /// [`serve_remote_control`] once, before the run starts.
+    pub fn state(&self) -> Arc<RemoteControlState> {
+        self.state.clone()
+    }
+}
+
+macro_rules! impl_record_generation {
+    () => {
+        fn record_generation(&self, context: &GaContext, population: &Population<Subject>) {
+            let stats = compute_stats(population);
+            *self.state.status.write().expect("remote control lock poisoned") = RemoteStatus {
+                generation: context.generation,
+                population_size: population.subjects.len(),
+                paused: self.state.is_paused(),
+                stats,
+            };
+
+            if let Some(best) = population.iter().min_by(|a, b| {
+                a.fitness()
+                    .partial_cmp(&b.fitness())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }) {
+                match serde_json::to_string(best.subject_ref()) {
+                    Ok(json) => {
+                        *self
+                            .state
+                            .best_subject_json
+                            .write()
+                            .expect("remote control lock poisoned") = Some(json);
+                    }
+                    Err(err) => tracing::log::warn!("failed to serialize best subject: {err}"),
+                }
+            }
+
+            while self.state.is_paused() {
+                std::thread::sleep(self.pause_poll_interval);
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq + Serialize> RemoteControl<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync + Serialize> RemoteControl<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq + Serialize> GaAction for RemoteControl<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("remote_control", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync + Serialize> GaAction for RemoteControl<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("remote_control", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+/// Handle to the background thread started by [`serve_remote_control`].
+/// Dropping it (or calling [`RemoteControlServerHandle::stop`]) unblocks the
+/// listener and joins the thread.
+pub struct RemoteControlServerHandle {
+    server: Arc<tiny_http::Server>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl RemoteControlServerHandle {
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for RemoteControlServerHandle {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+#[derive(Deserialize)]
+struct MutationRateRequest {
+    value: Odds,
+}
+
+fn respond_json(request: tiny_http::Request, status: u16, body: &str) {
+    let header =
+        tiny_http::Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+            .expect("static header is valid");
+    let response = tiny_http::Response::from_string(body.to_string())
+        .with_status_code(status)
+        .with_header(header);
+    let _ = request.respond(response);
+}
+
+fn respond_empty(request: tiny_http::Request, status: u16) {
+    let response = tiny_http::Response::from_string("").with_status_code(status);
+    let _ = request.respond(response);
+}
+
+/// Spawns a background thread serving a small control API for `state` at
+/// `addr`:
+/// - `GET /status` — generation, population size, paused flag, and fitness
+///   summary statistics as JSON.
+/// - `GET /best` — the most recently seen best subject as JSON (`404` until
+///   at least one generation has run).
+/// - `POST /pause` / `POST /resume` — pause or resume the GA thread in
+///   place, at the point [`RemoteControl::perform_action`] next runs.
+/// - `POST /mutation-rate` — body `{"value": <f64>}`; records the requested
+///   overall mutation chance for [`RemoteControlState::mutation_chance_override`]
+///   to pick up (applying it is left to the caller, see that method's docs).
+/// - `POST /checkpoint` — flags a checkpoint request for
+///   [`RemoteControlState::take_checkpoint_request`] to pick up.
+///
+/// Intended to be started once, alongside a [`RemoteControl`] registered as
+/// an action, before the GA loop runs on its own (often background) thread.
+pub fn serve_remote_control(
+    state: Arc<RemoteControlState>,
+    addr: impl ToSocketAddrs,
+) -> io::Result<RemoteControlServerHandle> {
+    let server =
+        tiny_http::Server::http(addr).map_err(|err| io::Error::other(err.to_string()))?;
+    let server = Arc::new(server);
+    let listener = server.clone();
+    let thread = std::thread::spawn(move || {
+        for mut request in listener.incoming_requests() {
+            match (request.method().clone(), request.url().to_string().as_str()) {
+                (Method::Get, "/status") => {
+                    let status = state.status.read().expect("remote control lock poisoned");
+                    let body = serde_json::to_string(&*status).unwrap_or_default();
+                    drop(status);
+                    respond_json(request, 200, &body);
+                }
+                (Method::Get, "/best") => {
+                    let best = state
+                        .best_subject_json
+                        .read()
+                        .expect("remote control lock poisoned");
+                    match &*best {
+                        Some(json) => respond_json(request, 200, json),
+                        None => respond_empty(request, 404),
+                    }
+                }
+                (Method::Post, "/pause") => {
+                    state.paused.store(true, Ordering::SeqCst);
+                    respond_empty(request, 200);
+                }
+                (Method::Post, "/resume") => {
+                    state.paused.store(false, Ordering::SeqCst);
+                    respond_empty(request, 200);
+                }
+                (Method::Post, "/mutation-rate") => {
+                    let mut body = String::new();
+                    if request.as_reader().read_to_string(&mut body).is_ok() {
+                        match serde_json::from_str::<MutationRateRequest>(&body) {
+                            Ok(parsed) => {
+                                *state
+                                    .mutation_chance_override
+                                    .write()
+                                    .expect("remote control lock poisoned") = Some(parsed.value);
+                                respond_empty(request, 200);
+                            }
+                            Err(_) => respond_empty(request, 400),
+                        }
+                    } else {
+                        respond_empty(request, 400);
+                    }
+                }
+                (Method::Post, "/checkpoint") => {
+                    state.checkpoint_requested.store(true, Ordering::SeqCst);
+                    respond_empty(request, 200);
+                }
+                _ => respond_empty(request, 404),
+            }
+        }
+    });
+    Ok(RemoteControlServerHandle {
+        server,
+        thread: Some(thread),
+    })
+}
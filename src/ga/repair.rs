@@ -0,0 +1,116 @@
+//! Repair hook: fixes offspring that mutation/reproduction left invalid
+//! (e.g. a permutation with a duplicated gene, a real-valued genome outside
+//! its bounds) instead of every [`crate::ga::mutation::ApplyMutation`]/
+//! [`crate::ga::reproduction::ApplyReproduction`] impl having to guarantee
+//! validity itself.
+
+use crate::ga::fitness::{Fit, Fitness, FitnessWrapped};
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+
+/// Fixes a single subject that may have ended up invalid, e.g. after
+/// mutation or crossover. Implementors that never produce invalid subjects
+/// can simply clone `subject` unchanged.
+pub trait Repair {
+    type Subject;
+    fn repair(&self, context: &GaContext, subject: &Self::Subject) -> Self::Subject;
+}
+
+/// Wraps `inner`, repairing every subject in the population (and
+/// re-measuring its fitness, since a repair can change it) right after
+/// `inner` runs — pairs with [`crate::ga::mutation::GenericMutator`]/
+/// [`crate::ga::reproduction::GenericReproducer`] the same way
+/// [`crate::ga::elite::ElitismAction`] pairs with an inner action.
+pub struct RepairAction<Repairer, Inner> {
+    repairer: Repairer,
+    inner: Inner,
+}
+
+impl<Repairer, Inner> RepairAction<Repairer, Inner> {
+    pub fn new(repairer: Repairer, inner: Inner) -> Self {
+        Self { repairer, inner }
+    }
+}
+
+impl<Repairer, Inner> GaAction for RepairAction<Repairer, Inner>
+where
+    Inner: GaAction,
+    Inner::Subject: Fit<Fitness>,
+    Repairer: Repair<Subject = Inner::Subject>,
+{
+    type Subject = Inner::Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("repair", context, population, |population| {
+            self.inner.perform_action(context, population);
+            for wrapped in population.subjects.iter_mut() {
+                let repaired = self.repairer.repair(context, wrapped.subject_ref());
+                let fitness = repaired.measure();
+                *wrapped = FitnessWrapped::new(repaired, fitness);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Clamped(i32);
+
+    impl Fit<Fitness> for Clamped {
+        fn measure(&self) -> Fitness {
+            self.0 as Fitness
+        }
+    }
+
+    struct ClampToTen;
+    impl Repair for ClampToTen {
+        type Subject = Clamped;
+        fn repair(&self, _context: &GaContext, subject: &Self::Subject) -> Self::Subject {
+            Clamped(subject.0.min(10))
+        }
+    }
+
+    struct BlowUpAction;
+    impl GaAction for BlowUpAction {
+        type Subject = Clamped;
+        fn perform_action(&self, _context: &GaContext, population: &mut Population<Self::Subject>) {
+            for wrapped in population.subjects.iter_mut() {
+                *wrapped = FitnessWrapped::new(Clamped(wrapped.subject_ref().0 + 100), 0.0);
+            }
+        }
+    }
+
+    fn population(values: Vec<i32>) -> Population<Clamped> {
+        let subjects = values
+            .into_iter()
+            .map(|v| FitnessWrapped::new(Clamped(v), v as Fitness))
+            .collect();
+        Population {
+            pool_size: 0,
+            subjects,
+            memory_budget_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_repair_action_fixes_invalid_offspring_after_inner_runs() {
+        let mut population = population(vec![1, 2]);
+        let action = RepairAction::new(ClampToTen, BlowUpAction);
+        action.perform_action(&GaContext::default(), &mut population);
+        let values: Vec<i32> = population.subjects.iter().map(|s| s.subject_ref().0).collect();
+        assert_eq!(values, vec![10, 10]);
+    }
+
+    #[test]
+    fn test_repair_action_recomputes_fitness_after_repair() {
+        let mut population = population(vec![1, 2]);
+        let action = RepairAction::new(ClampToTen, BlowUpAction);
+        action.perform_action(&GaContext::default(), &mut population);
+        for wrapped in &population.subjects {
+            assert_eq!(wrapped.fitness(), 10.0);
+        }
+    }
+}
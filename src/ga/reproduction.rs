@@ -1,10 +1,16 @@
+use std::cell::RefCell;
+use std::fmt;
 use std::hash::Hash;
 use std::marker::PhantomData;
 
 use derivative::Derivative;
 use itertools::Itertools;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
-use crate::ga::fitness::{Fitness, FitnessWrapped};
+pub mod operators;
+
+use crate::ga::fitness::{wrap_batch, Fit, FitBatch, Fitness, FitnessWrapped};
 use crate::ga::population::Population;
 use crate::ga::select::SelectOther;
 use crate::ga::subject::GaSubject;
@@ -15,11 +21,90 @@ pub fn asexual_reproduction<Subject: Clone>(subject: &Subject) -> Subject {
     subject.clone()
 }
 
+/// Generic uniform crossover for any genome that can be viewed as a slice of
+/// genes and rebuilt from one: each gene is swapped between the two parents
+/// independently with probability `rate`. Works for `RealGenome`, `Vec<T>`
+/// newtypes, or any other `Subject: AsRef<[T]> + FromIterator<T>`, so callers
+/// don't have to hand-roll per-gene zip/mix code for their own genome types.
+pub struct UniformCrossover<Subject, T> {
+    pub rate: Odds,
+    _subject: PhantomData<Subject>,
+    _gene: PhantomData<T>,
+}
+
+impl<Subject, T> UniformCrossover<Subject, T> {
+    pub fn new(rate: Odds) -> Self {
+        Self {
+            rate,
+            _subject: PhantomData,
+            _gene: PhantomData,
+        }
+    }
+}
+
+impl<Subject, T> Default for UniformCrossover<Subject, T> {
+    /// Defaults to a 50/50 swap odds, the classic uniform crossover.
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl<Subject, T> Clone for UniformCrossover<Subject, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.rate)
+    }
+}
+
+impl<Subject, T> fmt::Debug for UniformCrossover<Subject, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UniformCrossover").field("rate", &self.rate).finish()
+    }
+}
+
+impl<Subject, T> ApplyReproduction for UniformCrossover<Subject, T>
+where
+    T: Clone,
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq + AsRef<[T]> + FromIterator<T>,
+{
+    type Subject = Subject;
+
+    fn apply(
+        &self,
+        _context: &GaContext,
+        subject_a: &Self::Subject,
+        subject_b: &Self::Subject,
+    ) -> Option<ReproductionResult<Self::Subject>> {
+        let (a, b) = (subject_a.as_ref(), subject_b.as_ref());
+        assert_eq!(a.len(), b.len(), "UniformCrossover requires equal-length genomes");
+        let mut child_a = Vec::with_capacity(a.len());
+        let mut child_b = Vec::with_capacity(a.len());
+        for (gene_a, gene_b) in a.iter().zip(b.iter()) {
+            if coin_flip(self.rate) {
+                child_a.push(gene_b.clone());
+                child_b.push(gene_a.clone());
+            } else {
+                child_a.push(gene_a.clone());
+                child_b.push(gene_b.clone());
+            }
+        }
+        Some(ReproductionResult::Double(
+            Subject::from_iter(child_a),
+            Subject::from_iter(child_b),
+        ))
+    }
+
+    fn fitness(subject: &Self::Subject) -> Fitness {
+        subject.measure()
+    }
+}
+
 #[derive(Clone)]
 pub struct GenericReproducer<Reproducer, Selector, Subject, Actions> {
     _marker: PhantomData<Subject>,
     _reproducer: PhantomData<Reproducer>,
     options: ApplyReproductionOptions<Actions, Selector>,
+    // reused across generations to avoid a fresh Vec allocation per `perform_action` call
+    offspring_scratch: RefCell<Vec<FitnessWrapped<Subject>>>,
 }
 
 impl<Reproducer, Selector, Subject, Actions>
@@ -30,6 +115,7 @@ impl<Reproducer, Selector, Subject, Actions>
             _marker: PhantomData,
             _reproducer: PhantomData,
             options,
+            offspring_scratch: RefCell::new(Vec::new()),
         }
     }
 }
@@ -49,6 +135,7 @@ where
 
 #[derive(Derivative, Clone, Default)]
 #[derivative(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ApplyReproductionOptions<Actions, Selector> {
     pub selector: Selector,
     pub overall_reproduction_chance: Odds,
@@ -75,6 +162,7 @@ pub trait ApplyReproduction {
     fn fitness(subject: &Self::Subject) -> Fitness;
 }
 
+#[cfg(not(feature = "parallel"))]
 pub fn apply_reproductions<
     Subject,
     Reproducer: ApplyReproduction<Subject = Subject>,
@@ -84,18 +172,20 @@ pub fn apply_reproductions<
     context: &GaContext,
     population: &mut Population<Subject>,
     options: &ApplyReproductionOptions<Actions, Selector>,
+    appended_subjects: &mut Vec<FitnessWrapped<Subject>>,
 ) {
-    let mut appended_subjects = vec![];
+    appended_subjects.clear();
     for (subject_a, subject_b) in options
         .selector
-        .select_from(&population.subjects)
+        .select_from(context, &population.subjects)
         .iter()
         .tuple_windows()
     {
         if !coin_flip(options.overall_reproduction_chance) {
             continue;
         }
-        let (subject_a, subject_b) = (&subject_a.subject(), &subject_b.subject());
+        let parents = vec![subject_a.id(), subject_b.id()];
+        let (subject_a, subject_b) = (subject_a.subject_ref(), subject_b.subject_ref());
 
         for reproducer in options.reproduction_actions.sample_self().iter() {
             let offspring = match reproducer.apply(context, subject_a, subject_b) {
@@ -107,13 +197,76 @@ pub fn apply_reproductions<
             };
             for offspring in offspring {
                 let fitness = Reproducer::fitness(&offspring);
-                appended_subjects.push(FitnessWrapped::new(offspring, fitness));
+                let mut wrapped = FitnessWrapped::new_with_parentage(
+                    offspring,
+                    fitness,
+                    parents.clone(),
+                    std::any::type_name::<Reproducer>(),
+                );
+                wrapped.set_generation_born(context.generation);
+                appended_subjects.push(wrapped);
             }
         }
     }
-    population.subjects.extend(appended_subjects);
+    population.subjects.append(appended_subjects);
 }
 
+/// Reproduces each selected pair (and evaluates offspring fitness) in
+/// parallel via rayon, mirroring [`crate::ga::mutation::apply_mutations`]'s
+/// parallel variant, since fitness functions are typically the dominant
+/// per-generation cost. Pairing itself (`tuple_windows`) stays sequential —
+/// it's cheap pointer bookkeeping — only the `coin_flip`/`apply`/`fitness`
+/// work per pair runs across rayon's pool.
+#[cfg(feature = "parallel")]
+pub fn apply_reproductions<
+    Subject: Send + Sync,
+    Reproducer: ApplyReproduction<Subject = Subject> + Sync,
+    Selector: for<'a> SelectOther<&'a FitnessWrapped<Subject>, Output = Vec<&'a FitnessWrapped<Subject>>>
+        + Sync,
+    Actions: SampleSelf<Output = Vec<Reproducer>> + Sync,
+>(
+    context: &GaContext,
+    population: &mut Population<Subject>,
+    options: &ApplyReproductionOptions<Actions, Selector>,
+    appended_subjects: &mut Vec<FitnessWrapped<Subject>>,
+) {
+    appended_subjects.clear();
+    let selected = options.selector.select_from(context, &population.subjects);
+    let pairs: Vec<(&FitnessWrapped<Subject>, &FitnessWrapped<Subject>)> =
+        selected.into_iter().tuple_windows().collect();
+    appended_subjects.par_extend(pairs.into_par_iter().flat_map_iter(|(subject_a, subject_b)| {
+        if !coin_flip(options.overall_reproduction_chance) {
+            return vec![];
+        }
+        let parents = vec![subject_a.id(), subject_b.id()];
+        let (subject_a, subject_b) = (subject_a.subject_ref(), subject_b.subject_ref());
+        options
+            .reproduction_actions
+            .sample_self()
+            .iter()
+            .flat_map(|reproducer| {
+                let offspring = match reproducer.apply(context, subject_a, subject_b) {
+                    None => vec![],
+                    Some(ReproductionResult::Single(a)) => vec![a],
+                    Some(ReproductionResult::Double(a, b)) => vec![a, b],
+                    Some(ReproductionResult::Triple(a, b, c)) => vec![a, b, c],
+                    Some(ReproductionResult::Quad(a, b, c, d)) => vec![a, b, c, d],
+                };
+                let parents = parents.clone();
+                offspring.into_iter().map(move |offspring| {
+                    let fitness = Reproducer::fitness(&offspring);
+                    let mut wrapped =
+                        FitnessWrapped::new_with_parentage(offspring, fitness, parents.clone(), std::any::type_name::<Reproducer>());
+                    wrapped.set_generation_born(context.generation);
+                    wrapped
+                })
+            })
+            .collect::<Vec<_>>()
+    }));
+    population.subjects.append(appended_subjects);
+}
+
+#[cfg(not(feature = "parallel"))]
 impl<Reproducer, Selector, Subject, ReproducerActions> GaAction
     for GenericReproducer<Reproducer, Selector, Subject, ReproducerActions>
 where
@@ -127,6 +280,239 @@ where
     type Subject = Subject;
 
     fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
-        apply_reproductions(context, population, &self.options);
+        crate::ga::instrument_action("reproduction", context, population, |population| {
+            apply_reproductions(
+                context,
+                population,
+                &self.options,
+                &mut self.offspring_scratch.borrow_mut(),
+            );
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Reproducer, Selector, Subject, ReproducerActions> GaAction
+    for GenericReproducer<Reproducer, Selector, Subject, ReproducerActions>
+where
+    Subject: Send + Sync,
+    Reproducer: ApplyReproduction<Subject = Subject> + Sync,
+    Selector: for<'a> SelectOther<
+            &'a FitnessWrapped<Reproducer::Subject>,
+            Output = Vec<&'a FitnessWrapped<Subject>>,
+        > + Sync,
+    ReproducerActions: SampleSelf<Output = Vec<Reproducer>> + Sync,
+{
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("reproduction", context, population, |population| {
+            apply_reproductions(
+                context,
+                population,
+                &self.options,
+                &mut self.offspring_scratch.borrow_mut(),
+            );
+        });
+    }
+}
+
+/// Batched counterpart to [`apply_reproductions`] for [`FitBatch`] subjects:
+/// gathers every pair's offspring across the whole generation first, then
+/// scores them all in one [`FitBatch::measure_batch`] call instead of one
+/// [`ApplyReproduction::fitness`] call per offspring — bypassing
+/// `Reproducer::fitness` in favor of `Subject::measure_batch`, so a
+/// reproducer relying on a fitness definition that differs from the
+/// subject's own should keep using [`apply_reproductions`] instead. Offspring
+/// are also wrapped via [`wrap_batch`] rather than
+/// [`FitnessWrapped::new_with_parentage`], so a
+/// [`crate::ga::lineage::Genealogy`] won't see their parent/operator either.
+pub fn apply_reproductions_batched<
+    Subject: FitBatch,
+    Reproducer: ApplyReproduction<Subject = Subject>,
+    Selector: for<'a> SelectOther<&'a FitnessWrapped<Subject>, Output = Vec<&'a FitnessWrapped<Subject>>>,
+    Actions: SampleSelf<Output = Vec<Reproducer>>,
+>(
+    context: &GaContext,
+    population: &mut Population<Subject>,
+    options: &ApplyReproductionOptions<Actions, Selector>,
+    appended_subjects: &mut Vec<FitnessWrapped<Subject>>,
+) {
+    appended_subjects.clear();
+    let mut offspring = vec![];
+    for (subject_a, subject_b) in options
+        .selector
+        .select_from(context, &population.subjects)
+        .iter()
+        .tuple_windows()
+    {
+        if !coin_flip(options.overall_reproduction_chance) {
+            continue;
+        }
+        let (subject_a, subject_b) = (subject_a.subject_ref(), subject_b.subject_ref());
+
+        for reproducer in options.reproduction_actions.sample_self().iter() {
+            match reproducer.apply(context, subject_a, subject_b) {
+                None => {}
+                Some(ReproductionResult::Single(a)) => offspring.push(a),
+                Some(ReproductionResult::Double(a, b)) => offspring.extend([a, b]),
+                Some(ReproductionResult::Triple(a, b, c)) => offspring.extend([a, b, c]),
+                Some(ReproductionResult::Quad(a, b, c, d)) => offspring.extend([a, b, c, d]),
+            }
+        }
+    }
+    appended_subjects.extend(wrap_batch(offspring));
+    population.subjects.append(appended_subjects);
+}
+
+/// [`GaAction`] wrapper around [`apply_reproductions_batched`], for subjects
+/// whose fitness is worth vectorizing (SIMD, GPU, one DB round-trip) rather
+/// than measuring one offspring at a time — see [`GenericReproducer`] for the
+/// per-subject equivalent.
+#[derive(Clone)]
+pub struct GenericBatchReproducer<Reproducer, Selector, Subject, Actions> {
+    _marker: PhantomData<Subject>,
+    _reproducer: PhantomData<Reproducer>,
+    options: ApplyReproductionOptions<Actions, Selector>,
+    offspring_scratch: RefCell<Vec<FitnessWrapped<Subject>>>,
+}
+
+impl<Reproducer, Selector, Subject, Actions>
+    GenericBatchReproducer<Reproducer, Selector, Subject, Actions>
+{
+    pub fn new(options: ApplyReproductionOptions<Actions, Selector>) -> Self {
+        Self {
+            _marker: PhantomData,
+            _reproducer: PhantomData,
+            options,
+            offspring_scratch: RefCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<Reproducer, Selector, Subject, Actions> Default
+    for GenericBatchReproducer<Reproducer, Selector, Subject, Actions>
+where
+    Subject: Default,
+    Reproducer: Default,
+    Selector: Default,
+    Actions: Default,
+{
+    fn default() -> Self {
+        Self::new(ApplyReproductionOptions::<Actions, Selector>::default())
+    }
+}
+
+impl<Reproducer, Selector, Subject, ReproducerActions> GaAction
+    for GenericBatchReproducer<Reproducer, Selector, Subject, ReproducerActions>
+where
+    Subject: FitBatch,
+    Reproducer: ApplyReproduction<Subject = Subject>,
+    Selector: for<'a> SelectOther<
+        &'a FitnessWrapped<Reproducer::Subject>,
+        Output = Vec<&'a FitnessWrapped<Subject>>,
+    >,
+    ReproducerActions: SampleSelf<Output = Vec<Reproducer>>,
+{
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("reproduction", context, population, |population| {
+            apply_reproductions_batched(
+                context,
+                population,
+                &self.options,
+                &mut self.offspring_scratch.borrow_mut(),
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Genes(Vec<i32>);
+
+    impl AsRef<[i32]> for Genes {
+        fn as_ref(&self) -> &[i32] {
+            &self.0
+        }
+    }
+
+    impl FromIterator<i32> for Genes {
+        fn from_iter<I: IntoIterator<Item = i32>>(iter: I) -> Self {
+            Self(iter.into_iter().collect())
+        }
+    }
+
+    impl GaSubject for Genes {}
+
+    impl Fit<Fitness> for Genes {
+        fn measure(&self) -> Fitness {
+            self.0.iter().sum::<i32>() as Fitness
+        }
+    }
+
+    #[test]
+    fn test_uniform_crossover_children_are_recombinations_of_parents() {
+        let a = Genes(vec![1, 1, 1, 1]);
+        let b = Genes(vec![2, 2, 2, 2]);
+        let crossover = UniformCrossover::<Genes, i32>::new(0.5);
+        let Some(ReproductionResult::Double(child_a, child_b)) =
+            crossover.apply(&GaContext::default(), &a, &b)
+        else {
+            panic!("expected two children");
+        };
+        for (gene_a, gene_b) in child_a.0.iter().zip(child_b.0.iter()) {
+            assert_ne!(gene_a, gene_b);
+            assert!(*gene_a == 1 || *gene_a == 2);
+        }
+    }
+
+    #[test]
+    fn test_uniform_crossover_at_zero_rate_leaves_parents_unswapped() {
+        let a = Genes(vec![1, 1, 1, 1]);
+        let b = Genes(vec![2, 2, 2, 2]);
+        let crossover = UniformCrossover::<Genes, i32>::new(0.0);
+        let Some(ReproductionResult::Double(child_a, child_b)) =
+            crossover.apply(&GaContext::default(), &a, &b)
+        else {
+            panic!("expected two children");
+        };
+        assert_eq!(child_a, a);
+        assert_eq!(child_b, b);
+    }
+
+    impl FitBatch for Genes {
+        fn measure_batch(subjects: &[Self]) -> Vec<Fitness> {
+            subjects.iter().map(Fit::measure).collect()
+        }
+    }
+
+    #[test]
+    fn test_apply_reproductions_batched_scores_offspring_via_measure_batch() {
+        let mut population = Population {
+            subjects: vec![
+                FitnessWrapped::new(Genes(vec![1, 1, 1, 1]), 4.0),
+                FitnessWrapped::new(Genes(vec![2, 2, 2, 2]), 8.0),
+            ],
+            pool_size: 2,
+            memory_budget_bytes: None,
+        };
+        let options = ApplyReproductionOptions {
+            selector: crate::ga::select::SelectAll,
+            overall_reproduction_chance: 1.0,
+            reproduction_actions: crate::ga::WeightedActionsSampleAll(vec![
+                (UniformCrossover::<Genes, i32>::new(0.5), 1.0).into(),
+            ]),
+        };
+        let mut scratch = vec![];
+        apply_reproductions_batched(&GaContext::default(), &mut population, &options, &mut scratch);
+        assert_eq!(population.subjects.len(), 4);
+        for offspring in &population.subjects[2..] {
+            assert_eq!(offspring.fitness(), offspring.subject_ref().measure());
+        }
     }
 }
@@ -0,0 +1,233 @@
+//! Generic single- and two-point crossover for any slice-like genome,
+//! alongside [`super::UniformCrossover`], so genomes that expose
+//! `AsRef<[T]> + FromIterator<T>` get the classic crossover family without
+//! hand-rolled splice code.
+
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use crate::ga::fitness::{Fit, Fitness};
+use crate::ga::reproduction::{ApplyReproduction, ReproductionResult};
+use crate::ga::subject::GaSubject;
+use crate::ga::GaContext;
+use rand::Rng;
+
+/// Splits both parents at a single random gene position and swaps the
+/// tails, the classic single-point crossover. Panics if the two genomes
+/// have different lengths.
+pub struct OnePointCrossover<Subject, T> {
+    _subject: PhantomData<Subject>,
+    _gene: PhantomData<T>,
+}
+
+impl<Subject, T> OnePointCrossover<Subject, T> {
+    pub fn new() -> Self {
+        Self {
+            _subject: PhantomData,
+            _gene: PhantomData,
+        }
+    }
+}
+
+impl<Subject, T> Default for OnePointCrossover<Subject, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Subject, T> Clone for OnePointCrossover<Subject, T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<Subject, T> fmt::Debug for OnePointCrossover<Subject, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OnePointCrossover").finish()
+    }
+}
+
+impl<Subject, T> ApplyReproduction for OnePointCrossover<Subject, T>
+where
+    T: Clone,
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq + AsRef<[T]> + FromIterator<T>,
+{
+    type Subject = Subject;
+
+    fn apply(
+        &self,
+        context: &GaContext,
+        subject_a: &Self::Subject,
+        subject_b: &Self::Subject,
+    ) -> Option<ReproductionResult<Self::Subject>> {
+        let (a, b) = (subject_a.as_ref(), subject_b.as_ref());
+        assert_eq!(a.len(), b.len(), "OnePointCrossover requires equal-length genomes");
+        let point = context.rng().gen_range(0..=a.len());
+        let (child_a, child_b) = splice_at(a, b, &[point]);
+        Some(ReproductionResult::Double(
+            Subject::from_iter(child_a),
+            Subject::from_iter(child_b),
+        ))
+    }
+
+    fn fitness(subject: &Self::Subject) -> Fitness {
+        subject.measure()
+    }
+}
+
+/// Splits both parents at two random gene positions and swaps the middle
+/// segment, so the swapped material need not include either tail the way
+/// [`OnePointCrossover`]'s always does. Panics if the two genomes have
+/// different lengths.
+pub struct TwoPointCrossover<Subject, T> {
+    _subject: PhantomData<Subject>,
+    _gene: PhantomData<T>,
+}
+
+impl<Subject, T> TwoPointCrossover<Subject, T> {
+    pub fn new() -> Self {
+        Self {
+            _subject: PhantomData,
+            _gene: PhantomData,
+        }
+    }
+}
+
+impl<Subject, T> Default for TwoPointCrossover<Subject, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Subject, T> Clone for TwoPointCrossover<Subject, T> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
+
+impl<Subject, T> fmt::Debug for TwoPointCrossover<Subject, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TwoPointCrossover").finish()
+    }
+}
+
+impl<Subject, T> ApplyReproduction for TwoPointCrossover<Subject, T>
+where
+    T: Clone,
+    Subject: GaSubject + Fit<Fitness> + Hash + PartialEq + Eq + AsRef<[T]> + FromIterator<T>,
+{
+    type Subject = Subject;
+
+    fn apply(
+        &self,
+        context: &GaContext,
+        subject_a: &Self::Subject,
+        subject_b: &Self::Subject,
+    ) -> Option<ReproductionResult<Self::Subject>> {
+        let (a, b) = (subject_a.as_ref(), subject_b.as_ref());
+        assert_eq!(a.len(), b.len(), "TwoPointCrossover requires equal-length genomes");
+        let mut rand = context.rng();
+        let first = rand.gen_range(0..=a.len());
+        let second = rand.gen_range(0..=a.len());
+        let (start, end) = (first.min(second), first.max(second));
+        let (child_a, child_b) = splice_at(a, b, &[start, end]);
+        Some(ReproductionResult::Double(
+            Subject::from_iter(child_a),
+            Subject::from_iter(child_b),
+        ))
+    }
+
+    fn fitness(subject: &Self::Subject) -> Fitness {
+        subject.measure()
+    }
+}
+
+/// Builds both children by alternating which parent supplies each segment
+/// between consecutive `points` (with implicit boundaries at `0` and
+/// `a.len()`), starting with `a` for the first segment.
+fn splice_at<T: Clone>(a: &[T], b: &[T], points: &[usize]) -> (Vec<T>, Vec<T>) {
+    let mut child_a = Vec::with_capacity(a.len());
+    let mut child_b = Vec::with_capacity(a.len());
+    let mut boundaries = Vec::with_capacity(points.len() + 2);
+    boundaries.push(0);
+    boundaries.extend_from_slice(points);
+    boundaries.push(a.len());
+    for (segment, window) in boundaries.windows(2).enumerate() {
+        let (start, end) = (window[0], window[1]);
+        if segment % 2 == 0 {
+            child_a.extend_from_slice(&a[start..end]);
+            child_b.extend_from_slice(&b[start..end]);
+        } else {
+            child_a.extend_from_slice(&b[start..end]);
+            child_b.extend_from_slice(&a[start..end]);
+        }
+    }
+    (child_a, child_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+    struct Genes(Vec<i32>);
+
+    impl AsRef<[i32]> for Genes {
+        fn as_ref(&self) -> &[i32] {
+            &self.0
+        }
+    }
+
+    impl FromIterator<i32> for Genes {
+        fn from_iter<I: IntoIterator<Item = i32>>(iter: I) -> Self {
+            Self(iter.into_iter().collect())
+        }
+    }
+
+    impl GaSubject for Genes {}
+
+    impl Fit<Fitness> for Genes {
+        fn measure(&self) -> Fitness {
+            self.0.iter().sum::<i32>() as Fitness
+        }
+    }
+
+    #[test]
+    fn test_one_point_crossover_children_are_recombinations_of_parents() {
+        let a = Genes(vec![1, 1, 1, 1]);
+        let b = Genes(vec![2, 2, 2, 2]);
+        let crossover = OnePointCrossover::<Genes, i32>::new();
+        let Some(ReproductionResult::Double(child_a, child_b)) =
+            crossover.apply(&GaContext::default(), &a, &b)
+        else {
+            panic!("expected two children");
+        };
+        for (gene_a, gene_b) in child_a.0.iter().zip(child_b.0.iter()) {
+            assert_ne!(gene_a, gene_b);
+        }
+    }
+
+    #[test]
+    fn test_splice_at_alternates_segments_between_parents() {
+        let a = [1, 1, 1, 1, 1, 1];
+        let b = [2, 2, 2, 2, 2, 2];
+        let (child_a, child_b) = splice_at(&a, &b, &[2, 4]);
+        assert_eq!(child_a, vec![1, 1, 2, 2, 1, 1]);
+        assert_eq!(child_b, vec![2, 2, 1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn test_two_point_crossover_children_preserve_length() {
+        let a = Genes(vec![1, 1, 1, 1]);
+        let b = Genes(vec![2, 2, 2, 2]);
+        let crossover = TwoPointCrossover::<Genes, i32>::new();
+        let Some(ReproductionResult::Double(child_a, child_b)) =
+            crossover.apply(&GaContext::default(), &a, &b)
+        else {
+            panic!("expected two children");
+        };
+        assert_eq!(child_a.0.len(), 4);
+        assert_eq!(child_b.0.len(), 4);
+    }
+}
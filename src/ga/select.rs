@@ -1,9 +1,25 @@
 use std::collections::HashSet;
 
 use itertools::Itertools;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::seq::SliceRandom;
 
-use crate::util::{random_index_bias, Bias};
+use crate::ga::annealing::CoolingSchedule;
+use crate::ga::fitness::{Fitness, FitnessWrapped, PerCaseFitness};
+use crate::ga::GaContext;
+use crate::util::{coin_flip, random_index_bias, Bias, Odds};
 
+/// `context` gives implementations that draw randomly access to the run's
+/// [`GaContext::rng`] instead of a free-standing
+/// `crate::util::rng::thread_rng()` handle. [`SelectTournament`],
+/// [`SelectRouletteWheel`], [`SelectBoltzmann`] and [`SelectLexicase`] draw
+/// from it, so two runs sharing a seeded context select the same parents in
+/// the same order — for a non-`parallel` run. [`SelectRandomWithBias`] and
+/// [`SelectRandomManyWithBias`] ignore it and still draw from
+/// `thread_rng()` (see the note on `SelectRandomWithBias::select_random`). Under the `parallel` feature,
+/// [`GaContext::rng`]'s single shared lock is contended by every worker
+/// thread, so which draw a given worker gets depends on OS scheduling, not
+/// the seed; see the "not guaranteed" note on [`GaContext`] itself.
 pub trait SelectOther<T>: Copy {
     type Output;
     fn select_from<
@@ -11,6 +27,7 @@ pub trait SelectOther<T>: Copy {
         Iter2: Iterator<Item = T> + ExactSizeIterator,
     >(
         self,
+        context: &GaContext,
         items: Iter,
     ) -> Self::Output;
 }
@@ -22,6 +39,7 @@ pub trait SelectOtherRandom<T> {
         Iter2: Iterator<Item = T> + ExactSizeIterator,
     >(
         self,
+        context: &GaContext,
         items: Iter,
     ) -> Self::Output;
 }
@@ -37,6 +55,7 @@ impl<T> SelectOther<T> for SelectAll {
         Iter2: Iterator<Item = T> + ExactSizeIterator,
     >(
         self,
+        _context: &GaContext,
         items: Iter,
     ) -> Self::Output {
         items.into_iter().collect()
@@ -64,9 +83,10 @@ impl<T> SelectOther<T> for SelectRandomWithBias {
         Iter2: Iterator<Item = T> + ExactSizeIterator,
     >(
         self,
+        context: &GaContext,
         items: Iter,
     ) -> Self::Output {
-        self.select_random(items)
+        self.select_random(context, items)
     }
 }
 
@@ -77,6 +97,11 @@ impl<T> SelectOtherRandom<T> for SelectRandomWithBias {
         Iter2: Iterator<Item = T> + ExactSizeIterator,
     >(
         self,
+        // `random_index_bias` is still backed by `crate::util::rng::thread_rng()`
+        // rather than `context.rng()` (widening it would ripple into every
+        // other caller: prune, dedupe, cellular, and more) — left for
+        // `synth-1319`'s pluggable-backend work to address crate-wide.
+        _context: &GaContext,
         items: Iter,
     ) -> Self::Output {
         let mut items = items.into_iter();
@@ -151,6 +176,8 @@ impl<T> SelectOther<T> for SelectRandomManyWithBias {
         Iter2: Iterator<Item = T> + ExactSizeIterator,
     >(
         self,
+        // see the note on `SelectRandomWithBias::select_random`.
+        _context: &GaContext,
         items: Iter,
     ) -> Self::Output {
         self.select_random(items)
@@ -164,46 +191,543 @@ impl<T> SelectOtherRandom<T> for SelectRandomManyWithBias {
         Iter2: Iterator<Item = T> + ExactSizeIterator,
     >(
         self,
+        // see the note on `SelectRandomWithBias::select_random`.
+        _context: &GaContext,
         items: Iter,
     ) -> Self::Output {
         self.select_random(items)
     }
 }
 
+/// Tournament selection: draws `tournament_size` distinct entrants uniformly
+/// at random (without replacement, so the size is capped at the population
+/// length) and returns one of them by fitness rank.
+/// `pressure` is the probability the best entrant in the bracket wins
+/// outright; each subsequent rank gets `pressure` of what's left
+/// (`pressure * (1.0 - pressure).powi(rank)`), so `pressure = 1.0` always
+/// picks the bracket's best (maximum selection pressure) and smaller values
+/// give weaker entrants a growing chance, down to near-uniform as
+/// `pressure` approaches `0.0` (if every rank's flip loses, a uniformly
+/// random bracket entrant is returned). `reverse` picks which end of the
+/// bracket counts as best: unset, "best" means lowest fitness (this crate's
+/// default convention); set, it means highest, for callers running with
+/// `reverse_mode` (higher fitness is better). This is the opposite sense of
+/// [`SelectRouletteWheel::reverse`], which favors lower fitness when set.
+#[derive(Debug, Copy, Clone)]
+pub struct SelectTournament {
+    tournament_size: usize,
+    pressure: Odds,
+    reverse: bool,
+}
+
+impl SelectTournament {
+    pub fn new(tournament_size: usize, pressure: Odds, reverse: bool) -> Self {
+        Self {
+            tournament_size,
+            pressure,
+            reverse,
+        }
+    }
+    pub fn tournament_size(&self) -> usize {
+        self.tournament_size
+    }
+    pub fn pressure(&self) -> Odds {
+        self.pressure
+    }
+    pub fn reverse(&self) -> bool {
+        self.reverse
+    }
+}
+
+impl Default for SelectTournament {
+    fn default() -> Self {
+        Self::new(2, 1.0, false)
+    }
+}
+
+impl<'a, Subject> SelectOther<&'a FitnessWrapped<Subject>> for SelectTournament {
+    type Output = Option<&'a FitnessWrapped<Subject>>;
+    fn select_from<
+        Iter: IntoIterator<Item = &'a FitnessWrapped<Subject>, IntoIter = Iter2>,
+        Iter2: Iterator<Item = &'a FitnessWrapped<Subject>> + ExactSizeIterator,
+    >(
+        self,
+        context: &GaContext,
+        items: Iter,
+    ) -> Self::Output {
+        self.select_random(context, items)
+    }
+}
+
+impl<'a, Subject> SelectOtherRandom<&'a FitnessWrapped<Subject>> for SelectTournament {
+    type Output = Option<&'a FitnessWrapped<Subject>>;
+    fn select_random<
+        Iter: IntoIterator<Item = &'a FitnessWrapped<Subject>, IntoIter = Iter2>,
+        Iter2: Iterator<Item = &'a FitnessWrapped<Subject>> + ExactSizeIterator,
+    >(
+        self,
+        context: &GaContext,
+        items: Iter,
+    ) -> Self::Output {
+        let items: Vec<&'a FitnessWrapped<Subject>> = items.into_iter().collect();
+        if items.is_empty() {
+            return None;
+        }
+        let tournament_size = self.tournament_size.clamp(1, items.len());
+        let mut bracket: Vec<usize> =
+            rand::seq::index::sample(&mut *context.rng(), items.len(), tournament_size).into_vec();
+        bracket.sort_by(|&a, &b| {
+            let ordering = items[a]
+                .fitness()
+                .partial_cmp(&items[b].fitness())
+                .unwrap_or(std::cmp::Ordering::Equal);
+            if self.reverse {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        // each flip only happens once every better rank has lost its own, so
+        // rank `k` wins with overall probability `pressure * (1 - pressure)^k`
+        for &index in &bracket {
+            if coin_flip(self.pressure.clamp(0.0, 1.0)) {
+                return Some(items[index]);
+            }
+        }
+        bracket.choose(&mut *context.rng()).map(|&index| items[index])
+    }
+}
+
+/// Fitness-proportionate (roulette wheel) selection: draws `amount` parents
+/// with replacement, each with probability proportional to its weight's
+/// share of the total. Unset, a subject's weight is `fitness - min` (its
+/// distance above the population's lowest fitness, plus a tiny epsilon so no
+/// weight is zero). `reverse` makes it minimization-aware — when set, weight
+/// is proportional to `max - fitness` instead, so
+/// lower-fitness subjects (the better ones, under this crate's
+/// minimization convention) get the larger share of the wheel.
+#[derive(Debug, Copy, Clone)]
+pub struct SelectRouletteWheel {
+    amount: usize,
+    reverse: bool,
+}
+
+impl SelectRouletteWheel {
+    pub fn new(amount: usize, reverse: bool) -> Self {
+        Self { amount, reverse }
+    }
+    pub fn amount(&self) -> usize {
+        self.amount
+    }
+    pub fn reverse(&self) -> bool {
+        self.reverse
+    }
+}
+
+impl Default for SelectRouletteWheel {
+    fn default() -> Self {
+        Self::new(2, true)
+    }
+}
+
+impl<'a, Subject> SelectOther<&'a FitnessWrapped<Subject>> for SelectRouletteWheel {
+    type Output = Vec<&'a FitnessWrapped<Subject>>;
+    fn select_from<
+        Iter: IntoIterator<Item = &'a FitnessWrapped<Subject>, IntoIter = Iter2>,
+        Iter2: Iterator<Item = &'a FitnessWrapped<Subject>> + ExactSizeIterator,
+    >(
+        self,
+        context: &GaContext,
+        items: Iter,
+    ) -> Self::Output {
+        self.select_random(context, items)
+    }
+}
+
+impl<'a, Subject> SelectOtherRandom<&'a FitnessWrapped<Subject>> for SelectRouletteWheel {
+    type Output = Vec<&'a FitnessWrapped<Subject>>;
+    fn select_random<
+        Iter: IntoIterator<Item = &'a FitnessWrapped<Subject>, IntoIter = Iter2>,
+        Iter2: Iterator<Item = &'a FitnessWrapped<Subject>> + ExactSizeIterator,
+    >(
+        self,
+        context: &GaContext,
+        items: Iter,
+    ) -> Self::Output {
+        let items: Vec<&'a FitnessWrapped<Subject>> = items.into_iter().collect();
+        if items.is_empty() {
+            return vec![];
+        }
+        let fitnesses: Vec<Fitness> = items.iter().map(|item| item.fitness()).collect();
+        let min = fitnesses.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = fitnesses.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let weights: Vec<f64> = fitnesses
+            .iter()
+            .map(|&fitness| {
+                let share = if self.reverse { max - fitness } else { fitness - min };
+                share + f64::EPSILON
+            })
+            .collect();
+        let dist = WeightedIndex::new(&weights).expect("weights should not be all zero");
+        (0..self.amount).map(|_| items[dist.sample(&mut *context.rng())]).collect()
+    }
+}
+
+/// Boltzmann (softmax) selection: draws `amount` parents with replacement,
+/// weighted by `exp(signed_fitness / temperature)` (`signed_fitness` is
+/// `-fitness` when `reverse` is set, so minimization problems still favor
+/// lower fitness). `temperature` follows a [`CoolingSchedule`] so selection
+/// pressure can be annealed over a run; `generation` for the schedule still
+/// comes from its own counter rather than the `context: &GaContext` these
+/// methods now also take — call [`Self::set_generation`] once per generation
+/// (e.g. from a thin wrapping [`crate::ga::GaAction`]) before reproduction
+/// runs, same as before this type gained `context` access for its own draws.
+#[derive(Debug, Copy, Clone)]
+pub struct SelectBoltzmann {
+    amount: usize,
+    reverse: bool,
+    schedule: CoolingSchedule,
+    generation: usize,
+}
+
+impl SelectBoltzmann {
+    pub fn new(amount: usize, reverse: bool, schedule: CoolingSchedule) -> Self {
+        Self { amount, reverse, schedule, generation: 0 }
+    }
+    pub fn amount(&self) -> usize {
+        self.amount
+    }
+    pub fn reverse(&self) -> bool {
+        self.reverse
+    }
+    pub fn set_generation(&mut self, generation: usize) {
+        self.generation = generation;
+    }
+    pub fn temperature(&self) -> f64 {
+        self.schedule.temperature(self.generation)
+    }
+}
+
+impl<'a, Subject> SelectOther<&'a FitnessWrapped<Subject>> for SelectBoltzmann {
+    type Output = Vec<&'a FitnessWrapped<Subject>>;
+    fn select_from<
+        Iter: IntoIterator<Item = &'a FitnessWrapped<Subject>, IntoIter = Iter2>,
+        Iter2: Iterator<Item = &'a FitnessWrapped<Subject>> + ExactSizeIterator,
+    >(
+        self,
+        context: &GaContext,
+        items: Iter,
+    ) -> Self::Output {
+        self.select_random(context, items)
+    }
+}
+
+impl<'a, Subject> SelectOtherRandom<&'a FitnessWrapped<Subject>> for SelectBoltzmann {
+    type Output = Vec<&'a FitnessWrapped<Subject>>;
+    fn select_random<
+        Iter: IntoIterator<Item = &'a FitnessWrapped<Subject>, IntoIter = Iter2>,
+        Iter2: Iterator<Item = &'a FitnessWrapped<Subject>> + ExactSizeIterator,
+    >(
+        self,
+        context: &GaContext,
+        items: Iter,
+    ) -> Self::Output {
+        let items: Vec<&'a FitnessWrapped<Subject>> = items.into_iter().collect();
+        if items.is_empty() {
+            return vec![];
+        }
+        let temperature = self.temperature();
+        let signed_fitnesses: Vec<Fitness> = items
+            .iter()
+            .map(|item| if self.reverse { -item.fitness() } else { item.fitness() })
+            .collect();
+        let max = signed_fitnesses.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let weights: Vec<f64> = signed_fitnesses
+            .iter()
+            .map(|&fitness| ((fitness - max) / temperature).exp())
+            .collect();
+        let dist = WeightedIndex::new(&weights).expect("weights should not be all zero");
+        (0..self.amount).map(|_| items[dist.sample(&mut *context.rng())]).collect()
+    }
+}
+
+/// Lexicase selection: shuffles the test cases into a random order, then
+/// repeatedly filters the candidate pool down to whoever is (tied for) best
+/// on the next case, until one candidate remains or the cases run out.
+/// Unlike aggregate-score selection, this lets a candidate win purely by
+/// being the best on some cases even if its aggregate score is mediocre —
+/// useful for program-synthesis problems where a single weak case shouldn't
+/// be averaged away by many easy ones.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SelectLexicase;
+
+impl<'a, Subject: PerCaseFitness> SelectOther<&'a FitnessWrapped<Subject>> for SelectLexicase {
+    type Output = Option<&'a FitnessWrapped<Subject>>;
+    fn select_from<
+        Iter: IntoIterator<Item = &'a FitnessWrapped<Subject>, IntoIter = Iter2>,
+        Iter2: Iterator<Item = &'a FitnessWrapped<Subject>> + ExactSizeIterator,
+    >(
+        self,
+        context: &GaContext,
+        items: Iter,
+    ) -> Self::Output {
+        self.select_random(context, items)
+    }
+}
+
+impl<'a, Subject: PerCaseFitness> SelectOtherRandom<&'a FitnessWrapped<Subject>> for SelectLexicase {
+    type Output = Option<&'a FitnessWrapped<Subject>>;
+    fn select_random<
+        Iter: IntoIterator<Item = &'a FitnessWrapped<Subject>, IntoIter = Iter2>,
+        Iter2: Iterator<Item = &'a FitnessWrapped<Subject>> + ExactSizeIterator,
+    >(
+        self,
+        context: &GaContext,
+        items: Iter,
+    ) -> Self::Output {
+        let mut candidates: Vec<&'a FitnessWrapped<Subject>> = items.into_iter().collect();
+        if candidates.is_empty() {
+            return None;
+        }
+        let num_cases = candidates[0].subject_ref().case_errors().len();
+        let mut case_order: Vec<usize> = (0..num_cases).collect();
+        case_order.shuffle(&mut *context.rng());
+        for case in case_order {
+            if candidates.len() <= 1 {
+                break;
+            }
+            let best = candidates
+                .iter()
+                .map(|candidate| candidate.subject_ref().case_errors()[case])
+                .fold(f64::INFINITY, f64::min);
+            candidates.retain(|candidate| candidate.subject_ref().case_errors()[case] <= best);
+        }
+        candidates.into_iter().next()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    mod select_tournament {
+        use super::*;
+
+        fn population() -> Vec<FitnessWrapped<u32>> {
+            (0..10).map(|n| FitnessWrapped::new(n, n as f64)).collect()
+        }
+
+        #[test]
+        fn test_max_pressure_always_picks_the_bracket_lowest_fitness() {
+            let context = GaContext::default();
+            let items = population();
+            let selected = SelectTournament::new(items.len(), 1.0, false)
+                .select_from(&context, items.iter())
+                .unwrap();
+            assert_eq!(selected.fitness(), 0.0);
+        }
+
+        #[test]
+        fn test_reverse_max_pressure_always_picks_the_bracket_highest_fitness() {
+            let context = GaContext::default();
+            let items = population();
+            let selected = SelectTournament::new(items.len(), 1.0, true)
+                .select_from(&context, items.iter())
+                .unwrap();
+            assert_eq!(selected.fitness(), 9.0);
+        }
+
+        #[test]
+        fn test_empty_population_returns_none() {
+            let context = GaContext::default();
+            let items: Vec<FitnessWrapped<u32>> = vec![];
+            assert!(SelectTournament::default().select_from(&context, items.iter()).is_none());
+        }
+
+        #[test]
+        fn test_tournament_size_is_clamped_to_population_len() {
+            let context = GaContext::default();
+            let items = population();
+            let selected = SelectTournament::new(1000, 1.0, false).select_from(&context, items.iter());
+            assert!(selected.is_some());
+        }
+    }
+
+    mod select_roulette_wheel {
+        use super::*;
+
+        fn population() -> Vec<FitnessWrapped<u32>> {
+            (0..10).map(|n| FitnessWrapped::new(n, n as f64)).collect()
+        }
+
+        #[test]
+        fn test_empty_population_returns_nothing() {
+            let context = GaContext::default();
+            let items: Vec<FitnessWrapped<u32>> = vec![];
+            assert!(SelectRouletteWheel::default().select_from(&context, items.iter()).is_empty());
+        }
+
+        #[test]
+        fn test_returns_requested_amount() {
+            let context = GaContext::default();
+            let items = population();
+            let selected = SelectRouletteWheel::new(5, true).select_from(&context, items.iter());
+            assert_eq!(selected.len(), 5);
+        }
+
+        #[test]
+        fn test_reverse_mode_favors_lower_fitness() {
+            let context = GaContext::default();
+            let items = population();
+            let wins = (0..2000)
+                .flat_map(|_| SelectRouletteWheel::new(1, true).select_from(&context, items.iter()))
+                .filter(|selected| selected.fitness() <= 1.0)
+                .count();
+            // the two lowest-fitness subjects should win a large share of draws
+            // when minimization is favored, far more than the 2/10 uniform share.
+            assert!(wins > 600);
+        }
+
+        #[test]
+        fn test_forward_mode_favors_higher_fitness() {
+            let context = GaContext::default();
+            let items = population();
+            let wins = (0..2000)
+                .flat_map(|_| SelectRouletteWheel::new(1, false).select_from(&context, items.iter()))
+                .filter(|selected| selected.fitness() >= 8.0)
+                .count();
+            assert!(wins > 600);
+        }
+    }
+
+    mod select_boltzmann {
+        use super::*;
+
+        fn population() -> Vec<FitnessWrapped<u32>> {
+            (0..10).map(|n| FitnessWrapped::new(n, n as f64)).collect()
+        }
+
+        fn cold_schedule() -> CoolingSchedule {
+            CoolingSchedule::Exponential { initial: 0.1, decay_rate: 1.0 }
+        }
+
+        #[test]
+        fn test_empty_population_returns_nothing() {
+            let context = GaContext::default();
+            let items: Vec<FitnessWrapped<u32>> = vec![];
+            let selector = SelectBoltzmann::new(2, true, cold_schedule());
+            assert!(selector.select_from(&context, items.iter()).is_empty());
+        }
+
+        #[test]
+        fn test_returns_requested_amount() {
+            let context = GaContext::default();
+            let items = population();
+            let selected = SelectBoltzmann::new(5, true, cold_schedule()).select_from(&context, items.iter());
+            assert_eq!(selected.len(), 5);
+        }
+
+        #[test]
+        fn test_low_temperature_reverse_mode_always_favors_the_lowest_fitness() {
+            let context = GaContext::default();
+            let items = population();
+            let selector = SelectBoltzmann::new(1, true, cold_schedule());
+            let selected = selector.select_from(&context, items.iter());
+            assert_eq!(selected[0].fitness(), 0.0);
+        }
+
+        #[test]
+        fn test_low_temperature_forward_mode_always_favors_the_highest_fitness() {
+            let context = GaContext::default();
+            let items = population();
+            let selector = SelectBoltzmann::new(1, false, cold_schedule());
+            let selected = selector.select_from(&context, items.iter());
+            assert_eq!(selected[0].fitness(), 9.0);
+        }
+
+        #[test]
+        fn test_temperature_follows_the_schedule_as_generation_advances() {
+            let schedule = CoolingSchedule::Exponential { initial: 10.0, decay_rate: 0.5 };
+            let mut selector = SelectBoltzmann::new(1, true, schedule);
+            assert_eq!(selector.temperature(), 10.0);
+            selector.set_generation(1);
+            assert_eq!(selector.temperature(), 5.0);
+        }
+    }
+
+    mod select_lexicase {
+        use super::*;
+
+        #[derive(Debug, PartialEq)]
+        struct CaseSubject(Vec<Fitness>);
+
+        impl PerCaseFitness for CaseSubject {
+            fn case_errors(&self) -> Vec<Fitness> {
+                self.0.clone()
+            }
+        }
+
+        #[test]
+        fn test_empty_population_returns_none() {
+            let context = GaContext::default();
+            let items: Vec<FitnessWrapped<CaseSubject>> = vec![];
+            assert!(SelectLexicase.select_from(&context, items.iter()).is_none());
+        }
+
+        #[test]
+        fn test_candidate_that_wins_every_case_is_always_selected() {
+            let context = GaContext::default();
+            let items = [
+                FitnessWrapped::new(CaseSubject(vec![0.0, 0.0, 0.0]), 0.0),
+                FitnessWrapped::new(CaseSubject(vec![1.0, 1.0, 1.0]), 1.0),
+                FitnessWrapped::new(CaseSubject(vec![2.0, 2.0, 2.0]), 2.0),
+            ];
+            let selected = SelectLexicase.select_from(&context, items.iter()).unwrap();
+            assert_eq!(selected.subject_ref().0, vec![0.0, 0.0, 0.0]);
+        }
+
+        #[test]
+        fn test_single_candidate_is_trivially_selected() {
+            let context = GaContext::default();
+            let items = [FitnessWrapped::new(CaseSubject(vec![5.0]), 5.0)];
+            let selected = SelectLexicase.select_from(&context, items.iter()).unwrap();
+            assert_eq!(selected.subject_ref().0, vec![5.0]);
+        }
+    }
+
     mod select_random_with_bias {
         use super::*;
 
         #[test]
         fn test_select_random_owned() {
+            let context = GaContext::default();
             #[derive(Debug, PartialEq)]
             struct Foo(usize);
             let foo = Foo(1);
             let items = [foo];
-            let selected = SelectRandomWithBias::new(Bias::Front).select_random(items);
+            let selected = SelectRandomWithBias::new(Bias::Front).select_random(&context, items);
             assert_eq!(selected, Some(Foo(1)));
         }
 
         #[test]
         fn test_select_random() {
+            let context = GaContext::default();
             #[derive(Debug, PartialEq)]
             struct Foo(usize);
             let foo = Foo(1);
             let items = &[foo];
-            let selected = SelectRandomWithBias::new(Bias::Front).select_random(items);
+            let selected = SelectRandomWithBias::new(Bias::Front).select_random(&context, items);
             assert_eq!(selected, Some(Foo(1)).as_ref());
         }
 
         #[test]
         fn test_select_random_mut() {
+            let context = GaContext::default();
             #[derive(Debug, PartialEq)]
             struct Foo(usize);
             let mut foo = Foo(1);
             let items = [&mut foo];
-            let selected = SelectRandomWithBias::new(Bias::Front).select_random(items);
+            let selected = SelectRandomWithBias::new(Bias::Front).select_random(&context, items);
             let Some(selected) = selected else {
                 unreachable!();
             };
@@ -255,8 +779,7 @@ mod tests {
         fn test_select_random_range_a() {
             let len = 50000;
             let expected = len / 2 - 1;
-            let selected =
-                SelectRandomManyWithBias::new(expected, Bias::Front).select_random(0..len);
+            let selected = SelectRandomManyWithBias::new(expected, Bias::Front).select_random(0..len);
             assert_eq!(selected.into_iter().collect::<HashSet<_>>().len(), expected);
         }
 
@@ -264,8 +787,7 @@ mod tests {
         fn test_select_random_range_b() {
             let len = 50000;
             let expected = len / 2 + 1;
-            let selected =
-                SelectRandomManyWithBias::new(expected, Bias::Front).select_random(0..len);
+            let selected = SelectRandomManyWithBias::new(expected, Bias::Front).select_random(0..len);
             assert_eq!(selected.into_iter().collect::<HashSet<_>>().len(), expected);
         }
 
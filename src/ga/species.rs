@@ -0,0 +1,163 @@
+//! NEAT-style speciation: partitions the population into species by
+//! genotype distance threshold, and allocates a reproduction quota per
+//! species from each species' average fitness — explicit fitness sharing
+//! within a species, the mechanism the NEAT paper uses to protect a novel
+//! topological innovation from being outcompeted before it's had a chance
+//! to optimize.
+//!
+//! Runs as a [`GaAction`] before reproduction; `GenericReproducer`'s
+//! `Selector` has no notion of species membership, so pairing reproduction
+//! quota with an actual mate-selection restricted to within a species is
+//! left to the caller — [`Speciation::species`] exposes the partition (and
+//! [`allocate_quota`] the per-species offspring counts) for a caller-defined
+//! `Selector`/reproduction loop to consume.
+
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use crate::ga::niching::GenotypeDistance;
+use crate::ga::population::Population;
+use crate::ga::{GaAction, GaContext};
+
+#[derive(Debug, Clone)]
+pub struct SpeciesStats {
+    /// Index into `population.subjects` used as this species' representative.
+    pub representative_index: usize,
+    /// Indices into `population.subjects` belonging to this species.
+    pub member_indices: Vec<usize>,
+    pub average_fitness: f64,
+    pub best_fitness: f64,
+}
+
+/// Partitions the population into species each generation, exposing the
+/// result via a shared handle so a caller can read it back after the
+/// action runs.
+pub struct Speciation<Subject> {
+    threshold: f64,
+    species: Rc<RefCell<Vec<SpeciesStats>>>,
+    _marker: PhantomData<Subject>,
+}
+
+impl<Subject> Speciation<Subject> {
+    pub fn new(threshold: f64) -> Self {
+        Self { threshold, species: Rc::new(RefCell::new(vec![])), _marker: PhantomData }
+    }
+
+    pub fn species(&self) -> Rc<RefCell<Vec<SpeciesStats>>> {
+        self.species.clone()
+    }
+}
+
+impl<Subject: GenotypeDistance> GaAction for Speciation<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("speciation", context, population, |population| {
+            let mut species: Vec<SpeciesStats> = vec![];
+            for (index, wrapped) in population.subjects.iter().enumerate() {
+                let subject = wrapped.subject_ref();
+                let existing = species.iter_mut().find(|species| {
+                    population.subjects[species.representative_index]
+                        .subject_ref()
+                        .genotype_distance(subject)
+                        < self.threshold
+                });
+                match existing {
+                    Some(species) => species.member_indices.push(index),
+                    None => species.push(SpeciesStats {
+                        representative_index: index,
+                        member_indices: vec![index],
+                        average_fitness: 0.0,
+                        best_fitness: 0.0,
+                    }),
+                }
+            }
+            for species in species.iter_mut() {
+                let fitnesses: Vec<f64> =
+                    species.member_indices.iter().map(|&index| population.subjects[index].fitness()).collect();
+                species.average_fitness = fitnesses.iter().sum::<f64>() / fitnesses.len() as f64;
+                species.best_fitness = fitnesses.iter().cloned().fold(f64::INFINITY, f64::min);
+            }
+            *self.species.borrow_mut() = species;
+        });
+    }
+}
+
+/// Splits `total_offspring` across species in proportion to `1 /
+/// average_fitness` (this crate's minimization convention, so lower
+/// average fitness — a better species — gets a larger quota), using the
+/// largest-remainder method so the quotas sum to exactly `total_offspring`.
+pub fn allocate_quota(species: &[SpeciesStats], total_offspring: usize) -> Vec<usize> {
+    if species.is_empty() {
+        return vec![];
+    }
+    let weights: Vec<f64> = species.iter().map(|s| 1.0 / (s.average_fitness + f64::EPSILON)).collect();
+    let total_weight: f64 = weights.iter().sum();
+    let raw_quotas: Vec<f64> = weights.iter().map(|&w| w / total_weight * total_offspring as f64).collect();
+    let mut quotas: Vec<usize> = raw_quotas.iter().map(|&q| q.floor() as usize).collect();
+    let mut remaining = total_offspring - quotas.iter().sum::<usize>();
+    let mut remainders: Vec<usize> = (0..species.len()).collect();
+    remainders.sort_by(|&a, &b| {
+        (raw_quotas[b] - raw_quotas[b].floor())
+            .partial_cmp(&(raw_quotas[a] - raw_quotas[a].floor()))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    for &index in remainders.iter() {
+        if remaining == 0 {
+            break;
+        }
+        quotas[index] += 1;
+        remaining -= 1;
+    }
+    quotas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::fitness::FitnessWrapped;
+
+    #[derive(Clone)]
+    struct Point(f64);
+
+    impl GenotypeDistance for Point {
+        fn genotype_distance(&self, other: &Self) -> f64 {
+            (self.0 - other.0).abs()
+        }
+    }
+
+    fn population(values: Vec<(f64, f64)>) -> Population<Point> {
+        let subjects = values
+            .into_iter()
+            .map(|(genotype, fitness)| FitnessWrapped::new(Point(genotype), fitness))
+            .collect();
+        Population { pool_size: 0, subjects, memory_budget_bytes: None }
+    }
+
+    #[test]
+    fn test_nearby_subjects_are_grouped_into_one_species() {
+        let mut population = population(vec![(0.0, 1.0), (0.1, 1.0), (100.0, 1.0)]);
+        let action = Speciation::new(1.0);
+        action.perform_action(&GaContext::default(), &mut population);
+        let species = action.species();
+        let species = species.borrow();
+        assert_eq!(species.len(), 2);
+    }
+
+    #[test]
+    fn test_allocate_quota_sums_to_the_requested_total() {
+        let species = vec![
+            SpeciesStats { representative_index: 0, member_indices: vec![0], average_fitness: 1.0, best_fitness: 1.0 },
+            SpeciesStats { representative_index: 1, member_indices: vec![1], average_fitness: 4.0, best_fitness: 4.0 },
+        ];
+        let quotas = allocate_quota(&species, 10);
+        assert_eq!(quotas.iter().sum::<usize>(), 10);
+        assert!(quotas[0] > quotas[1]); // lower average fitness gets the larger share
+    }
+
+    #[test]
+    fn test_allocate_quota_on_empty_species_is_empty() {
+        assert!(allocate_quota(&[], 10).is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::path::Path;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use crate::ga::population::Population;
+use crate::ga::stats::{compute_stats, PopulationStats};
+use crate::ga::{GaAction, GaContext};
+
+/// Persists generation summaries, and periodic best-subject snapshots, into a
+/// SQLite database, so queries across many runs don't need a
+/// hand-rolled schema: open the same `path` across runs and every row is
+/// tagged with a `run_id` so they stay distinguishable in one database.
+///
+/// Register it as an action the same way [`crate::ga::csv_stats::CsvStatsRecorder`]
+/// is registered; `run_id` can be anything that identifies this run to you
+/// later (a UUID, a timestamp, a hyperparameter hash).
+pub struct SqliteRunArchive<Subject> {
+    run_id: String,
+    snapshot_interval: Option<usize>,
+    conn: RefCell<Connection>,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> SqliteRunArchive<Subject> {
+    pub fn new(
+        path: impl AsRef<Path>,
+        run_id: impl Into<String>,
+        snapshot_interval: Option<usize>,
+    ) -> rusqlite::Result<Self> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS generations (
+                run_id TEXT NOT NULL,
+                generation INTEGER NOT NULL,
+                population_size INTEGER NOT NULL,
+                min_fitness REAL NOT NULL,
+                max_fitness REAL NOT NULL,
+                mean_fitness REAL NOT NULL,
+                stddev_fitness REAL NOT NULL,
+                diversity REAL NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS snapshots (
+                run_id TEXT NOT NULL,
+                generation INTEGER NOT NULL,
+                best_fitness REAL NOT NULL,
+                subject_json TEXT NOT NULL
+            );",
+        )?;
+        Ok(Self {
+            run_id: run_id.into(),
+            snapshot_interval,
+            conn: RefCell::new(conn),
+            _subject: PhantomData,
+        })
+    }
+
+    fn insert_generation_row(
+        &self,
+        context: &GaContext,
+        population_size: usize,
+        stats: PopulationStats,
+    ) -> rusqlite::Result<()> {
+        self.conn.borrow().execute(
+            "INSERT INTO generations (run_id, generation, population_size, min_fitness, max_fitness, mean_fitness, stddev_fitness, diversity)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            rusqlite::params![
+                self.run_id,
+                context.generation as i64,
+                population_size as i64,
+                stats.min_fitness,
+                stats.max_fitness,
+                stats.mean_fitness,
+                stats.stddev_fitness,
+                stats.diversity,
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn insert_snapshot_row(
+        &self,
+        generation: usize,
+        best_fitness: f64,
+        subject_json: &str,
+    ) -> rusqlite::Result<()> {
+        self.conn.borrow().execute(
+            "INSERT INTO snapshots (run_id, generation, best_fitness, subject_json) VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![self.run_id, generation as i64, best_fitness, subject_json],
+        )?;
+        Ok(())
+    }
+}
+
+macro_rules! impl_record_generation {
+    () => {
+        fn record_generation(&self, context: &GaContext, population: &Population<Subject>) {
+            let Some(stats) = compute_stats(population) else {
+                return;
+            };
+            if let Err(err) = self.insert_generation_row(context, population.subjects.len(), stats)
+            {
+                tracing::log::warn!("failed to insert SQLite generation row: {err}");
+            }
+
+            let should_snapshot = self
+                .snapshot_interval
+                .map(|interval| interval != 0 && context.generation % interval == 0)
+                .unwrap_or(false);
+            if !should_snapshot {
+                return;
+            }
+            let Some(best) = population.iter().min_by(|a, b| {
+                a.fitness()
+                    .partial_cmp(&b.fitness())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }) else {
+                return;
+            };
+            match serde_json::to_string(best.subject_ref()) {
+                Ok(subject_json) => {
+                    if let Err(err) =
+                        self.insert_snapshot_row(context.generation, best.fitness(), &subject_json)
+                    {
+                        tracing::log::warn!("failed to insert SQLite snapshot row: {err}");
+                    }
+                }
+                Err(err) => tracing::log::warn!("failed to serialize snapshot subject: {err}"),
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq + Serialize> SqliteRunArchive<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync + Serialize> SqliteRunArchive<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq + Serialize> GaAction for SqliteRunArchive<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("sqlite", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync + Serialize> GaAction
+    for SqliteRunArchive<Subject>
+{
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("sqlite", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
@@ -0,0 +1,127 @@
+use std::hash::Hash;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::ga::fitness::{Fitness, FitnessWrapped};
+use crate::ga::population::Population;
+use crate::util::stable_hash;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PopulationStats {
+    pub min_fitness: Fitness,
+    pub max_fitness: Fitness,
+    pub mean_fitness: Fitness,
+    pub stddev_fitness: Fitness,
+    /// Number of distinct subjects (by hash) divided by the population size
+    /// (1.0 = no duplicates, approaching 0.0 as duplicates dominate).
+    pub diversity: f64,
+}
+
+fn stats_from_fitnesses(fitnesses: &[Fitness], diversity: f64) -> PopulationStats {
+    let n = fitnesses.len() as f64;
+    let min_fitness = fitnesses.iter().copied().fold(f64::INFINITY, f64::min);
+    let max_fitness = fitnesses.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let mean_fitness = fitnesses.iter().sum::<Fitness>() / n;
+    let variance = fitnesses
+        .iter()
+        .map(|fitness| (fitness - mean_fitness).powi(2))
+        .sum::<Fitness>()
+        / n;
+    PopulationStats {
+        min_fitness,
+        max_fitness,
+        mean_fitness,
+        stddev_fitness: variance.sqrt(),
+        diversity,
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn compute_stats<Subject: Hash>(population: &Population<Subject>) -> Option<PopulationStats> {
+    if population.subjects.is_empty() {
+        return None;
+    }
+    let fitnesses: Vec<Fitness> = population.subjects.iter().map(|s| s.fitness()).collect();
+    Some(stats_from_fitnesses(&fitnesses, diversity(&population.subjects)))
+}
+
+#[cfg(not(feature = "parallel"))]
+fn diversity<Subject: Hash>(subjects: &[FitnessWrapped<Subject>]) -> f64 {
+    use std::collections::HashSet;
+    let mut seen = HashSet::new();
+    for subject in subjects {
+        seen.insert(stable_hash(subject.subject_ref()));
+    }
+    seen.len() as f64 / subjects.len() as f64
+}
+
+/// Computes statistics and diversity with rayon, since this is otherwise run
+/// every generation and would negate the gains of parallel evaluation on
+/// large populations.
+#[cfg(feature = "parallel")]
+pub fn compute_stats<Subject: Hash + Send + Sync>(
+    population: &Population<Subject>,
+) -> Option<PopulationStats> {
+    if population.subjects.is_empty() {
+        return None;
+    }
+    let fitnesses: Vec<Fitness> = population
+        .subjects
+        .par_iter()
+        .map(|s| s.fitness())
+        .collect();
+    Some(stats_from_fitnesses(
+        &fitnesses,
+        diversity(&population.subjects),
+    ))
+}
+
+#[cfg(feature = "parallel")]
+fn diversity<Subject: Hash + Send + Sync>(subjects: &[FitnessWrapped<Subject>]) -> f64 {
+    use dashmap::DashSet;
+    let seen = DashSet::new();
+    subjects.par_iter().for_each(|subject| {
+        seen.insert(stable_hash(subject.subject_ref()));
+    });
+    seen.len() as f64 / subjects.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::fitness::FitnessWrapped;
+    use crate::ga::population::Population;
+    use crate::ga::stats::compute_stats;
+
+    fn population_of(fitnesses: &[u32]) -> Population<u32> {
+        Population {
+            pool_size: fitnesses.len(),
+            subjects: fitnesses
+                .iter()
+                .map(|&f| FitnessWrapped::new(f, f as f64))
+                .collect(),
+            memory_budget_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_compute_stats_empty_is_none() {
+        assert!(compute_stats(&population_of(&[])).is_none());
+    }
+
+    #[test]
+    fn test_compute_stats_fitness_summary() {
+        let stats = compute_stats(&population_of(&[1, 2, 3, 4])).unwrap();
+        assert_eq!(stats.min_fitness, 1.0);
+        assert_eq!(stats.max_fitness, 4.0);
+        assert_eq!(stats.mean_fitness, 2.5);
+        assert_eq!(stats.diversity, 1.0);
+    }
+
+    #[test]
+    fn test_compute_stats_diversity_with_duplicates() {
+        let stats = compute_stats(&population_of(&[1, 1, 2, 2])).unwrap();
+        assert_eq!(stats.diversity, 0.5);
+    }
+}
@@ -0,0 +1,137 @@
+//! Steady-state evolution: each generation produces a small batch of
+//! offspring and inserts them in place of the current worst subjects,
+//! instead of [`crate::ga::action::DefaultActions`]'s full
+//! prune/mutate/reproduce/inflate cycle rebuilding a large chunk of the
+//! population at once. Population size stays constant generation to
+//! generation.
+//!
+//! `GaIterator` already sorts the population best-first (ascending, or
+//! descending in reverse mode) before any `GaAction` runs, so "the
+//! current worst subjects" is just the trailing slice.
+
+use std::marker::PhantomData;
+
+use derivative::Derivative;
+use itertools::Itertools;
+
+use crate::ga::fitness::{Fitness, FitnessWrapped};
+use crate::ga::population::Population;
+use crate::ga::reproduction::{ApplyReproduction, ReproductionResult};
+use crate::ga::select::SelectOther;
+use crate::ga::subject::GaSubject;
+use crate::ga::{GaAction, GaContext, SampleSelf};
+
+#[derive(Derivative, Clone)]
+#[derivative(Debug, Default(bound = "Actions: Default, Selector: Default"))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SteadyStateOptions<Actions, Selector> {
+    pub selector: Selector,
+    #[derivative(Debug = "ignore")]
+    pub reproduction_actions: Actions,
+}
+
+/// Selects parents, reproduces a batch of offspring from them, and replaces
+/// an equal number of the population's current worst subjects with that
+/// offspring — unconditionally, not only when an offspring beats the
+/// subject it replaces (that's [`crate::ga::annealing::accept`]'s job to add
+/// on top, inside a caller's own `ApplyReproduction::apply`, if wanted).
+#[derive(Clone, Default)]
+pub struct SteadyStateActions<Reproducer, Selector, Subject, Actions> {
+    _marker: PhantomData<(Reproducer, Subject)>,
+    options: SteadyStateOptions<Actions, Selector>,
+}
+
+impl<Reproducer, Selector, Subject, Actions> SteadyStateActions<Reproducer, Selector, Subject, Actions> {
+    pub fn new(options: SteadyStateOptions<Actions, Selector>) -> Self {
+        Self { _marker: PhantomData, options }
+    }
+}
+
+impl<Reproducer, Selector, Subject, Actions> GaAction
+    for SteadyStateActions<Reproducer, Selector, Subject, Actions>
+where
+    Reproducer: ApplyReproduction<Subject = Subject>,
+    Selector: for<'a> SelectOther<&'a FitnessWrapped<Subject>, Output = Vec<&'a FitnessWrapped<Subject>>>,
+    Actions: SampleSelf<Output = Vec<Reproducer>>,
+    Subject: GaSubject,
+{
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("steady_state", context, population, |population| {
+            let mut offspring: Vec<FitnessWrapped<Subject>> = vec![];
+            for (parent_a, parent_b) in
+                self.options.selector.select_from(context, &population.subjects).iter().tuple_windows()
+            {
+                let (parent_a, parent_b) = (parent_a.subject_ref(), parent_b.subject_ref());
+                for reproducer in self.options.reproduction_actions.sample_self().iter() {
+                    let results = match reproducer.apply(context, parent_a, parent_b) {
+                        None => vec![],
+                        Some(ReproductionResult::Single(a)) => vec![a],
+                        Some(ReproductionResult::Double(a, b)) => vec![a, b],
+                        Some(ReproductionResult::Triple(a, b, c)) => vec![a, b, c],
+                        Some(ReproductionResult::Quad(a, b, c, d)) => vec![a, b, c, d],
+                    };
+                    for subject in results {
+                        let fitness: Fitness = Reproducer::fitness(&subject);
+                        offspring.push(FitnessWrapped::new(subject, fitness));
+                    }
+                }
+            }
+            let replace_count = offspring.len().min(population.subjects.len());
+            let keep = population.subjects.len() - replace_count;
+            population.subjects.truncate(keep);
+            population.subjects.extend(offspring.into_iter().take(replace_count));
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::select::SelectAll;
+    use crate::ga::WeightedActionsSampleAll;
+
+    impl GaSubject for i32 {}
+
+    #[derive(Clone)]
+    struct Increment;
+    impl ApplyReproduction for Increment {
+        type Subject = i32;
+        fn apply(&self, _context: &GaContext, a: &i32, b: &i32) -> Option<ReproductionResult<i32>> {
+            Some(ReproductionResult::Single(a.max(b) + 1))
+        }
+        fn fitness(subject: &i32) -> Fitness {
+            *subject as Fitness
+        }
+    }
+
+    fn population(values: Vec<i32>) -> Population<i32> {
+        let subjects = values.into_iter().map(|v| FitnessWrapped::new(v, v as Fitness)).collect();
+        Population { pool_size: 0, subjects, memory_budget_bytes: None }
+    }
+
+    #[test]
+    fn test_population_size_is_unchanged() {
+        let mut population = population(vec![0, 1, 2, 3, 4]);
+        let action = SteadyStateActions::<Increment, _, i32, _>::new(SteadyStateOptions {
+            selector: SelectAll,
+            reproduction_actions: WeightedActionsSampleAll(vec![(Increment, 1.0).into()]),
+        });
+        action.perform_action(&GaContext::default(), &mut population);
+        assert_eq!(population.subjects.len(), 5);
+    }
+
+    #[test]
+    fn test_offspring_replace_the_current_worst() {
+        let mut population = population(vec![0, 1, 2, 3, 4]);
+        let action = SteadyStateActions::<Increment, _, i32, _>::new(SteadyStateOptions {
+            selector: SelectAll,
+            reproduction_actions: WeightedActionsSampleAll(vec![(Increment, 1.0).into()]),
+        });
+        action.perform_action(&GaContext::default(), &mut population);
+        // the best subject (0) survives untouched; the worst was replaced.
+        assert_eq!(*population.subjects[0].subject_ref(), 0);
+        assert_ne!(*population.subjects[4].subject_ref(), 4);
+    }
+}
@@ -0,0 +1,218 @@
+use std::cell::Cell;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::ga::fitness::Fitness;
+use crate::ga::ga_iterator::GaIterState;
+
+/// Why a [`crate::ga::ga_iterator::GaIterator`] (or the
+/// [`crate::ga::ga_runner::GaRunner`] driving it) stopped advancing
+/// generations, recorded on [`crate::ga::ga_iterator::GaIterState`] and
+/// emitted via tracing, so a caller doesn't have to infer the reason by
+/// parsing debug logs.
+///
+/// [`Self::TargetReached`], [`Self::OutOfRange`], [`Self::UserRequested`],
+/// and [`Self::Budget`] (via [`crate::ga::ga_runner::GaRunnerOptions::max_duration`])
+/// are produced by this crate. Nothing in the crate records
+/// [`Self::MaxGenerations`], [`Self::Stagnated`], or [`Self::Error`]: the
+/// [`MaxGenerations`] terminator below only answers `true`/`false` through
+/// [`Terminator::is_met`], and there is no stagnation detector or fallible
+/// action that reports a reason.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TerminationReason {
+    TargetReached,
+    OutOfRange,
+    MaxGenerations,
+    Stagnated,
+    Budget,
+    UserRequested,
+    Error,
+}
+
+impl fmt::Display for TerminationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            TerminationReason::TargetReached => "target_reached",
+            TerminationReason::OutOfRange => "out_of_range",
+            TerminationReason::MaxGenerations => "max_generations",
+            TerminationReason::Stagnated => "stagnated",
+            TerminationReason::Budget => "budget",
+            TerminationReason::UserRequested => "user_requested",
+            TerminationReason::Error => "error",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// A composable stopping condition, so callers can express something like
+/// "stop at target fitness OR after 1,000,000 generations OR after 10
+/// minutes" by combining leaf terminators with [`Terminator::or`]/
+/// [`Terminator::and`] instead of hand-rolling the boolean logic.
+///
+/// Nothing in [`crate::ga::ga_runner::GaRunner`] evaluates a `Terminator`
+/// automatically — its loop already hardcodes its own OutOfRange/
+/// TargetReached/Budget checks (see [`TerminationReason`]'s docs), and
+/// `GaRunnerOptions` can't hold a generic (or `dyn`) `Terminator` field
+/// without either a breaking type parameter or giving up `#[derive(Clone)]`.
+/// Instead, evaluate a composed `Terminator` yourself from
+/// [`crate::ga::ga_runner::GaRunnerOptions::before_each_generation`]/
+/// [`crate::ga::ga_runner::GaRunnerOptions::after_each_generation`] and
+/// return `Some(GaRunnerCustomForEachGenerationResult::Terminate)` when it
+/// reports `true`, the same extension point those hooks already exist for.
+pub trait Terminator<Subject> {
+    fn is_met(&self, state: &GaIterState<Subject>) -> bool;
+
+    fn or<Other>(self, other: Other) -> Or<Self, Other>
+    where
+        Self: Sized,
+        Other: Terminator<Subject>,
+    {
+        Or(self, other)
+    }
+
+    fn and<Other>(self, other: Other) -> And<Self, Other>
+    where
+        Self: Sized,
+        Other: Terminator<Subject>,
+    {
+        And(self, other)
+    }
+}
+
+/// Met once either wrapped terminator is met. See [`Terminator::or`].
+pub struct Or<A, B>(A, B);
+
+impl<Subject, A, B> Terminator<Subject> for Or<A, B>
+where
+    A: Terminator<Subject>,
+    B: Terminator<Subject>,
+{
+    fn is_met(&self, state: &GaIterState<Subject>) -> bool {
+        self.0.is_met(state) || self.1.is_met(state)
+    }
+}
+
+/// Met only once both wrapped terminators are met. See [`Terminator::and`].
+pub struct And<A, B>(A, B);
+
+impl<Subject, A, B> Terminator<Subject> for And<A, B>
+where
+    A: Terminator<Subject>,
+    B: Terminator<Subject>,
+{
+    fn is_met(&self, state: &GaIterState<Subject>) -> bool {
+        self.0.is_met(state) && self.1.is_met(state)
+    }
+}
+
+/// Met once [`GaIterState::current_fitness`] equals `target` exactly. This is
+/// stricter than [`crate::ga::ga_iterator::GaIterator::is_fitness_at_target`],
+/// which also accepts a fitness within the options' `target_fitness_epsilon`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetFitness(pub Fitness);
+
+impl<Subject> Terminator<Subject> for TargetFitness {
+    fn is_met(&self, state: &GaIterState<Subject>) -> bool {
+        state.current_fitness() == Some(self.0)
+    }
+}
+
+/// Met once [`crate::ga::GaContext::generation`] reaches `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxGenerations(pub usize);
+
+impl<Subject> Terminator<Subject> for MaxGenerations {
+    fn is_met(&self, state: &GaIterState<Subject>) -> bool {
+        state.context().generation >= self.0
+    }
+}
+
+/// Met once `duration` has elapsed since the first time this terminator was
+/// checked, so it doubles as its own stopwatch instead of needing the caller
+/// to thread a start time through. Interior-mutable (a [`Cell`], not a
+/// field written through `&mut self`) because [`Terminator::is_met`] takes
+/// `&self`, matching how `is_met` methods on the other leaf terminators
+/// don't need `&mut` either.
+pub struct MaxDuration {
+    duration: Duration,
+    start: Cell<Option<Instant>>,
+}
+
+impl MaxDuration {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            start: Cell::new(None),
+        }
+    }
+}
+
+impl<Subject> Terminator<Subject> for MaxDuration {
+    fn is_met(&self, _state: &GaIterState<Subject>) -> bool {
+        let start = self.start.get().unwrap_or_else(|| {
+            let now = Instant::now();
+            self.start.set(Some(now));
+            now
+        });
+        start.elapsed() >= self.duration
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ga::population::Population;
+    use crate::ga::GaContext;
+
+    fn state_at_generation(generation: usize) -> GaIterState<i32> {
+        let population = Population {
+            subjects: vec![],
+            pool_size: 0,
+            memory_budget_bytes: None,
+        };
+        GaIterState::new(GaContext::new(generation), population)
+    }
+
+    #[test]
+    fn test_max_generations_is_met_at_and_past_target() {
+        let terminator = MaxGenerations(10);
+        assert!(!terminator.is_met(&state_at_generation(9)));
+        assert!(terminator.is_met(&state_at_generation(10)));
+        assert!(terminator.is_met(&state_at_generation(11)));
+    }
+
+    #[test]
+    fn test_target_fitness_is_met_only_on_exact_match() {
+        let mut state = state_at_generation(0);
+        let terminator = TargetFitness(0.0);
+        assert!(!terminator.is_met(&state));
+        state.current_fitness = Some(0.0);
+        assert!(terminator.is_met(&state));
+    }
+
+    #[test]
+    fn test_max_duration_is_not_met_before_duration_elapses() {
+        let terminator = MaxDuration::new(Duration::from_secs(60));
+        assert!(!terminator.is_met(&state_at_generation(0)));
+    }
+
+    #[test]
+    fn test_or_is_met_when_either_side_is_met() {
+        let terminator = Terminator::<i32>::or(MaxGenerations(100), TargetFitness(0.0));
+        assert!(Terminator::<i32>::is_met(&terminator, &state_at_generation(100)));
+        let mut state = state_at_generation(0);
+        state.current_fitness = Some(0.0);
+        assert!(Terminator::<i32>::is_met(&terminator, &state));
+        assert!(!Terminator::<i32>::is_met(&terminator, &state_at_generation(0)));
+    }
+
+    #[test]
+    fn test_and_is_met_only_when_both_sides_are_met() {
+        let terminator = Terminator::<i32>::and(MaxGenerations(100), TargetFitness(0.0));
+        let mut state = state_at_generation(100);
+        assert!(!Terminator::<i32>::is_met(&terminator, &state));
+        state.current_fitness = Some(0.0);
+        assert!(Terminator::<i32>::is_met(&terminator, &state));
+    }
+}
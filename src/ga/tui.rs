@@ -0,0 +1,220 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::io;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crossterm::event::{self, Event, KeyCode};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::ExecutableCommand;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::{Block, Borders, Paragraph, Sparkline};
+use ratatui::Terminal;
+
+use crate::ga::population::Population;
+use crate::ga::stats::compute_stats;
+use crate::ga::{GaAction, GaContext};
+
+const HISTORY_LEN: usize = 120;
+
+/// Live terminal dashboard for a running GA: min/mean fitness sparklines,
+/// generation rate, and the current best subject's [`Display`] output.
+/// Register it as an action the same way [`crate::ga::csv_stats::CsvStatsRecorder`]
+/// is registered. [`TuiDashboard::new`] takes over the terminal (raw mode +
+/// alternate screen) for the lifetime of the value and restores it on drop.
+///
+/// Shows per-generation timing rather than a per-action breakdown:
+/// attributing time to individual actions (prune vs. mutation vs.
+/// reproduction, etc.) would mean subscribing to the `ga_action` tracing
+/// spans emitted by [`crate::ga::instrument_action`], which this widget
+/// doesn't do.
+///
+/// `GaRunnerOptions`'s generation hooks are plain `fn` pointers, so they
+/// can't close over this dashboard to check for a quit key press. Instead,
+/// drive the run with your own loop over [`crate::ga::ga_iterator::GaIterator`]
+/// and break when [`TuiDashboard::should_quit`] returns `true`.
+pub struct TuiDashboard<Subject> {
+    terminal: RefCell<Terminal<CrosstermBackend<io::Stdout>>>,
+    min_fitness_history: RefCell<VecDeque<f64>>,
+    mean_fitness_history: RefCell<VecDeque<f64>>,
+    last_tick: RefCell<Instant>,
+    generations_per_sec: RefCell<f64>,
+    best_display: RefCell<String>,
+    should_quit: RefCell<bool>,
+    _subject: PhantomData<Subject>,
+}
+
+impl<Subject> TuiDashboard<Subject> {
+    pub fn new() -> io::Result<Self> {
+        enable_raw_mode()?;
+        io::stdout().execute(EnterAlternateScreen)?;
+        let terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+        Ok(Self {
+            terminal: RefCell::new(terminal),
+            min_fitness_history: RefCell::new(VecDeque::with_capacity(HISTORY_LEN)),
+            mean_fitness_history: RefCell::new(VecDeque::with_capacity(HISTORY_LEN)),
+            last_tick: RefCell::new(Instant::now()),
+            generations_per_sec: RefCell::new(0.0),
+            best_display: RefCell::new(String::new()),
+            should_quit: RefCell::new(false),
+            _subject: PhantomData,
+        })
+    }
+
+    /// `true` once the user has pressed `q` in the dashboard. The caller's
+    /// own generation loop is expected to check this and stop.
+    pub fn should_quit(&self) -> bool {
+        *self.should_quit.borrow()
+    }
+
+    fn poll_quit_key(&self) {
+        while event::poll(Duration::ZERO).unwrap_or(false) {
+            if let Ok(Event::Key(key)) = event::read() {
+                if key.code == KeyCode::Char('q') {
+                    *self.should_quit.borrow_mut() = true;
+                }
+            }
+        }
+    }
+
+    fn push_history(history: &mut VecDeque<f64>, value: f64) {
+        if history.len() == HISTORY_LEN {
+            history.pop_front();
+        }
+        history.push_back(value);
+    }
+
+    fn sparkline_data(history: &VecDeque<f64>) -> Vec<u64> {
+        let min = history.iter().copied().fold(f64::INFINITY, f64::min);
+        history
+            .iter()
+            .map(|value| ((value - min).max(0.0) * 1000.0).round() as u64)
+            .collect()
+    }
+}
+
+impl<Subject> Drop for TuiDashboard<Subject> {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = io::stdout().execute(LeaveAlternateScreen);
+    }
+}
+
+macro_rules! impl_record_generation {
+    () => {
+        fn record_generation(&self, context: &GaContext, population: &Population<Subject>) {
+            self.poll_quit_key();
+
+            let now = Instant::now();
+            let elapsed = now.duration_since(*self.last_tick.borrow());
+            *self.last_tick.borrow_mut() = now;
+            if elapsed > Duration::ZERO {
+                *self.generations_per_sec.borrow_mut() = 1.0 / elapsed.as_secs_f64();
+            }
+
+            if let Some(stats) = compute_stats(population) {
+                Self::push_history(&mut self.min_fitness_history.borrow_mut(), stats.min_fitness);
+                Self::push_history(
+                    &mut self.mean_fitness_history.borrow_mut(),
+                    stats.mean_fitness,
+                );
+            }
+
+            if let Some(best) = population.iter().min_by(|a, b| {
+                a.fitness()
+                    .partial_cmp(&b.fitness())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }) {
+                *self.best_display.borrow_mut() = best.subject_ref().to_string();
+            }
+
+            let min_history = self.min_fitness_history.borrow();
+            let mean_history = self.mean_fitness_history.borrow();
+            let min_data = Self::sparkline_data(&min_history);
+            let mean_data = Self::sparkline_data(&mean_history);
+            let generation = context.generation;
+            let generations_per_sec = *self.generations_per_sec.borrow();
+            let best_display = self.best_display.borrow().clone();
+            drop(min_history);
+            drop(mean_history);
+
+            let _ = self.terminal.borrow_mut().draw(|frame| {
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Length(3),
+                        Constraint::Length(3),
+                        Constraint::Length(3),
+                        Constraint::Min(0),
+                    ])
+                    .split(frame.area());
+
+                frame.render_widget(
+                    Paragraph::new(format!(
+                        "generation {generation} | {generations_per_sec:.2} gen/s (press q to quit)"
+                    ))
+                    .block(Block::default().borders(Borders::ALL).title("status")),
+                    rows[0],
+                );
+                frame.render_widget(
+                    Sparkline::default()
+                        .block(Block::default().borders(Borders::ALL).title("min fitness"))
+                        .data(&min_data)
+                        .style(Style::default().fg(Color::Blue)),
+                    rows[1],
+                );
+                frame.render_widget(
+                    Sparkline::default()
+                        .block(Block::default().borders(Borders::ALL).title("mean fitness"))
+                        .data(&mean_data)
+                        .style(Style::default().fg(Color::Green)),
+                    rows[2],
+                );
+                frame.render_widget(
+                    Paragraph::new(best_display)
+                        .block(Block::default().borders(Borders::ALL).title("best subject")),
+                    rows[3],
+                );
+            });
+        }
+    };
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq + Display> TuiDashboard<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync + Display> TuiDashboard<Subject> {
+    impl_record_generation!();
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<Subject: Hash + Eq + PartialEq + Display> GaAction for TuiDashboard<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("tui", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<Subject: Hash + Eq + PartialEq + Send + Sync + Display> GaAction for TuiDashboard<Subject> {
+    type Subject = Subject;
+
+    fn perform_action(&self, context: &GaContext, population: &mut Population<Self::Subject>) {
+        crate::ga::instrument_action("tui", context, population, |population| {
+            self.record_generation(context, population);
+        });
+    }
+}
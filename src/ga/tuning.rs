@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use crate::ga::fitness::Fitness;
+
+/// A single hyperparameter value suggested by a [`HyperparameterTuner`].
+/// Kept as an enum of primitives, rather than a caller-defined struct, so
+/// this crate doesn't need a generic parameter for every possible search
+/// space shape, and so a value can be relayed as-is across a process
+/// boundary (e.g. to an Optuna study) when the `serde` feature is enabled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HyperparameterValue {
+    Float(f64),
+    Int(i64),
+}
+
+/// Parameter values for a single trial, keyed by name so the tuner and the
+/// caller's trial function agree on what each value means without a shared
+/// struct type.
+pub type TrialParams = HashMap<String, HyperparameterValue>;
+
+/// Suggest/observe interface so an external tuner can propose hyperparameter
+/// values per trial and receive back the objective a full GA run produced,
+/// without this crate knowing anything about the tuner's search algorithm.
+/// An implementation can be a local search strategy, or a thin relay to a
+/// tuner running out-of-process (e.g. over a pipe to an Optuna study),
+/// replacing a hand-rolled shell-script harness around one-shot runs.
+pub trait HyperparameterTuner {
+    /// Proposes parameter values for the next trial.
+    fn suggest(&mut self, trial: usize) -> TrialParams;
+    /// Reports the objective (e.g. final best fitness) a trial's run produced.
+    fn observe(&mut self, trial: usize, objective: Fitness);
+}
+
+/// Runs `trial_count` trials against `tuner`: each trial asks the tuner for
+/// parameters, hands them to `run_trial` to build and run a GA (e.g. via
+/// [`crate::ga::ga_runner::ga_runner`]) and return its objective, then
+/// reports that objective back to the tuner. This harness only owns the
+/// suggest/observe loop, not the GA itself, since the subject type and
+/// actions vary per caller.
+pub fn run_tuning_trials(
+    tuner: &mut impl HyperparameterTuner,
+    trial_count: usize,
+    mut run_trial: impl FnMut(usize, &TrialParams) -> Fitness,
+) {
+    for trial in 0..trial_count {
+        let params = tuner.suggest(trial);
+        let objective = run_trial(trial, &params);
+        tuner.observe(trial, objective);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ga::tuning::{run_tuning_trials, HyperparameterTuner, HyperparameterValue, TrialParams};
+
+    struct GridTuner {
+        candidates: Vec<f64>,
+        observed: Vec<(usize, f64)>,
+    }
+
+    impl HyperparameterTuner for GridTuner {
+        fn suggest(&mut self, trial: usize) -> TrialParams {
+            let mut params = TrialParams::new();
+            params.insert(
+                "mutation_chance".to_string(),
+                HyperparameterValue::Float(self.candidates[trial]),
+            );
+            params
+        }
+
+        fn observe(&mut self, trial: usize, objective: f64) {
+            self.observed.push((trial, objective));
+        }
+    }
+
+    #[test]
+    fn test_run_tuning_trials_suggests_then_observes_each_trial() {
+        let mut tuner = GridTuner {
+            candidates: vec![0.1, 0.2, 0.3],
+            observed: vec![],
+        };
+
+        run_tuning_trials(&mut tuner, 3, |_trial, params| {
+            let Some(HyperparameterValue::Float(chance)) = params.get("mutation_chance") else {
+                panic!("expected mutation_chance to be suggested");
+            };
+            chance * 10.0
+        });
+
+        assert_eq!(tuner.observed, vec![(0, 1.0), (1, 2.0), (2, 3.0)]);
+    }
+}
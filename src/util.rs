@@ -1,4 +1,10 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+
+use rand::distributions::{Distribution, WeightedIndex};
 use rand::Rng;
+use rand_distr::{Beta, Gamma};
 
 pub trait ApplyRatioFloat64 {
     fn apply_ratio(&self, ratio: f64) -> f64;
@@ -7,18 +13,103 @@ pub trait ApplyRatioFloat64 {
     fn apply_ratio_round(&self, ratio: f64) -> Self;
 }
 
-impl ApplyRatioFloat64 for usize {
+/// Checked counterpart of [`ApplyRatioFloat64`] for callers that size prune
+/// targets or selector amounts from untrusted or user-supplied ratios, where
+/// silently accepting a ratio outside `0.0..=1.0` would produce nonsense.
+pub trait ApplyRatioFloat64Checked: Sized {
+    fn apply_ratio_ceil_checked(&self, ratio: f64) -> Result<Self, InvalidRatioError>;
+    fn apply_ratio_floor_checked(&self, ratio: f64) -> Result<Self, InvalidRatioError>;
+    fn apply_ratio_round_checked(&self, ratio: f64) -> Result<Self, InvalidRatioError>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct InvalidRatioError(pub f64);
+
+impl fmt::Display for InvalidRatioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ratio must be between 0.0 and 1.0 inclusively, got: {}",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidRatioError {}
+
+fn validate_ratio(ratio: f64) -> Result<(), InvalidRatioError> {
+    if (0.0..=1.0).contains(&ratio) {
+        Ok(())
+    } else {
+        Err(InvalidRatioError(ratio))
+    }
+}
+
+macro_rules! impl_apply_ratio_float64 {
+    ($ty:ty) => {
+        impl ApplyRatioFloat64 for $ty {
+            fn apply_ratio(&self, ratio: f64) -> f64 {
+                *self as f64 * ratio
+            }
+            fn apply_ratio_ceil(&self, ratio: f64) -> Self {
+                self.apply_ratio(ratio).ceil() as $ty
+            }
+            fn apply_ratio_floor(&self, ratio: f64) -> Self {
+                self.apply_ratio(ratio).floor() as $ty
+            }
+            fn apply_ratio_round(&self, ratio: f64) -> Self {
+                self.apply_ratio(ratio).round() as $ty
+            }
+        }
+
+        impl ApplyRatioFloat64Checked for $ty {
+            fn apply_ratio_ceil_checked(&self, ratio: f64) -> Result<Self, InvalidRatioError> {
+                validate_ratio(ratio)?;
+                Ok(self.apply_ratio_ceil(ratio))
+            }
+            fn apply_ratio_floor_checked(&self, ratio: f64) -> Result<Self, InvalidRatioError> {
+                validate_ratio(ratio)?;
+                Ok(self.apply_ratio_floor(ratio))
+            }
+            fn apply_ratio_round_checked(&self, ratio: f64) -> Result<Self, InvalidRatioError> {
+                validate_ratio(ratio)?;
+                Ok(self.apply_ratio_round(ratio))
+            }
+        }
+    };
+}
+
+impl_apply_ratio_float64!(usize);
+impl_apply_ratio_float64!(u32);
+impl_apply_ratio_float64!(u64);
+
+impl ApplyRatioFloat64 for NonZeroUsize {
     fn apply_ratio(&self, ratio: f64) -> f64 {
-        *self as f64 * ratio
+        self.get().apply_ratio(ratio)
     }
     fn apply_ratio_ceil(&self, ratio: f64) -> Self {
-        self.apply_ratio(ratio).ceil() as usize
+        NonZeroUsize::new(self.get().apply_ratio_ceil(ratio).max(1)).unwrap()
     }
     fn apply_ratio_floor(&self, ratio: f64) -> Self {
-        self.apply_ratio(ratio).floor() as usize
+        NonZeroUsize::new(self.get().apply_ratio_floor(ratio).max(1)).unwrap()
     }
     fn apply_ratio_round(&self, ratio: f64) -> Self {
-        self.apply_ratio(ratio).round() as usize
+        NonZeroUsize::new(self.get().apply_ratio_round(ratio).max(1)).unwrap()
+    }
+}
+
+impl ApplyRatioFloat64Checked for NonZeroUsize {
+    fn apply_ratio_ceil_checked(&self, ratio: f64) -> Result<Self, InvalidRatioError> {
+        validate_ratio(ratio)?;
+        Ok(self.apply_ratio_ceil(ratio))
+    }
+    fn apply_ratio_floor_checked(&self, ratio: f64) -> Result<Self, InvalidRatioError> {
+        validate_ratio(ratio)?;
+        Ok(self.apply_ratio_floor(ratio))
+    }
+    fn apply_ratio_round_checked(&self, ratio: f64) -> Result<Self, InvalidRatioError> {
+        validate_ratio(ratio)?;
+        Ok(self.apply_ratio_round(ratio))
     }
 }
 
@@ -34,13 +125,28 @@ pub fn coin_flip(odds: Odds) -> bool {
     rng::thread_rng().gen_bool(odds)
 }
 
-#[derive(Debug, Copy, Clone, Default)]
+/// Fallible counterpart to [`coin_flip`] for callers that can't tolerate a
+/// panic/debug-assert on an out-of-range `odds`, e.g. odds computed from
+/// live data rather than a literal.
+pub fn try_coin_flip(odds: Odds) -> crate::error::Result<bool> {
+    if !(0.0..=1.0).contains(&odds) {
+        return Err(crate::error::Error::InvalidOdds(odds));
+    }
+    Ok(rng::thread_rng().gen_bool(odds))
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Bias {
     #[default]
     Front,
     FrontInverse,
     Back,
     BackInverse,
+    /// Favors median-ranked items, i.e. indexes near the middle of the range.
+    Middle,
+    /// No bias, every index is equally likely.
+    Uniform,
 }
 
 impl Bias {
@@ -50,6 +156,9 @@ impl Bias {
             Self::FrontInverse => Self::Front,
             Self::Back => Self::BackInverse,
             Self::BackInverse => Self::Back,
+            // symmetric around the middle of the range, there is no opposing direction to invert
+            Self::Middle => Self::Middle,
+            Self::Uniform => Self::Uniform,
         }
     }
 }
@@ -67,6 +176,12 @@ fn bias_value(x: f64, bias: Bias) -> f64 {
             let u = x.powf(1.0 / b);
             t + u
         }
+        Bias::Middle => {
+            let centered = (x - 0.5) * 2.0;
+            let shaped = centered.signum() * centered.abs().powf(b);
+            1.0 + shaped
+        }
+        Bias::Uniform => x * 2.0,
         Bias::FrontInverse | Bias::BackInverse => 1.0 - bias_value(x, bias.inverse()),
     }
 }
@@ -86,18 +201,224 @@ pub fn random_index_bias(len: usize, bias: Bias) -> usize {
     _random_index_bias(x, len, bias)
 }
 
+/// Samples an index from `weights` using a temperature-scaled softmax distribution.
+/// Lower `temperature` sharpens the distribution towards the highest weight,
+/// higher `temperature` flattens it towards uniform selection.
+/// Returns `None` if `weights` is empty.
+pub fn softmax_index<R: Rng + ?Sized>(
+    weights: &[f64],
+    temperature: f64,
+    rng: &mut R,
+) -> Option<usize> {
+    try_softmax_index(weights, temperature, rng).expect("weights should not be all zero")
+}
+
+/// Fallible counterpart to [`softmax_index`] for callers that can't
+/// tolerate a panic when every weight collapses to zero after softmax
+/// (e.g. weights derived from live [`crate::ga::operator_stats::OperatorStats`]
+/// that can all decay together), returning
+/// [`crate::error::Error::EmptyWeights`] instead.
+pub fn try_softmax_index<R: Rng + ?Sized>(
+    weights: &[f64],
+    temperature: f64,
+    rng: &mut R,
+) -> crate::error::Result<Option<usize>> {
+    debug_assert!(temperature > 0.0, "temperature must be > 0.0, got: {temperature}");
+    if weights.is_empty() {
+        return Ok(None);
+    }
+    let max = weights.iter().copied().fold(f64::MIN, f64::max);
+    let exp_weights: Vec<f64> = weights
+        .iter()
+        .map(|weight| ((weight - max) / temperature).exp())
+        .collect();
+    let dist =
+        WeightedIndex::new(exp_weights).map_err(|_| crate::error::Error::EmptyWeights)?;
+    Ok(Some(dist.sample(rng)))
+}
+
+/// Samples a single value from a `Beta(alpha, beta)` distribution.
+/// Useful for mutation step sizes and adaptive weight updates that need a
+/// value bounded to `0.0..=1.0`.
+pub fn sample_beta<R: Rng + ?Sized>(alpha: f64, beta: f64, rng: &mut R) -> f64 {
+    Beta::new(alpha, beta)
+        .expect("alpha and beta must be > 0.0")
+        .sample(rng)
+}
+
+/// Samples a single value from a `Gamma(shape, scale)` distribution.
+/// Useful for mutation step sizes and adaptive weight updates that need a
+/// non-negative, right-skewed value.
+pub fn sample_gamma<R: Rng + ?Sized>(shape: f64, scale: f64, rng: &mut R) -> f64 {
+    Gamma::new(shape, scale)
+        .expect("shape and scale must be > 0.0")
+        .sample(rng)
+}
+
+/// A fixed-seed, endian-stable hasher (FNV-1a) used by [`stable_hash`].
+/// Unlike `std::collections::hash_map::DefaultHasher`, its output does not
+/// vary between process runs, so it is safe to use anywhere subject identity
+/// needs to be compared or persisted (dedupe, fitness caching, incest checks).
+struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+/// Returns a stable, endian-independent hash of `value` that is consistent
+/// across process runs, used anywhere features key on subject identity
+/// (dedupe, fitness caching, incest checks) and need to agree on one hash.
+pub fn stable_hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = StableHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
 pub mod rng {
-    #[cfg(not(test))]
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    use std::cell::RefCell;
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    use std::rc::Rc;
+
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
     use rand::prelude::ThreadRng;
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    use rand::rngs::{SmallRng, StdRng};
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    use rand::{Error, RngCore, SeedableRng};
 
-    #[cfg(test)]
+    /// Returns the deterministic step-based mock RNG.
+    /// Used automatically under `cfg(test)`, and available to downstream
+    /// crates that enable the `deterministic-rng` feature for their own
+    /// tests and benchmarks.
+    #[cfg(any(test, feature = "deterministic-rng"))]
     pub fn thread_rng() -> simple_ga_internal_lib::test_rng::MockThreadRng {
         simple_ga_internal_lib::test_rng::thread_rng()
     }
 
-    #[cfg(not(test))]
-    pub fn thread_rng() -> ThreadRng {
-        rand::thread_rng()
+    /// Reseeds this thread's mock RNG from `seed` instead of the fixed
+    /// `StepRng::new(0, 1)` [`simple_ga_internal_lib::test_rng::rng`] otherwise
+    /// always starts from, so a `GeneticAlgorithmOptions::seed` reproduces the
+    /// exact run under `deterministic-rng`/`cfg(test)` too, not just in normal
+    /// builds.
+    #[cfg(any(test, feature = "deterministic-rng"))]
+    pub fn seed_thread_rng(seed: u64) {
+        simple_ga_internal_lib::test_rng::seed_thread_rng(seed);
+    }
+
+    /// Every free function in this module that calls [`thread_rng`] (`coin_flip`,
+    /// `random_index_bias`, free-standing genome constructors, ...) draws from
+    /// whichever of these is current on this thread, so [`seed_thread_rng`] can
+    /// make them reproducible without changing any of their call sites.
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    pub enum GaThreadRng {
+        Os(ThreadRng),
+        /// A non-cryptographic, lower-overhead generator selected by
+        /// [`use_fast_thread_rng`] for callers whose GA inner loops call
+        /// [`thread_rng`] millions of times and can't amortize
+        /// [`ThreadRng`]'s per-call cost.
+        Fast(Rc<RefCell<SmallRng>>),
+        /// `Rc<RefCell<..>>` (rather than a plain `StdRng`) so every
+        /// `thread_rng()` call on this thread after [`seed_thread_rng`] shares
+        /// and advances the same generator, the way [`ThreadRng`] shares one
+        /// generator across calls within a thread.
+        Seeded(Rc<RefCell<StdRng>>),
+    }
+
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    impl RngCore for GaThreadRng {
+        fn next_u32(&mut self) -> u32 {
+            match self {
+                Self::Os(rng) => rng.next_u32(),
+                Self::Fast(rng) => rng.borrow_mut().next_u32(),
+                Self::Seeded(rng) => rng.borrow_mut().next_u32(),
+            }
+        }
+        fn next_u64(&mut self) -> u64 {
+            match self {
+                Self::Os(rng) => rng.next_u64(),
+                Self::Fast(rng) => rng.borrow_mut().next_u64(),
+                Self::Seeded(rng) => rng.borrow_mut().next_u64(),
+            }
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            match self {
+                Self::Os(rng) => rng.fill_bytes(dest),
+                Self::Fast(rng) => rng.borrow_mut().fill_bytes(dest),
+                Self::Seeded(rng) => rng.borrow_mut().fill_bytes(dest),
+            }
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+            match self {
+                Self::Os(rng) => rng.try_fill_bytes(dest),
+                Self::Fast(rng) => rng.borrow_mut().try_fill_bytes(dest),
+                Self::Seeded(rng) => rng.borrow_mut().try_fill_bytes(dest),
+            }
+        }
+    }
+
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    thread_local! {
+        static SEEDED_THREAD_RNG: RefCell<Option<Rc<RefCell<StdRng>>>> = const { RefCell::new(None) };
+        static FAST_THREAD_RNG: RefCell<Option<Rc<RefCell<SmallRng>>>> = const { RefCell::new(None) };
+    }
+
+    /// Switches this thread's [`thread_rng`] from [`ThreadRng`] to [`SmallRng`],
+    /// a non-cryptographic generator with lower per-call overhead, for callers
+    /// whose GA inner loops (mutation, selection, reproduction) call
+    /// [`thread_rng`] millions of times and can measure the difference.
+    /// [`seed_thread_rng`] still takes priority over this when both are set,
+    /// so reproducibility is never silently lost by opting into speed.
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    pub fn use_fast_thread_rng() {
+        FAST_THREAD_RNG.with(|cell| {
+            let mut cell = cell.borrow_mut();
+            if cell.is_none() {
+                *cell = Some(Rc::new(RefCell::new(SmallRng::from_entropy())));
+            }
+        });
+    }
+
+    /// Reseeds this thread's [`thread_rng`] from `seed`, so every free function
+    /// in this module that draws from it (`coin_flip`, `random_index_bias`,
+    /// free-standing genome constructors, ...) becomes reproducible for the
+    /// rest of the calling thread's lifetime, in place of the OS-seeded
+    /// `rand::thread_rng()` those otherwise fall back to.
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    pub fn seed_thread_rng(seed: u64) {
+        SEEDED_THREAD_RNG.with(|cell| {
+            *cell.borrow_mut() = Some(Rc::new(RefCell::new(StdRng::seed_from_u64(seed))));
+        });
+    }
+
+    #[cfg(not(any(test, feature = "deterministic-rng")))]
+    pub fn thread_rng() -> GaThreadRng {
+        let seeded = SEEDED_THREAD_RNG.with(|cell| cell.borrow().clone());
+        if let Some(rng) = seeded {
+            return GaThreadRng::Seeded(rng);
+        }
+        let fast = FAST_THREAD_RNG.with(|cell| cell.borrow().clone());
+        match fast {
+            Some(rng) => GaThreadRng::Fast(rng),
+            None => GaThreadRng::Os(rand::thread_rng()),
+        }
     }
 }
 
@@ -123,6 +444,80 @@ mod tests {
         assert!(expected(avg));
     }
 
+    #[test]
+    fn test_random_index_bias_middle_tighter_than_uniform() {
+        const SAMPLE_SIZE: usize = 10000;
+        const LEN: usize = 100;
+        let variance = |bias: Bias| {
+            let samples: Vec<f64> = (0..SAMPLE_SIZE)
+                .map(|n| _random_index_bias(n as f64 / SAMPLE_SIZE as f64, LEN, bias) as f64)
+                .collect();
+            let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+            samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / samples.len() as f64
+        };
+        assert!(variance(Bias::Middle) < variance(Bias::Uniform));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_bias_serde_round_trip() {
+        let json = serde_json::to_string(&Bias::Middle).unwrap();
+        assert_eq!(serde_json::from_str::<Bias>(&json).unwrap(), Bias::Middle);
+    }
+
+    mod softmax_index_tests {
+        use super::*;
+
+        #[test]
+        fn test_empty() {
+            let mut rng = rng::thread_rng();
+            assert_eq!(softmax_index(&[], 1.0, &mut rng), None);
+        }
+
+        #[test]
+        fn test_picks_highest_weight_at_low_temperature() {
+            let mut rng = rng::thread_rng();
+            let weights = [0.0, 0.0, 10.0];
+            assert_eq!(softmax_index(&weights, 0.01, &mut rng), Some(2));
+        }
+    }
+
+    mod stable_hash_tests {
+        use super::*;
+
+        #[test]
+        fn test_is_deterministic() {
+            assert_eq!(stable_hash(&"subject-a"), stable_hash(&"subject-a"));
+        }
+
+        #[test]
+        fn test_differs_for_different_values() {
+            assert_ne!(stable_hash(&"subject-a"), stable_hash(&"subject-b"));
+        }
+    }
+
+    mod sample_distribution_tests {
+        use super::*;
+
+        #[test]
+        fn test_sample_beta_in_range() {
+            let mut rng = rng::thread_rng();
+            for _ in 0..100 {
+                let value = sample_beta(2.0, 2.0, &mut rng);
+                assert!((0.0..=1.0).contains(&value));
+            }
+        }
+
+        #[test]
+        fn test_sample_gamma_non_negative() {
+            let mut rng = rng::thread_rng();
+            for _ in 0..100 {
+                let value = sample_gamma(2.0, 2.0, &mut rng);
+                assert!(value >= 0.0);
+            }
+        }
+    }
+
     mod apply_ratio_float64_tests {
         use super::*;
 
@@ -167,5 +562,34 @@ mod tests {
         fn test_round(input: usize, ratio: f64, expected: usize) {
             assert_eq!(input.apply_ratio_round(ratio), expected);
         }
+
+        #[test]
+        fn test_u32_u64() {
+            assert_eq!(2u32.apply_ratio_ceil(0.5), 1u32);
+            assert_eq!(2u64.apply_ratio_ceil(0.5), 1u64);
+        }
+
+        #[test]
+        fn test_non_zero_usize_never_zero() {
+            let one = NonZeroUsize::new(1).unwrap();
+            assert_eq!(one.apply_ratio_floor(0.0), one);
+        }
+
+        #[test]
+        fn test_checked_rejects_out_of_range_ratio() {
+            assert_eq!(
+                1usize.apply_ratio_ceil_checked(1.5),
+                Err(InvalidRatioError(1.5))
+            );
+            assert_eq!(
+                1usize.apply_ratio_ceil_checked(-0.1),
+                Err(InvalidRatioError(-0.1))
+            );
+        }
+
+        #[test]
+        fn test_checked_accepts_in_range_ratio() {
+            assert_eq!(1usize.apply_ratio_ceil_checked(0.5), Ok(1usize));
+        }
     }
 }